@@ -1,24 +1,61 @@
+use crate::core::date::Date;
+use crate::core::frame::request_frame;
 use crate::core::geometry::Rect;
 use crate::core::input::{PointerSignal, PointerState};
+use crate::core::keymap::Shortcut;
+use crate::core::locale::Locale;
+use crate::core::navigation::{TypeAhead, fuzzy_rank};
+use crate::core::selection::{SelectionMode, SelectionModel};
+use crate::core::validation::Validator;
 use crate::render::canvas2d;
+use crate::text::font::{
+    FontDescriptor, FontFaceSpec, FontLoadState, FontSource, FontStyle, load_state, register_face, register_fallbacks,
+};
+use crate::text::layout::{TextAlign, VerticalAlign};
+use crate::text::rich::{RichTextTheme, TextSpan};
 use crate::theme::REACTRON_THEME;
+use crate::ui::overlay;
+use crate::ui::toast::{ToastAction, ToastLevel, notify};
 use crate::ui::tree::{
-    CrossAlign, EdgeInsets, LayoutProps, SizeSpec, UiAction, UiEvent, UiTree, Widget,
+    CrossAlign, EdgeInsets, FieldValue, FormValues, LayoutProps, SizeSpec, SortDirection, UiAction, UiEvent, UiTree,
+    Widget,
 };
 use crate::widgets::button::{Button, ButtonStyle};
-use crate::widgets::checkbox::{Checkbox, CheckboxStyle};
+use crate::widgets::checkbox::{CheckState, Checkbox, CheckboxStyle};
+use crate::widgets::checkbox_group::{CheckboxGroup, CheckboxItem};
+use crate::widgets::combo_box::ComboBox;
+use crate::widgets::date_picker::{DatePicker, DateSelection};
 use crate::widgets::container::{Container, ContainerStyle};
+use crate::widgets::dialog::{Dialog, DialogStack};
+use crate::widgets::form::{
+    AsyncValidator, FieldKind, FieldSpec, Form, ValidateOn,
+};
 use crate::widgets::form_field::{FormField, FormFieldStyle};
 use crate::widgets::label::{Label, LabelStyle};
-use crate::widgets::list_view::{ListView, ListViewStyle};
-use crate::widgets::modal::{Modal, ModalStyle};
+use crate::widgets::list_view::{
+    ListDataSource, ListView, ListViewStyle, PageBuffer, PagedListSource, RowCache, RowChange,
+    StringListSource,
+};
+use crate::widgets::menu::{ContextMenu, MenuBar, MenuBarMenu, MenuEntry};
+use crate::widgets::number_input::NumberInput;
 use crate::widgets::radio_group::{RadioGroup, RadioGroupStyle};
+use crate::widgets::rich_text::{RichText, RichTextStyle};
+use crate::widgets::scroll_view::ScrollView;
 use crate::widgets::select::{Select, SelectStyle};
-use crate::widgets::slider::{Slider, SliderStyle};
+use crate::widgets::slider::{Orientation, Slider, SliderStyle};
+use crate::widgets::table::{
+    render_text_cell, CellValue, Table, TableColumn, TableDataSource, TableStyle,
+};
 use crate::widgets::tabs::{Tabs, TabsStyle};
-use crate::widgets::text_input::{TextInput, TextInputStyle};
+use crate::widgets::text_input::{TextInput, TextInputBehavior};
+use crate::widgets::toast_host::ToastHost;
+use crate::widgets::tooltip::{Tooltip, TooltipTrigger};
 use crate::widgets::toggle::{Toggle, ToggleStyle};
+use crate::widgets::tree_view::{TreeNode, TreeView};
 use crate::widgets::triangle_hero::TriangleHero;
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
 
@@ -36,20 +73,67 @@ pub struct DemoState {
     pub modal_result: String,
     pub show_modal: bool,
     pub selected_item: String,
+    pub selected_count: usize,
+    pub profile: String,
+    previous_profile: Option<(u64, String)>,
+    pending_animations: bool,
+    pub todos: Rc<RefCell<Vec<TodoItem>>>,
+    pub list_tab: String,
+    pub task_activity: String,
+    pub log_selected: usize,
+    pub log_activity: String,
+    pub tree_selection: String,
+    pub price_range: String,
+    pub hours_range: String,
+    pub categories: String,
+    pending_children: Option<ChildrenRequest>,
+    pub feed: Rc<RefCell<PageBuffer>>,
+    pending_page: Option<PageRequest>,
+    pub feed_failed_once: bool,
+    code_font_reported: bool,
     pub pointer: PointerState,
 }
 
 pub struct DemoApp {
     state: DemoState,
-    ui: UiTree,
-    modal: Modal,
+    root: ScrollView,
+    dialogs: DialogStack<DemoDialogResult>,
+    toasts: ToastHost,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DemoDialogResult {
+    Confirm,
+    Cancel,
+    Dismiss,
+    Close,
 }
 
+impl DemoDialogResult {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Confirm => "confirm",
+            Self::Cancel => "cancel",
+            Self::Dismiss => "dismiss",
+            Self::Close => "close",
+        }
+    }
+}
+
+const DEMO_FEED_LENGTH: usize = 600;
+const DEMO_PAGE_SIZE: usize = 30;
+const DEMO_LOG_ROWS: usize = 10_000;
+
+const CODE_FONT: FontDescriptor = FontDescriptor::new("JetBrains Mono", 13.0);
+
+const KEY_MENU_BAR: &str = "menu_bar";
 const KEY_TRIANGLE: &str = "triangle_hero";
 const KEY_CLICK_LABEL: &str = "clicks_label";
 const KEY_HINT_LABEL: &str = "hint_label";
 const KEY_TABS: &str = "main_tabs";
 const KEY_RESULTS_LIST: &str = "results_list";
+const KEY_LOG_TABLE: &str = "log_table";
+const KEY_FILE_TREE: &str = "file_tree";
 const KEY_CONTROLS_FIELD: &str = "controls_field";
 const KEY_CTRL_QUERY: &str = "ctrl_query";
 const KEY_CTRL_TOGGLE_NEON: &str = "ctrl_toggle_neon";
@@ -58,9 +142,87 @@ const KEY_CTRL_INTENSITY: &str = "ctrl_intensity";
 const KEY_CTRL_ANIMATIONS: &str = "ctrl_animations";
 const KEY_CTRL_DENSITY: &str = "ctrl_density";
 const KEY_CTRL_MODAL_BTN: &str = "ctrl_modal_btn";
+const KEY_FILTERS_FIELD: &str = "filters_field";
+const KEY_FILTER_PRICE: &str = "filter_price";
+const KEY_FILTER_HOURS: &str = "filter_hours";
+const KEY_FILTER_CATEGORIES: &str = "filter_categories";
+const KEY_PROFILE_FORM: &str = "profile_form";
+const KEY_SETTINGS_DIALOG: &str = "settings_dialog";
+const KEY_DETAILS_DIALOG: &str = "details_dialog";
+const KEY_DIALOG_SUMMARY: &str = "dialog_summary";
+const KEY_DIALOG_ANIMATIONS: &str = "dialog_animations";
+
+const TIME_ZONES: &[&str] = &[
+    "Africa/Cairo",
+    "Africa/Johannesburg",
+    "Africa/Lagos",
+    "Africa/Nairobi",
+    "America/Anchorage",
+    "America/Argentina/Buenos_Aires",
+    "America/Bogota",
+    "America/Chicago",
+    "America/Denver",
+    "America/Halifax",
+    "America/Los_Angeles",
+    "America/Mexico_City",
+    "America/New_York",
+    "America/Phoenix",
+    "America/Santiago",
+    "America/Sao_Paulo",
+    "America/Toronto",
+    "America/Vancouver",
+    "Asia/Bangkok",
+    "Asia/Dubai",
+    "Asia/Hong_Kong",
+    "Asia/Jakarta",
+    "Asia/Jerusalem",
+    "Asia/Karachi",
+    "Asia/Kolkata",
+    "Asia/Manila",
+    "Asia/Seoul",
+    "Asia/Shanghai",
+    "Asia/Singapore",
+    "Asia/Taipei",
+    "Asia/Tehran",
+    "Asia/Tokyo",
+    "Atlantic/Reykjavik",
+    "Australia/Adelaide",
+    "Australia/Brisbane",
+    "Australia/Melbourne",
+    "Australia/Perth",
+    "Australia/Sydney",
+    "Europe/Amsterdam",
+    "Europe/Athens",
+    "Europe/Berlin",
+    "Europe/Dublin",
+    "Europe/Helsinki",
+    "Europe/Istanbul",
+    "Europe/Lisbon",
+    "Europe/London",
+    "Europe/Madrid",
+    "Europe/Moscow",
+    "Europe/Oslo",
+    "Europe/Paris",
+    "Europe/Prague",
+    "Europe/Rome",
+    "Europe/Stockholm",
+    "Europe/Warsaw",
+    "Europe/Zurich",
+    "Pacific/Auckland",
+    "Pacific/Fiji",
+    "Pacific/Honolulu",
+    "UTC",
+];
 
 impl DemoApp {
     pub fn new() -> Self {
+        register_fallbacks("JetBrains Mono", &["Consolas", "Menlo", "DejaVu Sans Mono"], "monospace");
+        register_face(FontFaceSpec {
+            family: CODE_FONT.family,
+            source: FontSource::Local("JetBrains Mono"),
+            weight: CODE_FONT.weight,
+            style: FontStyle::Normal,
+        });
         let mut ui = UiTree::column(
             Rect {
                 x: 0.0,
@@ -72,6 +234,16 @@ impl DemoApp {
         );
         ui.set_padding(EdgeInsets::all(16.0));
         ui.set_align_items(CrossAlign::Center);
+        ui.push_key_with_order(
+            KEY_MENU_BAR,
+            Box::new(make_menu_bar()),
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(32.0),
+                align_self: Some(CrossAlign::Stretch),
+            },
+            -1,
+        );
         ui.push_key_with(
             "accent_bar",
             Box::new(Container {
@@ -123,7 +295,10 @@ impl DemoApp {
                 style: LabelStyle {
                     font: REACTRON_THEME.font_label,
                     color: REACTRON_THEME.text_primary,
+                    ..LabelStyle::default()
                 },
+                spans: Vec::new(),
+                measured: None,
             }),
             LayoutProps {
                 width: SizeSpec::Flex(1.0),
@@ -145,9 +320,11 @@ impl DemoApp {
                     "Overview".to_string(),
                     "Controls".to_string(),
                     "Metrics".to_string(),
+                    "Tasks".to_string(),
                 ],
                 selected: 0,
                 focused: false,
+                type_ahead: TypeAhead::default(),
                 style: TabsStyle::default(),
             }),
             LayoutProps {
@@ -170,28 +347,42 @@ impl DemoApp {
                 style: LabelStyle {
                     font: REACTRON_THEME.font_label,
                     color: REACTRON_THEME.text_muted,
+                    wrap: true,
+                    max_lines: Some(2),
+                    ..LabelStyle::default()
                 },
+                spans: Vec::new(),
+                measured: None,
             }),
             LayoutProps {
                 width: SizeSpec::Flex(1.0),
-                height: SizeSpec::Fixed(24.0),
+                height: SizeSpec::Fixed(38.0),
                 align_self: Some(CrossAlign::Stretch),
             },
         );
         ui.push_key_with(
             KEY_HINT_LABEL,
-            Box::new(Label {
+            Box::new(RichText {
+                key: KEY_HINT_LABEL,
                 rect: Rect {
                     x: 0.0,
                     y: 0.0,
                     width: 408.0,
                     height: 24.0,
                 },
-                text: "Click/tap the button to toggle accent color".to_string(),
-                style: LabelStyle {
-                    font: REACTRON_THEME.font_label,
-                    color: REACTRON_THEME.text_muted,
+                spans: make_hint_spans(false),
+                style: RichTextStyle {
+                    theme: RichTextTheme {
+                        font: REACTRON_THEME.font_label,
+                        code_font: CODE_FONT,
+                        color: REACTRON_THEME.text_muted,
+                        link_color: REACTRON_THEME.accent_secondary,
+                        code_background: "#1c2742",
+                    },
+                    ..RichTextStyle::default()
                 },
+                focused: false,
+                focused_link: None,
             }),
             LayoutProps {
                 width: SizeSpec::Flex(1.0),
@@ -208,12 +399,15 @@ impl DemoApp {
                     width: 408.0,
                     height: 190.0,
                 },
-                items: make_demo_items("", "Overview"),
+                source: Box::new(StringListSource { items: Vec::new() }),
+                rows: RowCache::default(),
                 row_height: 28.0,
                 scroll_offset: 0.0,
                 key: "results_item",
-                selected: Some(0),
-                selection_anchor: Some(0),
+                selection: SelectionModel::new(SelectionMode::Multiple),
+                highlight_query: String::new(),
+                type_ahead: TypeAhead::default(),
+                tooltip: TooltipTrigger::default(),
                 style: ListViewStyle::default(),
                 focused: false,
             }),
@@ -224,6 +418,63 @@ impl DemoApp {
             },
             1,
         );
+        ui.push_key_with_order(
+            KEY_LOG_TABLE,
+            Box::new(ContextMenu::new(
+                "log_menu",
+                make_log_menu(),
+                Box::new(Table::new(
+                    "log_rows",
+                    vec![
+                        TableColumn::new("time", "Time", 110.0),
+                        TableColumn::new("level", "Level", 76.0).align(TextAlign::Center),
+                        TableColumn::new("service", "Service", 90.0),
+                        TableColumn::new("latency", "Latency ms", 100.0)
+                            .align(TextAlign::End)
+                            .renderer(render_latency_cell),
+                        TableColumn::new("cpu", "CPU", 90.0),
+                        TableColumn::new("message", "Message", 220.0),
+                    ],
+                    Box::new(LogTableSource),
+                    SelectionModel::new(SelectionMode::Multiple),
+                )),
+            )),
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(220.0),
+                align_self: Some(CrossAlign::Stretch),
+            },
+            1,
+        );
+        ui.push_key_with_order(
+            KEY_FILE_TREE,
+            Box::new(TreeView::new(
+                "file_tree_node",
+                vec![
+                    TreeNode::branch(
+                        "src",
+                        "src",
+                        vec![
+                            TreeNode::lazy("src/widgets", "widgets"),
+                            TreeNode::lazy("src/core", "core"),
+                            TreeNode::leaf("src/lib.rs", "lib.rs"),
+                            TreeNode::leaf("src/main.rs", "main.rs"),
+                        ],
+                    )
+                    .expanded(),
+                    TreeNode::lazy("web", "web"),
+                    TreeNode::lazy("config", "config"),
+                    TreeNode::leaf("Cargo.toml", "Cargo.toml"),
+                    TreeNode::leaf("README.md", "README.md"),
+                ],
+            )),
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(200.0),
+                align_self: Some(CrossAlign::Stretch),
+            },
+            1,
+        );
         ui.push_key_with_order(
             KEY_CONTROLS_FIELD,
             Box::new(FormField {
@@ -250,22 +501,13 @@ impl DemoApp {
                     row.set_align_items(CrossAlign::Center);
                     row.push_key_with_order(
                         KEY_CTRL_QUERY,
-                        Box::new(TextInput {
-                            key: "search_query",
-                            rect: Rect {
-                                x: 0.0,
-                                y: 0.0,
-                                width: 0.0,
-                                height: 44.0,
-                            },
-                            value: String::new(),
-                            placeholder: "Type here...",
-                            style: TextInputStyle::default(),
-                            focused: false,
-                            cursor: 0,
-                            selection_anchor: None,
-                            dragging_selection: false,
-                        }),
+                        Box::new(TextInput::new("search_query", "Type here...").behavior(TextInputBehavior {
+                            max_length: Some(64),
+                            validators: vec![Validator::required(
+                                "Query is empty. Type to filter list items.",
+                            )],
+                            ..TextInputBehavior::default()
+                        })),
                         LayoutProps {
                             width: SizeSpec::Flex(2.0),
                             height: SizeSpec::Fixed(44.0),
@@ -275,26 +517,29 @@ impl DemoApp {
                     );
                     row.push_key_with_order(
                         "ctrl_button_toggle_accent",
-                        Box::new(Button {
-                            action: UiAction::ToggleAccent,
-                            rect: Rect {
-                                x: 0.0,
-                                y: 0.0,
-                                width: 0.0,
-                                height: 44.0,
-                            },
-                            label: "Reactron Button",
-                            style: ButtonStyle {
-                                idle_fill: "#18233d",
-                                hover_fill: "#283960",
-                                pressed_fill: "#1f2a47",
-                                border: "#3d5387",
-                                focus_border: "#27ffd8",
-                                text: REACTRON_THEME.text_primary,
-                                font: REACTRON_THEME.font_button,
-                            },
-                            focused: false,
-                        }),
+                        Box::new(Tooltip::new(
+                            "Switch the hero triangle between the primary and secondary accent colors",
+                            Box::new(Button {
+                                action: UiAction::ToggleAccent,
+                                rect: Rect {
+                                    x: 0.0,
+                                    y: 0.0,
+                                    width: 0.0,
+                                    height: 44.0,
+                                },
+                                label: "Reactron Button",
+                                style: ButtonStyle {
+                                    idle_fill: "#18233d",
+                                    hover_fill: "#283960",
+                                    pressed_fill: "#1f2a47",
+                                    border: "#3d5387",
+                                    focus_border: "#27ffd8",
+                                    text: REACTRON_THEME.text_primary,
+                                    font: REACTRON_THEME.font_button,
+                                },
+                                focused: false,
+                            }),
+                        )),
                         LayoutProps {
                             width: SizeSpec::Flex(1.0),
                             height: SizeSpec::Fixed(44.0),
@@ -304,26 +549,29 @@ impl DemoApp {
                     );
                     row.push_key_with_order(
                         KEY_CTRL_MODAL_BTN,
-                        Box::new(Button {
-                            action: UiAction::OpenModal,
-                            rect: Rect {
-                                x: 0.0,
-                                y: 0.0,
-                                width: 0.0,
-                                height: 44.0,
-                            },
-                            label: "Open Modal",
-                            style: ButtonStyle {
-                                idle_fill: "#1b2744",
-                                hover_fill: "#2b3f6e",
-                                pressed_fill: "#25365d",
-                                border: "#4765a7",
-                                focus_border: "#27ffd8",
-                                text: REACTRON_THEME.text_primary,
-                                font: "600 14px Consolas",
-                            },
-                            focused: false,
-                        }),
+                        Box::new(Tooltip::new(
+                            "Open a confirmation dialog summarizing the current settings (Esc closes it)",
+                            Box::new(Button {
+                                action: UiAction::OpenModal,
+                                rect: Rect {
+                                    x: 0.0,
+                                    y: 0.0,
+                                    width: 0.0,
+                                    height: 44.0,
+                                },
+                                label: "Open Modal",
+                                style: ButtonStyle {
+                                    idle_fill: "#1b2744",
+                                    hover_fill: "#2b3f6e",
+                                    pressed_fill: "#25365d",
+                                    border: "#4765a7",
+                                    focus_border: "#27ffd8",
+                                    text: REACTRON_THEME.text_primary,
+                                    font: FontDescriptor::new("Consolas", 14.0).weight(600),
+                                },
+                                focused: false,
+                            }),
+                        )),
                         LayoutProps {
                             width: SizeSpec::Flex(0.9),
                             height: SizeSpec::Fixed(44.0),
@@ -363,12 +611,18 @@ impl DemoApp {
                                 height: 44.0,
                             },
                             value: 65.0,
+                            range_end: None,
                             min: 0.0,
                             max: 100.0,
                             step: 5.0,
+                            large_step: Some(25.0),
+                            orientation: Orientation::Horizontal,
+                            tick_interval: None,
+                            tick_label_interval: None,
                             label: "Intensity",
                             focused: false,
                             dragging: false,
+                            active_thumb: 0,
                             style: SliderStyle::default(),
                         }),
                         LayoutProps {
@@ -388,7 +642,7 @@ impl DemoApp {
                                 width: 0.0,
                                 height: 44.0,
                             },
-                            value: true,
+                            state: CheckState::Checked,
                             label: "Animations",
                             focused: false,
                             style: CheckboxStyle::default(),
@@ -418,6 +672,7 @@ impl DemoApp {
                             ],
                             selected: 1,
                             focused: false,
+                            type_ahead: TypeAhead::default(),
                             style: RadioGroupStyle::default(),
                         }),
                         LayoutProps {
@@ -448,6 +703,7 @@ impl DemoApp {
                             open: false,
                             highlighted: 0,
                             label: "Preset",
+                            type_ahead: TypeAhead::default(),
                         }),
                         LayoutProps {
                             width: SizeSpec::Flex(0.95),
@@ -469,6 +725,27 @@ impl DemoApp {
             2,
         );
 
+        ui.push_key_with_order(
+            KEY_FILTERS_FIELD,
+            Box::new(make_filters_field()),
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(212.0),
+                align_self: Some(CrossAlign::Stretch),
+            },
+            3,
+        );
+        ui.push_key_with_order(
+            KEY_PROFILE_FORM,
+            Box::new(make_profile_form()),
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(478.0),
+                align_self: Some(CrossAlign::Stretch),
+            },
+            4,
+        );
+
         Self {
             state: DemoState {
                 accent_on: true,
@@ -482,27 +759,130 @@ impl DemoApp {
                 modal_result: "none".to_string(),
                 show_modal: false,
                 selected_item: "Widget Item 001".to_string(),
+                selected_count: 1,
+                profile: "unsaved".to_string(),
+                price_range: "0..1000".to_string(),
+                hours_range: "0..24".to_string(),
+                categories: "Widgets, Metrics".to_string(),
+                todos: Rc::new(RefCell::new(make_todos(1000))),
                 ..DemoState::default()
             },
-            ui,
-            modal: Modal {
-                key: "demo_modal_open",
-                result_key: "demo_modal_result",
-                rect: Rect {
-                    x: 0.0,
-                    y: 0.0,
-                    width: 0.0,
-                    height: 0.0,
-                },
-                open: false,
-                title: "Reactron Modal".to_string(),
-                message: "This is a reusable modal primitive with dismiss/cancel/confirm paths."
-                    .to_string(),
-                confirm_label: "Confirm",
-                cancel_label: "Cancel",
-                focused: true,
-                style: ModalStyle::default(),
+            root: {
+                let mut root = ScrollView::new(ui);
+                root.set_focused(true);
+                root
             },
+            dialogs: DialogStack::default(),
+            toasts: ToastHost::new("demo_toasts"),
+        }
+    }
+
+    fn run_menu_command(&mut self, command: &str, checked: Option<bool>) {
+        match command {
+            "file_open_modal" => self.open_settings_dialog(),
+            "file_export_csv" | "file_export_json" => {
+                let format = if command == "file_export_csv" { "CSV" } else { "JSON" };
+                notify(format!("Exported logs as {}.", format), ToastLevel::Success, Some(3000.0), None);
+            }
+            "view_neon" => self.state.neon_mode = checked.unwrap_or(!self.state.neon_mode),
+            "view_animations" => self.state.animations = checked.unwrap_or(!self.state.animations),
+            "view_accent" => self.state.accent_on = !self.state.accent_on,
+            "help_shortcuts" => {
+                notify(
+                    "F2 modal, Alt+N neon, Alt+A animations, Alt+T accent, Alt+Shift+D dismiss toast",
+                    ToastLevel::Info,
+                    Some(6000.0),
+                    None,
+                );
+            }
+            _ => {
+                if let Some((_, density)) = DENSITY_COMMANDS.iter().find(|(name, _)| *name == command) {
+                    self.state.density = density.to_string();
+                }
+            }
+        }
+    }
+
+    fn open_settings_dialog(&mut self) {
+        if self.dialogs.contains(KEY_SETTINGS_DIALOG) {
+            return;
+        }
+        self.state.pending_animations = self.state.animations;
+        self.dialogs.push(make_settings_dialog(&self.state), self.root.content.focused_key());
+        self.state.show_modal = true;
+        self.state.modal_result = "opened".to_string();
+    }
+
+    fn close_dialog(&mut self, key: &str, result: DemoDialogResult, opener: Option<&'static str>) {
+        if key == KEY_SETTINGS_DIALOG {
+            if result == DemoDialogResult::Confirm {
+                self.state.animations = self.state.pending_animations;
+            }
+            let level = if result == DemoDialogResult::Confirm {
+                ToastLevel::Success
+            } else {
+                ToastLevel::Info
+            };
+            notify(format!("Dialog closed: {}", result.as_str()), level, Some(3000.0), None);
+            self.state.modal_result = result.as_str().to_string();
+        }
+        if let Some(opener) = opener {
+            self.root.content.focus_key(opener);
+        }
+        self.state.show_modal = self.dialogs.is_open();
+    }
+
+    fn report_code_font(&mut self) {
+        if self.state.code_font_reported {
+            return;
+        }
+        match load_state(&CODE_FONT) {
+            Some(FontLoadState::Loaded) => {
+                notify("Loaded JetBrains Mono for code spans.", ToastLevel::Success, Some(3000.0), None);
+            }
+            Some(FontLoadState::Failed) => {
+                notify("JetBrains Mono unavailable, using fallback fonts.", ToastLevel::Warning, Some(4000.0), None);
+            }
+            _ => return,
+        }
+        self.state.code_font_reported = true;
+    }
+
+    fn deliver_pending_page(&mut self) {
+        let Some(request) = self.state.pending_page.as_mut() else {
+            return;
+        };
+        if request.polls_left > 0 {
+            request.polls_left -= 1;
+            request_frame();
+            return;
+        }
+        let offset = request.offset;
+        self.state.pending_page = None;
+
+        if offset >= DEMO_PAGE_SIZE * 3 && !self.state.feed_failed_once {
+            self.state.feed_failed_once = true;
+            self.state.feed.borrow_mut().fail("Network timeout");
+            return;
+        }
+        let (items, has_more) =
+            make_demo_page(&self.state.query, &self.state.active_tab, offset, DEMO_PAGE_SIZE);
+        self.state.feed.borrow_mut().append_page(items, has_more);
+    }
+
+    fn deliver_pending_children(&mut self) {
+        let Some(request) = self.state.pending_children.as_mut() else {
+            return;
+        };
+        if request.polls_left > 0 {
+            request.polls_left -= 1;
+            request_frame();
+            return;
+        }
+        let id = request.id.clone();
+        self.state.pending_children = None;
+        if let Some(tree) = self.root.content.widget_mut_by_key::<TreeView>(KEY_FILE_TREE) {
+            tree.set_children(&id, make_demo_children(&id));
         }
     }
 
@@ -510,12 +890,22 @@ impl DemoApp {
         self.state.pointer.apply(signal);
     }
 
+    pub fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        if self.dialogs.is_open() {
+            self.dialogs.handles_shortcut(shortcut)
+        } else {
+            self.toasts.handles_shortcut(shortcut) || self.root.handles_shortcut(shortcut)
+        }
+    }
+
     pub fn render(
         &mut self,
         context: &CanvasRenderingContext2d,
         canvas: &HtmlCanvasElement,
         dpr: f64,
+        time_ms: f64,
     ) -> Result<(), JsValue> {
+        self.state.pointer.time_ms = time_ms;
         let (width, height) = canvas2d::sync_canvas_resolution(canvas, dpr);
 
         canvas2d::clear(context, width, height, REACTRON_THEME.background);
@@ -547,25 +937,19 @@ impl DemoApp {
             "#7481a3"
         };
 
-        let interaction_text = if self.state.pointer.is_down {
-            "Pointer down: release on button to trigger"
-        } else {
-            "Tab/Shift+Tab focus | Left/Right on tabs | Esc closes modal"
-        };
-
-        self.ui.set_area(Rect {
+        self.root.set_rect(Rect {
             x: width * 0.5 - 220.0,
             y: height * 0.1,
             width: 440.0,
             height: height * 0.8,
         });
 
-        if let Some(hero) = self.ui.widget_mut_by_key::<TriangleHero>(KEY_TRIANGLE) {
+        if let Some(hero) = self.root.content.widget_mut_by_key::<TriangleHero>(KEY_TRIANGLE) {
             hero.set_color(hero_color);
         }
-        if let Some(clicks_label) = self.ui.widget_mut_by_key::<Label>(KEY_CLICK_LABEL) {
+        if let Some(clicks_label) = self.root.content.widget_mut_by_key::<Label>(KEY_CLICK_LABEL) {
             clicks_label.set_text(format!(
-                "Clicks: {} | Tab: {} | Query: {} | Preset: {} | Intensity: {:.0}% | Animations: {} | Density: {} | Modal: {} | Selected: {}",
+                "Clicks: {} | Tab: {} | Query: {} | Preset: {} | Intensity: {:.0}% | Animations: {} | Density: {} | Modal: {} | Selected: {} ({} rows) | Profile: {} | Tasks: {} {} | Logs: {} selected {} | File: {} | Price: {} | Hours: {} | Categories: {}",
                 self.state.clicks,
                 self.state.active_tab,
                 self.state.query,
@@ -574,20 +958,64 @@ impl DemoApp {
                 if self.state.animations { "On" } else { "Off" },
                 self.state.density,
                 self.state.modal_result,
-                self.state.selected_item
+                self.state.selected_item,
+                self.state.selected_count,
+                self.state.profile,
+                describe_todos(&self.state.todos.borrow()),
+                self.state.task_activity,
+                self.state.log_selected,
+                self.state.log_activity,
+                self.state.tree_selection,
+                self.state.price_range,
+                self.state.hours_range,
+                self.state.categories
             ));
         }
-        if let Some(hint_label) = self.ui.widget_mut_by_key::<Label>(KEY_HINT_LABEL) {
-            hint_label.set_text(interaction_text.to_string());
+        if let Some(hint_label) = self.root.content.widget_mut_by_key::<RichText>(KEY_HINT_LABEL) {
+            hint_label.set_spans(make_hint_spans(self.state.pointer.is_down));
         }
-        if let Some(list) = self.ui.widget_mut_by_key::<ListView>(KEY_RESULTS_LIST) {
-            list.set_items(make_demo_items(&self.state.query, &self.state.active_tab));
+        self.deliver_pending_page();
+        self.deliver_pending_children();
+        self.report_code_font();
+        if let Some(list) = self.root.content.widget_mut_by_key::<ListView>(KEY_RESULTS_LIST) {
+            let list_key = if self.state.active_tab == "Tasks" {
+                self.state.active_tab.clone()
+            } else {
+                format!("{}|{}", self.state.active_tab, self.state.query)
+            };
+            if list_key != self.state.list_tab {
+                if self.state.active_tab == "Tasks" {
+                    list.set_source(Box::new(TodoSource {
+                        items: Rc::clone(&self.state.todos),
+                    }));
+                } else {
+                    self.state.feed.borrow_mut().reset();
+                    self.state.pending_page = None;
+                    list.set_source(Box::new(PagedListSource {
+                        buffer: Rc::clone(&self.state.feed),
+                        page_size: DEMO_PAGE_SIZE,
+                    }));
+                }
+                self.state.list_tab = list_key;
+            }
+            list.highlight_query = if self.state.active_tab == "Tasks" {
+                String::new()
+            } else {
+                self.state.query.clone()
+            };
             list.set_selected_by_value(&self.state.selected_item);
         }
-        if let Some(tabs) = self.ui.widget_mut_by_key::<Tabs>(KEY_TABS) {
+        if let Some(menu_bar) = self.root.content.widget_mut_by_key::<MenuBar>(KEY_MENU_BAR) {
+            menu_bar.set_checked("view_neon", self.state.neon_mode);
+            menu_bar.set_checked("view_animations", self.state.animations);
+            for (command, density) in DENSITY_COMMANDS {
+                menu_bar.set_checked(command, self.state.density == density);
+            }
+        }
+        if let Some(tabs) = self.root.content.widget_mut_by_key::<Tabs>(KEY_TABS) {
             tabs.set_selected_by_value(&self.state.active_tab);
         }
-        if let Some(field) = self.ui.widget_mut_by_key::<FormField>(KEY_CONTROLS_FIELD) {
+        if let Some(field) = self.root.content.widget_mut_by_key::<FormField>(KEY_CONTROLS_FIELD) {
             if let Some(input) = field.child_mut().widget_mut_by_key::<TextInput>(KEY_CTRL_QUERY) {
                 input.set_value(self.state.query.clone());
             }
@@ -606,36 +1034,62 @@ impl DemoApp {
             if let Some(radio) = field.child_mut().widget_mut_by_key::<RadioGroup>(KEY_CTRL_DENSITY) {
                 radio.set_selected_by_value(&self.state.density);
             }
-            let validation = if self.state.query.trim().is_empty() {
-                Some("Query is empty. Type to filter list items.".to_string())
-            } else {
-                None
-            };
-            field.set_validation(validation);
         }
 
-        let mut ui_pointer = self.state.pointer.clone();
+        overlay::set_viewport(Rect {
+            x: 0.0,
+            y: 0.0,
+            width,
+            height,
+        });
+        let routed_pointer = overlay::route_pointer(&self.state.pointer);
+        let mut ui_pointer = routed_pointer.clone();
         if self.state.show_modal {
             ui_pointer.just_pressed = false;
             ui_pointer.just_released = false;
             ui_pointer.is_down = false;
+            ui_pointer.secondary_click = false;
             ui_pointer.suppress_focus_and_text_input();
+            ui_pointer.scroll_x = 0.0;
             ui_pointer.scroll_y = 0.0;
         }
 
-        let mut events = self.ui.draw(context, &ui_pointer);
-        self.modal.set_open(self.state.show_modal);
-        self.modal.set_rect(Rect {
+        if ui_pointer.focus_next && !self.root.focus_next_in_children() {
+            self.root.focus_next_in_children();
+        } else if ui_pointer.focus_prev && !self.root.focus_prev_in_children() {
+            self.root.focus_prev_in_children();
+        }
+
+        self.root.set_focused(!self.state.show_modal);
+        let mut events = self.root.draw(context, &ui_pointer);
+        overlay::paint_overlays(context);
+        events.extend(self.dialogs.draw(
+            context,
+            &routed_pointer,
+            Rect {
+                x: 0.0,
+                y: 0.0,
+                width,
+                height,
+            },
+        ));
+        let mut toast_pointer = self.state.pointer.clone();
+        if self.state.show_modal {
+            toast_pointer = ui_pointer.clone();
+            toast_pointer.x = f64::NEG_INFINITY;
+            toast_pointer.y = f64::NEG_INFINITY;
+        }
+        self.toasts.set_rect(Rect {
             x: 0.0,
             y: 0.0,
             width,
             height,
         });
-        self.modal.set_message(format!(
-            "Density: {} | Preset: {} | Intensity: {:.0}%",
-            self.state.density, self.state.preset, self.state.intensity
-        ));
-        events.extend(self.modal.draw(context, &self.state.pointer));
+        events.extend(self.toasts.draw(context, &toast_pointer));
+        overlay::flush_overlays(context);
+        for closed in self.dialogs.take_closed() {
+            self.close_dialog(closed.key, closed.result, closed.opener);
+        }
         for event in events {
             match event {
                 UiEvent::Action(UiAction::ToggleAccent) => {
@@ -645,9 +1099,12 @@ impl DemoApp {
                 UiEvent::Action(UiAction::SetNeon(enabled)) => {
                     self.state.neon_mode = enabled;
                 }
-                UiEvent::Action(UiAction::OpenModal) => {
-                    self.state.show_modal = true;
-                    self.state.modal_result = "opened".to_string();
+                UiEvent::Action(UiAction::OpenModal)
+                | UiEvent::LinkActivated {
+                    key: KEY_HINT_LABEL,
+                    target: "modal",
+                } => {
+                    self.open_settings_dialog();
                 }
                 UiEvent::ValueChanged {
                     key: "main_tab",
@@ -655,6 +1112,7 @@ impl DemoApp {
                 } => {
                     self.state.active_tab = value;
                     self.state.selected_item = String::new();
+                    self.state.selected_count = 0;
                 }
                 UiEvent::ValueChanged {
                     key: "search_query",
@@ -674,6 +1132,24 @@ impl DemoApp {
                 } => {
                     self.state.selected_item = value;
                 }
+                UiEvent::ValueChanged {
+                    key: "filter_price_range",
+                    value,
+                } => {
+                    self.state.price_range = describe_range(&value);
+                }
+                UiEvent::ValueChanged {
+                    key: "filter_hours_range",
+                    value,
+                } => {
+                    self.state.hours_range = describe_range(&value);
+                }
+                UiEvent::CheckedChanged {
+                    key: "filter_categories",
+                    ids,
+                } => {
+                    self.state.categories = if ids.is_empty() { "none".to_string() } else { ids.join(", ") };
+                }
                 UiEvent::ValueChanged {
                     key: "ui_intensity",
                     value,
@@ -693,18 +1169,149 @@ impl DemoApp {
                     self.state.density = value;
                 }
                 UiEvent::ValueChanged {
-                    key: "demo_modal_open",
+                    key: "dialog_animations",
                     value,
                 } => {
-                    self.state.show_modal = value == "true";
+                    self.state.pending_animations = value == "true";
+                }
+                UiEvent::LinkActivated {
+                    key: KEY_DIALOG_SUMMARY,
+                    target: "details",
+                } => {
+                    if !self.dialogs.contains(KEY_DETAILS_DIALOG) {
+                        self.dialogs.push(make_details_dialog(), None);
+                    }
+                }
+                UiEvent::FormSubmitted {
+                    key: "profile_form",
+                    values,
+                } => {
+                    let previous = std::mem::replace(&mut self.state.profile, describe_profile(&values));
+                    let id = notify(
+                        "Profile saved.",
+                        ToastLevel::Success,
+                        Some(5000.0),
+                        Some(ToastAction {
+                            key: "toast_undo_profile",
+                            label: "Undo",
+                        }),
+                    );
+                    self.state.previous_profile = Some((id, previous));
+                }
+                UiEvent::ToastAction {
+                    key: "toast_undo_profile",
+                    id,
+                } => {
+                    if let Some((toast, previous)) = self.state.previous_profile.take()
+                        && toast == id
+                    {
+                        self.state.profile = previous;
+                        notify("Profile change reverted.", ToastLevel::Warning, Some(3000.0), None);
+                    }
+                }
+                UiEvent::MenuCommand {
+                    key: "menu_bar",
+                    command,
+                    checked,
+                } => self.run_menu_command(command, checked),
+                UiEvent::MenuCommand {
+                    key: "log_menu",
+                    command,
+                    checked,
+                } => {
+                    self.state.log_activity = match checked {
+                        Some(checked) => format!("{} {}", command, if checked { "on" } else { "off" }),
+                        None => command.to_string(),
+                    };
+                }
+                UiEvent::Action(UiAction::SubmitForm) => {}
+                UiEvent::RowEvent {
+                    key: "results_item",
+                    index,
+                    event,
+                } => match *event {
+                    UiEvent::ValueChanged {
+                        key: "todo_done",
+                        value,
+                    } => {
+                        self.state.task_activity = format!(
+                            "#{} {}",
+                            index + 1,
+                            if value == "true" { "done" } else { "reopened" }
+                        );
+                    }
+                    UiEvent::Action(UiAction::RemoveRow) => {
+                        self.state.task_activity = format!("#{} removed", index + 1);
+                    }
+                    _ => {}
+                },
+                UiEvent::SelectionChanged {
+                    key: "results_item",
+                    indices,
+                } => {
+                    self.state.selected_count = indices.len();
+                }
+                UiEvent::LoadMore {
+                    key: "results_item",
+                    offset,
+                } => {
+                    self.state.pending_page = Some(PageRequest {
+                        offset,
+                        polls_left: 20,
+                    });
+                    request_frame();
+                }
+                UiEvent::SelectionChanged {
+                    key: "log_rows",
+                    indices,
+                } => {
+                    self.state.log_selected = indices.len();
+                }
+                UiEvent::SortChanged {
+                    key: "log_rows",
+                    column,
+                    direction,
+                } => {
+                    self.state.log_activity = match direction {
+                        Some(SortDirection::Ascending) => format!("sorted by {} asc", column),
+                        Some(SortDirection::Descending) => format!("sorted by {} desc", column),
+                        None => "unsorted".to_string(),
+                    };
+                }
+                UiEvent::CellActivated {
+                    key: "log_rows",
+                    row,
+                    column,
+                } => {
+                    self.state.log_activity = format!("opened row {} {}", row + 1, column);
                 }
                 UiEvent::ValueChanged {
-                    key: "demo_modal_result",
+                    key: "file_tree_node",
                     value,
                 } => {
-                    self.state.modal_result = value;
+                    self.state.tree_selection = value;
                 }
-                UiEvent::ValueChanged { .. } => {}
+                UiEvent::ChildrenRequested {
+                    key: "file_tree_node",
+                    id,
+                } => {
+                    self.state.pending_children = Some(ChildrenRequest { id, polls_left: 20 });
+                    request_frame();
+                }
+                UiEvent::Action(UiAction::RemoveRow) => {}
+                UiEvent::ValueChanged { .. }
+                | UiEvent::SelectionChanged { .. }
+                | UiEvent::LoadMore { .. }
+                | UiEvent::RowEvent { .. }
+                | UiEvent::LinkActivated { .. }
+                | UiEvent::SortChanged { .. }
+                | UiEvent::CellActivated { .. }
+                | UiEvent::ChildrenRequested { .. }
+                | UiEvent::Validation { .. }
+                | UiEvent::CheckedChanged { .. }
+                | UiEvent::ToastAction { .. }
+                | UiEvent::MenuCommand { .. }
+                | UiEvent::FormSubmitted { .. } => {}
             }
         }
 
@@ -713,23 +1320,803 @@ impl DemoApp {
     }
 }
 
-fn make_demo_items(query: &str, tab: &str) -> Vec<String> {
+const DENSITY_COMMANDS: [(&str, &str); 3] = [
+    ("density_compact", "Compact"),
+    ("density_cozy", "Cozy"),
+    ("density_comfort", "Comfort"),
+];
+
+fn make_menu_bar() -> MenuBar {
+    MenuBar::new(
+        "menu_bar",
+        vec![
+            MenuBarMenu::new(
+                "File",
+                vec![
+                    MenuEntry::command("file_open_modal", "Open modal\u{2026}").shortcut("F2"),
+                    MenuEntry::submenu(
+                        "Export",
+                        vec![
+                            MenuEntry::command("file_export_csv", "As CSV").shortcut("Alt+Shift+C"),
+                            MenuEntry::command("file_export_json", "As JSON").shortcut("Alt+Shift+J"),
+                        ],
+                    ),
+                    MenuEntry::separator(),
+                    MenuEntry::command("file_print", "Print").shortcut("Ctrl+P").disabled(),
+                ],
+            ),
+            MenuBarMenu::new(
+                "View",
+                vec![
+                    MenuEntry::command("view_neon", "Neon mode").shortcut("Alt+N").checked(true),
+                    MenuEntry::command("view_animations", "Animations").shortcut("Alt+A").checked(true),
+                    MenuEntry::command("view_accent", "Swap accent").shortcut("Alt+T"),
+                    MenuEntry::separator(),
+                    MenuEntry::submenu(
+                        "Density",
+                        DENSITY_COMMANDS
+                            .iter()
+                            .map(|(command, density)| MenuEntry::command(command, *density).checked(false))
+                            .collect(),
+                    ),
+                ],
+            ),
+            MenuBarMenu::new(
+                "Help",
+                vec![MenuEntry::command("help_shortcuts", "Keyboard shortcuts").shortcut("F1")],
+            ),
+        ],
+    )
+}
+
+fn make_log_menu() -> Vec<MenuEntry> {
+    vec![
+        MenuEntry::command("log_copy", "Copy message").shortcut("Alt+C"),
+        MenuEntry::command("log_reviewed", "Mark reviewed").shortcut("Alt+R").checked(false),
+        MenuEntry::submenu(
+            "Filter level",
+            vec![
+                MenuEntry::command("log_level_info", "Info and above"),
+                MenuEntry::command("log_level_warn", "Warnings and above"),
+                MenuEntry::command("log_level_error", "Errors only"),
+            ],
+        ),
+        MenuEntry::separator(),
+        MenuEntry::command("log_refresh", "Refresh").shortcut("F5"),
+    ]
+}
+
+fn make_settings_dialog(state: &DemoState) -> Dialog<DemoDialogResult> {
+    let mut content = UiTree::column(
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        },
+        10.0,
+    );
+    content.push_key_with(
+        KEY_DIALOG_SUMMARY,
+        Box::new(RichText {
+            key: KEY_DIALOG_SUMMARY,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 44.0,
+            },
+            spans: vec![
+                TextSpan::new(format!(
+                    "Density: {} | Preset: {} | Intensity: {:.0}% ",
+                    state.density, state.preset, state.intensity
+                )),
+                TextSpan::new("More details").link("details"),
+            ],
+            style: RichTextStyle {
+                align: TextAlign::Start,
+                ..RichTextStyle::default()
+            },
+            focused: false,
+            focused_link: None,
+        }),
+        LayoutProps {
+            width: SizeSpec::Flex(1.0),
+            height: SizeSpec::Fixed(44.0),
+            align_self: Some(CrossAlign::Stretch),
+        },
+    );
+    content.push_key_with(
+        KEY_DIALOG_ANIMATIONS,
+        Box::new(Checkbox {
+            key: "dialog_animations",
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 36.0,
+            },
+            state: CheckState::from_bool(state.animations),
+            label: "Animations",
+            focused: false,
+            style: CheckboxStyle::default(),
+        }),
+        LayoutProps {
+            width: SizeSpec::Flex(1.0),
+            height: SizeSpec::Fixed(36.0),
+            align_self: Some(CrossAlign::Stretch),
+        },
+    );
+    Dialog::new(KEY_SETTINGS_DIALOG, "Reactron Dialog", content)
+        .button("Cancel", DemoDialogResult::Cancel)
+        .primary_button("Confirm", DemoDialogResult::Confirm)
+        .dismissible(DemoDialogResult::Dismiss)
+}
+
+fn make_details_dialog() -> Dialog<DemoDialogResult> {
+    let mut content = UiTree::column(
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        },
+        0.0,
+    );
+    content.push_key_with(
+        "details_text",
+        Box::new(RichText {
+            key: "details_text",
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            spans: vec![
+                TextSpan::new("Dialogs stack: Tab stays inside the top dialog, "),
+                TextSpan::new("Esc").code(),
+                TextSpan::new(" closes it and focus returns to whatever opened it."),
+            ],
+            style: RichTextStyle {
+                align: TextAlign::Start,
+                vertical_align: VerticalAlign::Top,
+                ..RichTextStyle::default()
+            },
+            focused: false,
+            focused_link: None,
+        }),
+        LayoutProps {
+            width: SizeSpec::Flex(1.0),
+            height: SizeSpec::Flex(1.0),
+            align_self: Some(CrossAlign::Stretch),
+        },
+    );
+    Dialog::new(KEY_DETAILS_DIALOG, "Dialog details", content)
+        .size(380.0, Some(220.0))
+        .primary_button("Close", DemoDialogResult::Close)
+        .dismissible(DemoDialogResult::Close)
+}
+
+fn make_demo_page(query: &str, tab: &str, offset: usize, limit: usize) -> (Vec<String>, bool) {
     let prefix = match tab {
         "Controls" => "Control",
         "Metrics" => "Metric",
         _ => "Widget",
     };
-    let items = (1..=120)
-        .map(|index| format!("{} Item {:03}", prefix, index))
+    let feed = (1..=DEMO_FEED_LENGTH)
+        .map(|index| {
+            if index % 8 == 0 {
+                format!("{} Item {:03} (pinned: shared with operations, analytics and release review)", prefix, index)
+            } else {
+                format!("{} Item {:03}", prefix, index)
+            }
+        })
         .collect::<Vec<_>>();
+    let mut matches = fuzzy_rank(&feed, query)
+        .into_iter()
+        .map(|found| feed[found.index].clone())
+        .skip(offset);
+    let items = matches.by_ref().take(limit).collect::<Vec<_>>();
+    let has_more = matches.next().is_some();
+    (items, has_more)
+}
+
+struct PageRequest {
+    offset: usize,
+    polls_left: u32,
+}
+
+struct ChildrenRequest {
+    id: String,
+    polls_left: u32,
+}
 
-    if query.is_empty() {
-        return items;
+fn make_demo_children(id: &str) -> Vec<TreeNode> {
+    let depth = id.matches('/').count();
+    let names: &[&str] = match id.rsplit('/').next().unwrap_or(id) {
+        "widgets" => &["button.rs", "list_view.rs", "table.rs", "tree_view.rs"],
+        "core" => &["geometry.rs", "input.rs", "navigation.rs", "selection.rs"],
+        "web" => &["index.html", "styles.css"],
+        _ => &["defaults.toml", "overrides.toml"],
+    };
+    let mut children = names
+        .iter()
+        .map(|name| TreeNode::leaf(format!("{}/{}", id, name), *name))
+        .collect::<Vec<_>>();
+    if depth < 3 {
+        children.insert(0, TreeNode::lazy(format!("{}/nested", id), "nested"));
     }
+    children
+}
 
-    let query_lower = query.to_lowercase();
-    items
-        .into_iter()
-        .filter(|item| item.to_lowercase().contains(&query_lower))
+struct NameAvailability {
+    value: String,
+    polls_left: u32,
+}
+
+impl AsyncValidator for NameAvailability {
+    fn start(&mut self, value: &str) {
+        self.value = value.to_string();
+        self.polls_left = 30;
+    }
+
+    fn poll(&mut self) -> Option<(String, Option<String>)> {
+        if self.polls_left > 0 {
+            self.polls_left -= 1;
+            return None;
+        }
+        let name = self.value.trim().to_lowercase();
+        let error = matches!(name.as_str(), "admin" | "root" | "reactron")
+            .then(|| format!("\"{}\" is already taken.", name));
+        Some((self.value.clone(), error))
+    }
+}
+
+fn profile_input(key: &'static str, placeholder: &'static str, behavior: TextInputBehavior) -> TextInput {
+    TextInput::new(key, placeholder).behavior(behavior)
+}
+
+fn profile_field(label: &str, helper_text: &str, input_key: &'static str, input: Box<dyn Widget>) -> FormField {
+    let mut child = UiTree::row(
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 44.0,
+        },
+        10.0,
+    );
+    child.push_key_with_order(
+        input_key,
+        input,
+        LayoutProps {
+            width: SizeSpec::Flex(1.0),
+            height: SizeSpec::Fixed(44.0),
+            align_self: Some(CrossAlign::Stretch),
+        },
+        0,
+    );
+    FormField {
+        rect: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        },
+        label: label.to_string(),
+        helper_text: helper_text.to_string(),
+        error_text: String::new(),
+        has_error: false,
+        child,
+        focused: false,
+        style: FormFieldStyle::default(),
+    }
+}
+
+fn today() -> Date {
+    let now = web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.time_origin() + performance.now())
+        .unwrap_or(0.0);
+    Date::from_unix_ms(now)
+}
+
+fn make_availability_picker() -> DatePicker {
+    let today = today();
+    let mut picker = DatePicker::new("profile_availability_input", today, "Pick a date range");
+    picker.selection = DateSelection::Range;
+    picker.locale = Locale::DE_DE;
+    picker.min = Some(today);
+    picker.max = Some(today.add_months(12));
+    picker.disabled = Some(|date| date.weekday() >= 5);
+    picker
+}
+
+fn range_slider(key: &'static str, label: &'static str, range: (f64, f64), step: f64, ticks: (f64, f64)) -> Slider {
+    Slider {
+        key,
+        rect: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        },
+        value: range.0,
+        range_end: Some(range.1),
+        min: range.0,
+        max: range.1,
+        step,
+        large_step: None,
+        orientation: Orientation::Horizontal,
+        tick_interval: Some(ticks.0),
+        tick_label_interval: Some(ticks.1),
+        label,
+        focused: false,
+        dragging: false,
+        active_thumb: 0,
+        style: SliderStyle::default(),
+    }
+}
+
+fn make_filters_field() -> FormField {
+    let mut row = UiTree::row(
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 150.0,
+        },
+        10.0,
+    );
+    row.push_key_with_order(
+        KEY_FILTER_PRICE,
+        Box::new(range_slider("filter_price_range", "Price", (0.0, 1000.0), 10.0, (50.0, 250.0))),
+        LayoutProps {
+            width: SizeSpec::Flex(1.0),
+            height: SizeSpec::Fixed(64.0),
+            align_self: Some(CrossAlign::Start),
+        },
+        0,
+    );
+    row.push_key_with_order(
+        KEY_FILTER_HOURS,
+        Box::new(Slider {
+            orientation: Orientation::Vertical,
+            large_step: Some(6.0),
+            ..range_slider("filter_hours_range", "Hours", (0.0, 24.0), 1.0, (1.0, 6.0))
+        }),
+        LayoutProps {
+            width: SizeSpec::Fixed(110.0),
+            height: SizeSpec::Fixed(150.0),
+            align_self: Some(CrossAlign::Stretch),
+        },
+        1,
+    );
+    row.push_key_with_order(
+        KEY_FILTER_CATEGORIES,
+        Box::new(CheckboxGroup::new(
+            "filter_categories",
+            "All categories",
+            vec![
+                CheckboxItem::new("Widgets", "Widgets").checked(),
+                CheckboxItem::new("Controls", "Controls"),
+                CheckboxItem::new("Metrics", "Metrics").checked(),
+                CheckboxItem::new("Tasks", "Tasks"),
+            ],
+        )),
+        LayoutProps {
+            width: SizeSpec::Fixed(150.0),
+            height: SizeSpec::Fixed(140.0),
+            align_self: Some(CrossAlign::Start),
+        },
+        2,
+    );
+    FormField {
+        rect: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        },
+        label: "Filters".to_string(),
+        helper_text: "Tab switches thumbs; Shift+arrows or PageUp/PageDown take large steps".to_string(),
+        error_text: String::new(),
+        has_error: false,
+        child: row,
+        focused: false,
+        style: FormFieldStyle::default(),
+    }
+}
+
+fn make_profile_form() -> Form {
+    let mut form = Form::new(KEY_PROFILE_FORM, 8.0);
+    form.register(
+        FieldSpec {
+            name: "profile_name",
+            value_key: "profile_name_input",
+            kind: FieldKind::Text,
+            initial: String::new(),
+            validate_on: ValidateOn::Change,
+            validators: vec![
+                Validator::required("Display name is required."),
+                Validator::min_length(3, "Use at least 3 characters."),
+            ],
+            async_validator: Some(Box::new(NameAvailability {
+                value: String::new(),
+                polls_left: 0,
+            })),
+        },
+        profile_field(
+            "Display name",
+            "Checked for availability as you type",
+            "profile_name_input",
+            Box::new(profile_input("profile_name_input", "e.g. neon-fox", TextInputBehavior::default())),
+        ),
+        104.0,
+    );
+    form.register(
+        FieldSpec {
+            name: "profile_retries",
+            value_key: "profile_retries_input",
+            kind: FieldKind::Number,
+            initial: String::new(),
+            validate_on: ValidateOn::Blur,
+            validators: vec![Validator::required("Retry count is required.")],
+            async_validator: None,
+        },
+        profile_field(
+            "Retry count",
+            "Step with arrows, wheel or buttons; validated on blur",
+            "profile_retries_input",
+            Box::new(NumberInput::new("profile_retries_input", 0.0, 10.0, 1.0, "0-10")),
+        ),
+        104.0,
+    );
+    form.register(
+        FieldSpec {
+            name: "profile_time_zone",
+            value_key: "profile_time_zone_input",
+            kind: FieldKind::Text,
+            initial: String::new(),
+            validate_on: ValidateOn::Blur,
+            validators: vec![Validator::required("Pick a time zone.")],
+            async_validator: None,
+        },
+        profile_field(
+            "Time zone",
+            "Type to filter, arrows to pick, Enter to accept",
+            "profile_time_zone_input",
+            Box::new(ComboBox::new(
+                "profile_time_zone_input",
+                TIME_ZONES.iter().map(|zone| zone.to_string()).collect(),
+                "e.g. Europe/Berlin",
+            )),
+        ),
+        104.0,
+    );
+    form.register(
+        FieldSpec {
+            name: "profile_availability",
+            value_key: "profile_availability_input",
+            kind: FieldKind::Text,
+            initial: String::new(),
+            validate_on: ValidateOn::Change,
+            validators: Vec::new(),
+            async_validator: None,
+        },
+        profile_field(
+            "Availability",
+            "Weekdays only; arrows move by day/week, PageUp/PageDown by month",
+            "profile_availability_input",
+            Box::new(make_availability_picker()),
+        ),
+        104.0,
+    );
+    form.push_widget(
+        "profile_submit",
+        Box::new(Button {
+            action: UiAction::SubmitForm,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 40.0,
+            },
+            label: "Save Profile",
+            style: ButtonStyle {
+                font: FontDescriptor::new("Consolas", 14.0).weight(600),
+                ..ButtonStyle::default()
+            },
+            focused: false,
+        }),
+        40.0,
+    );
+    form
+}
+
+fn make_hint_spans(pointer_down: bool) -> Vec<TextSpan> {
+    if pointer_down {
+        return vec![
+            TextSpan::new("Pointer down: "),
+            TextSpan::new("release").bold(),
+            TextSpan::new(" on button to trigger"),
+        ];
+    }
+    vec![
+        TextSpan::new("Tab").code(),
+        TextSpan::new(" focus | "),
+        TextSpan::new("Left/Right").code(),
+        TextSpan::new(" on tabs | "),
+        TextSpan::new("Open modal").link("modal"),
+    ]
+}
+
+pub struct TodoItem {
+    pub title: String,
+    pub note: Option<String>,
+    pub done: bool,
+}
+
+struct LogTableSource;
+
+impl TableDataSource for LogTableSource {
+    fn row_count(&self) -> usize {
+        DEMO_LOG_ROWS
+    }
+
+    fn cell(&self, row: usize, column: &'static str) -> CellValue {
+        match column {
+            "time" => CellValue::Text(format!(
+                "{:02}:{:02}:{:02}.{:03}",
+                9 + row / 3600 % 12,
+                row / 60 % 60,
+                row % 60,
+                row * 37 % 1000
+            )),
+            "level" => match row * 7 % 10 {
+                0 => CellValue::Badge {
+                    text: "ERROR".to_string(),
+                    color: "#ff6b6b",
+                },
+                1 | 2 => CellValue::Badge {
+                    text: "WARN".to_string(),
+                    color: "#ffd166",
+                },
+                _ => CellValue::Badge {
+                    text: "INFO".to_string(),
+                    color: "#27ffd8",
+                },
+            },
+            "service" => CellValue::Text(
+                ["api", "auth", "billing", "search", "worker"][row * 3 % 5].to_string(),
+            ),
+            "latency" => CellValue::Number {
+                value: (row * 7919 % 900) as f64 / 3.0 + 4.0,
+                precision: 1,
+            },
+            "cpu" => CellValue::Progress((row * 53 % 100) as f64 / 100.0),
+            _ => CellValue::Text(format!("request #{} handled", row + 1)),
+        }
+    }
+}
+
+fn render_latency_cell(
+    context: &CanvasRenderingContext2d,
+    rect: Rect,
+    value: &CellValue,
+    column: &TableColumn,
+    style: &TableStyle,
+) {
+    let text = match value {
+        CellValue::Number { value, .. } if *value >= 250.0 => "#ff6b6b",
+        CellValue::Number { value, .. } if *value >= 120.0 => "#ffd166",
+        _ => style.text,
+    };
+    render_text_cell(
+        context,
+        rect,
+        value,
+        column,
+        &TableStyle {
+            text,
+            ..*style
+        },
+    );
+}
+
+fn make_todos(count: usize) -> Vec<TodoItem> {
+    (1..=count)
+        .map(|index| TodoItem {
+            title: format!("Task {:04}", index),
+            note: (index % 7 == 0).then(|| {
+                format!(
+                    "Follow up on task {:04}: review the layout, check focus order and confirm the copy.",
+                    index
+                )
+            }),
+            done: index % 5 == 0,
+        })
         .collect()
 }
+
+fn describe_todos(todos: &[TodoItem]) -> String {
+    let done = todos.iter().filter(|todo| todo.done).count();
+    format!("{}/{}", done, todos.len())
+}
+
+struct TodoSource {
+    items: Rc<RefCell<Vec<TodoItem>>>,
+}
+
+impl ListDataSource for TodoSource {
+    fn item_count(&self) -> usize {
+        self.items.borrow().len()
+    }
+
+    fn item_text(&self, index: usize) -> String {
+        self.items
+            .borrow()
+            .get(index)
+            .map(|todo| todo.title.clone())
+            .unwrap_or_default()
+    }
+
+    fn build_row(&mut self, index: usize, recycled: Option<Box<dyn Widget>>) -> Option<Box<dyn Widget>> {
+        let items = self.items.borrow();
+        let todo = items.get(index)?;
+        let text = match &todo.note {
+            Some(note) => format!("{}\n{}", todo.title, note),
+            None => todo.title.clone(),
+        };
+        let mut row = recycled.unwrap_or_else(|| Box::new(TodoRow::new()));
+        if let Some(todo_row) = row.as_any_mut().downcast_mut::<TodoRow>() {
+            todo_row.done.set_value(todo.done);
+            todo_row.title.set_text(text);
+        }
+        Some(row)
+    }
+
+    fn row_event(&mut self, index: usize, event: &UiEvent) -> RowChange {
+        let mut items = self.items.borrow_mut();
+        match event {
+            UiEvent::ValueChanged {
+                key: "todo_done",
+                value,
+            } => {
+                if let Some(todo) = items.get_mut(index) {
+                    todo.done = value == "true";
+                }
+                RowChange::None
+            }
+            UiEvent::Action(UiAction::RemoveRow) if index < items.len() => {
+                items.remove(index);
+                RowChange::Removed
+            }
+            _ => RowChange::None,
+        }
+    }
+}
+
+struct TodoRow {
+    rect: Rect,
+    done: Checkbox,
+    title: Label,
+    remove: Button,
+}
+
+impl TodoRow {
+    fn new() -> Self {
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+        Self {
+            rect,
+            done: Checkbox {
+                key: "todo_done",
+                rect,
+                state: CheckState::Unchecked,
+                label: "",
+                focused: false,
+                style: CheckboxStyle::default(),
+            },
+            title: Label {
+                rect,
+                text: String::new(),
+                style: LabelStyle {
+                    align: TextAlign::Start,
+                    wrap: true,
+                    max_lines: Some(3),
+                    ..LabelStyle::default()
+                },
+                spans: Vec::new(),
+                measured: None,
+            },
+            remove: Button {
+                action: UiAction::RemoveRow,
+                rect,
+                label: "Remove",
+                style: ButtonStyle {
+                    font: FontDescriptor::new("Consolas", 12.0).weight(600),
+                    ..ButtonStyle::default()
+                },
+                focused: false,
+            },
+        }
+    }
+}
+
+impl Widget for TodoRow {
+    fn desired_size(&self) -> (f64, f64) {
+        let text_height = self.title.measured.map(|(_, height)| height).unwrap_or(0.0);
+        (self.rect.width, (text_height + 12.0).max(34.0))
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.done.set_rect(Rect {
+            x: rect.x + 8.0,
+            y: rect.y,
+            width: 24.0,
+            height: rect.height,
+        });
+        self.title.set_rect(Rect {
+            x: rect.x + 40.0,
+            y: rect.y,
+            width: (rect.width - 120.0).max(0.0),
+            height: rect.height,
+        });
+        self.remove.set_rect(Rect {
+            x: rect.x + rect.width - 72.0,
+            y: rect.y + (rect.height - 24.0) * 0.5,
+            width: 64.0,
+            height: 24.0,
+        });
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let mut events = self.done.draw(context, pointer);
+        events.extend(self.title.draw(context, pointer));
+        events.extend(self.remove.draw(context, pointer));
+        events
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        self.done.activate()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+fn describe_range(value: &str) -> String {
+    value
+        .split("..")
+        .map(|bound| format!("{:.0}", bound.parse::<f64>().unwrap_or(0.0)))
+        .collect::<Vec<_>>()
+        .join("..")
+}
+
+fn describe_profile(values: &FormValues) -> String {
+    let name = match values.get("profile_name") {
+        Some(FieldValue::Text(name)) => name.clone(),
+        _ => String::new(),
+    };
+    let retries = match values.get("profile_retries") {
+        Some(FieldValue::Number(retries)) => *retries,
+        _ => 0.0,
+    };
+    let time_zone = match values.get("profile_time_zone") {
+        Some(FieldValue::Text(time_zone)) => time_zone.clone(),
+        _ => String::new(),
+    };
+    let availability = match values.get("profile_availability") {
+        Some(FieldValue::Text(availability)) if !availability.is_empty() => availability.replace('/', " to "),
+        _ => "any time".to_string(),
+    };
+    format!("{} ({:.0} retries, {}, {})", name, retries, time_zone, availability)
+}
@@ -0,0 +1,207 @@
+use crate::core::locale::{DateOrder, Locale};
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        (day >= 1 && day <= days_in_month(year, month)).then_some(Self { year, month, day })
+    }
+
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    pub fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub fn from_unix_ms(ms: f64) -> Self {
+        Self::from_days((ms / 86_400_000.0).floor() as i64)
+    }
+
+    pub fn weekday(self) -> u32 {
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    pub fn clamp_to(self, min: Option<Date>, max: Option<Date>) -> Self {
+        let lower = min.map_or(self, |min| self.max(min));
+        max.map_or(lower, |max| lower.min(max))
+    }
+
+    pub fn parse_iso(text: &str) -> Option<Self> {
+        let mut parts = text.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Self::new(year, month, day)
+    }
+
+    pub fn parse_local(text: &str, locale: &Locale) -> Option<Self> {
+        if let Some(date) = Self::parse_iso(text) {
+            return Some(date);
+        }
+        let fields = text
+            .trim()
+            .split(|ch: char| !ch.is_ascii_digit())
+            .filter(|field| !field.is_empty())
+            .map(|field| field.parse::<i32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let [first, second, third] = fields[..] else {
+            return None;
+        };
+        let (year, month, day) = match locale.date_order {
+            DateOrder::YearMonthDay => (first, second, third),
+            DateOrder::MonthDayYear => (third, first, second),
+            DateOrder::DayMonthYear => (third, second, first),
+        };
+        let year = if (0..100).contains(&year) { year + 2000 } else { year };
+        Self::new(year, u32::try_from(month).ok()?, u32::try_from(day).ok()?)
+    }
+
+    pub fn format_local(self, locale: &Locale) -> String {
+        let separator = locale.date_separator;
+        match locale.date_order {
+            DateOrder::YearMonthDay => format!("{:04}{sep}{:02}{sep}{:02}", self.year, self.month, self.day, sep = separator),
+            DateOrder::MonthDayYear => format!("{:02}{sep}{:02}{sep}{:04}", self.month, self.day, self.year, sep = separator),
+            DateOrder::DayMonthYear => format!("{:02}{sep}{:02}{sep}{:04}", self.day, self.month, self.year, sep = separator),
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+pub fn month_grid(year: i32, month: u32, week_start: u32) -> Vec<Date> {
+    let first = Date { year, month, day: 1 };
+    let lead = (first.weekday() + 7 - week_start % 7) % 7;
+    let start = first.add_days(-i64::from(lead));
+    (0..42).map(|offset| start.add_days(offset)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn leap_years_follow_gregorian_rules() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2100, 2), 28);
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2024, 13, 1).is_none());
+    }
+
+    #[test]
+    fn days_round_trip_around_the_epoch() {
+        assert_eq!(Date::from_days(0), date(1970, 1, 1));
+        assert_eq!(Date::from_days(-1), date(1969, 12, 31));
+        assert_eq!(date(2000, 3, 1).to_days(), 11_017);
+        assert_eq!(date(2000, 2, 29).add_days(1), date(2000, 3, 1));
+        for days in (-800_000..800_000).step_by(997) {
+            assert_eq!(Date::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn weekday_counts_from_monday() {
+        assert_eq!(date(1970, 1, 1).weekday(), 3);
+        assert_eq!(date(2024, 2, 29).weekday(), 3);
+        assert_eq!(date(2023, 1, 1).weekday(), 6);
+    }
+
+    #[test]
+    fn month_grid_starts_on_the_locale_week_start() {
+        let monday_first = month_grid(2024, 2, Locale::DE_DE.week_start);
+        assert_eq!(monday_first.len(), 42);
+        assert_eq!(monday_first[0], date(2024, 1, 29));
+        assert_eq!(monday_first[3], date(2024, 2, 1));
+
+        let sunday_first = month_grid(2024, 2, Locale::EN_US.week_start);
+        assert_eq!(sunday_first[0], date(2024, 1, 28));
+        assert_eq!(sunday_first[4], date(2024, 2, 1));
+        assert_eq!(sunday_first[32], date(2024, 2, 29));
+
+        let no_lead = month_grid(2023, 1, 6);
+        assert_eq!(no_lead[0], date(2023, 1, 1));
+    }
+
+    #[test]
+    fn parse_local_uses_the_locale_field_order() {
+        assert_eq!(Date::parse_local("03/04/2024", &Locale::EN_US), Some(date(2024, 3, 4)));
+        assert_eq!(Date::parse_local("03/04/2024", &Locale::EN_GB), Some(date(2024, 4, 3)));
+        assert_eq!(Date::parse_local("29.2.24", &Locale::DE_DE), Some(date(2024, 2, 29)));
+        assert_eq!(Date::parse_local("29.2.23", &Locale::DE_DE), None);
+        assert_eq!(Date::parse_local("2024-02-29", &Locale::EN_US), Some(date(2024, 2, 29)));
+        assert_eq!(Date::parse_local("3/4", &Locale::EN_US), None);
+    }
+}
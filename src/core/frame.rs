@@ -0,0 +1,13 @@
+use std::cell::Cell;
+
+thread_local! {
+    static FRAME_REQUESTED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub fn request_frame() {
+    FRAME_REQUESTED.with(|requested| requested.set(true));
+}
+
+pub fn take_frame_request() -> bool {
+    FRAME_REQUESTED.with(|requested| requested.replace(false))
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EditKind {
+    Insert,
+    Delete,
+    Replace,
+}
+
+pub struct EditHistory<T: Clone> {
+    undo_stack: VecDeque<T>,
+    redo_stack: Vec<T>,
+    limit: usize,
+    open_run: Option<EditKind>,
+}
+
+impl<T: Clone> Default for EditHistory<T> {
+    fn default() -> Self {
+        Self::with_limit(200)
+    }
+}
+
+impl<T: Clone> EditHistory<T> {
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            limit: limit.max(1),
+            open_run: None,
+        }
+    }
+
+    pub fn record(&mut self, before: T, kind: EditKind) {
+        self.redo_stack.clear();
+        let coalesce = kind != EditKind::Replace && self.open_run == Some(kind);
+        if !coalesce {
+            self.undo_stack.push_back(before);
+            if self.undo_stack.len() > self.limit {
+                self.undo_stack.pop_front();
+            }
+        }
+        self.open_run = if kind == EditKind::Replace {
+            None
+        } else {
+            Some(kind)
+        };
+    }
+
+    pub fn seal(&mut self) {
+        self.open_run = None;
+    }
+
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo_stack.pop_back()?;
+        self.redo_stack.push(current);
+        self.open_run = None;
+        Some(previous)
+    }
+
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo_stack.pop()?;
+        self.undo_stack.push_back(current);
+        self.open_run = None;
+        Some(next)
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.open_run = None;
+    }
+
+    #[allow(dead_code)]
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_edits_of_one_kind_coalesce() {
+        let mut history = EditHistory::default();
+        history.record("", EditKind::Insert);
+        history.record("a", EditKind::Insert);
+        history.record("ab", EditKind::Insert);
+        history.record("abc", EditKind::Delete);
+        history.record("ab", EditKind::Delete);
+
+        assert_eq!(history.undo("a"), Some("abc"));
+        assert_eq!(history.undo("abc"), Some(""));
+        assert_eq!(history.undo(""), None);
+    }
+
+    #[test]
+    fn replace_and_seal_start_new_groups() {
+        let mut history = EditHistory::default();
+        history.record("", EditKind::Replace);
+        history.record("pasted", EditKind::Insert);
+        history.record("pasted!", EditKind::Insert);
+        history.seal();
+        history.record("pasted!?", EditKind::Insert);
+
+        assert_eq!(history.undo("pasted!?!"), Some("pasted!?"));
+        assert_eq!(history.undo("pasted!?"), Some("pasted"));
+        assert_eq!(history.undo("pasted"), Some(""));
+    }
+
+    #[test]
+    fn undo_stack_keeps_only_the_newest_entries() {
+        let mut history = EditHistory::default();
+        for value in 0..250 {
+            history.record(value, EditKind::Replace);
+        }
+        let mut undone = Vec::new();
+        let mut current = 250;
+        while let Some(previous) = history.undo(current) {
+            undone.push(previous);
+            current = previous;
+        }
+        assert_eq!(undone.len(), 200);
+        assert_eq!(undone.first(), Some(&249));
+        assert_eq!(undone.last(), Some(&50));
+
+        let mut tiny = EditHistory::with_limit(0);
+        tiny.record(1, EditKind::Replace);
+        tiny.record(2, EditKind::Replace);
+        assert_eq!(tiny.undo(3), Some(2));
+        assert!(!tiny.can_undo());
+    }
+
+    #[test]
+    fn recording_after_undo_clears_redo() {
+        let mut history = EditHistory::default();
+        history.record("", EditKind::Insert);
+        assert_eq!(history.undo("a"), Some(""));
+        assert_eq!(history.redo(""), Some("a"));
+        assert_eq!(history.undo("a"), Some(""));
+        assert!(history.can_redo());
+
+        history.record("", EditKind::Insert);
+        assert!(!history.can_redo());
+        assert_eq!(history.redo("b"), None);
+    }
+
+    #[test]
+    fn undo_and_redo_close_the_open_group() {
+        let mut history = EditHistory::default();
+        history.record("", EditKind::Insert);
+        assert_eq!(history.undo("a"), Some(""));
+        history.record("", EditKind::Insert);
+        history.record("x", EditKind::Insert);
+        assert_eq!(history.undo("xy"), Some(""));
+        assert_eq!(history.undo(""), None);
+    }
+}
@@ -1,3 +1,20 @@
+use crate::core::keymap::Shortcut;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PointerButton {
+    #[default]
+    Primary,
+    Secondary,
+    Middle,
+}
+
 #[derive(Default, Clone)]
 pub struct PointerState {
     pub x: f64,
@@ -5,6 +22,7 @@ pub struct PointerState {
     pub is_down: bool,
     pub just_pressed: bool,
     pub just_released: bool,
+    pub secondary_click: bool,
     pub activate_primary: bool,
     pub focus_next: bool,
     pub focus_prev: bool,
@@ -31,14 +49,32 @@ pub struct PointerState {
     pub copy: bool,
     pub cut: bool,
     pub paste: bool,
+    pub undo: bool,
+    pub redo: bool,
     pub cancel: bool,
+    pub shortcut: Option<Shortcut>,
+    pub scroll_x: f64,
     pub scroll_y: f64,
+    pub modifiers: Modifiers,
+    pub touch: bool,
+    pub time_ms: f64,
 }
 
 pub enum PointerSignal {
     Move { x: f64, y: f64 },
-    Down { x: f64, y: f64 },
-    Up { x: f64, y: f64 },
+    Down {
+        x: f64,
+        y: f64,
+        button: PointerButton,
+        modifiers: Modifiers,
+        touch: bool,
+    },
+    Up {
+        x: f64,
+        y: f64,
+        button: PointerButton,
+        modifiers: Modifiers,
+    },
     Leave,
     ActivatePrimary,
     FocusNext,
@@ -66,8 +102,16 @@ pub enum PointerSignal {
     Copy,
     Cut,
     Paste,
+    Undo,
+    Redo,
     Cancel,
-    Scroll { x: f64, y: f64, delta_y: f64 },
+    Shortcut(Shortcut),
+    Scroll {
+        x: f64,
+        y: f64,
+        delta_x: f64,
+        delta_y: f64,
+    },
 }
 
 impl PointerState {
@@ -98,7 +142,10 @@ impl PointerState {
         self.copy = false;
         self.cut = false;
         self.paste = false;
+        self.undo = false;
+        self.redo = false;
         self.cancel = false;
+        self.shortcut = None;
     }
 
     pub fn apply(&mut self, signal: PointerSignal) {
@@ -107,18 +154,34 @@ impl PointerState {
                 self.x = x;
                 self.y = y;
             }
-            PointerSignal::Down { x, y } => {
+            PointerSignal::Down {
+                x,
+                y,
+                button,
+                modifiers,
+                touch,
+            } => {
                 self.x = x;
                 self.y = y;
-                if !self.is_down {
+                self.modifiers = modifiers;
+                self.touch = touch;
+                if button == PointerButton::Secondary {
+                    self.secondary_click = true;
+                } else if button == PointerButton::Primary && !self.is_down {
                     self.is_down = true;
                     self.just_pressed = true;
                 }
             }
-            PointerSignal::Up { x, y } => {
+            PointerSignal::Up {
+                x,
+                y,
+                button,
+                modifiers,
+            } => {
                 self.x = x;
                 self.y = y;
-                if self.is_down {
+                self.modifiers = modifiers;
+                if button == PointerButton::Primary && self.is_down {
                     self.is_down = false;
                     self.just_released = true;
                 }
@@ -207,12 +270,27 @@ impl PointerState {
             PointerSignal::Paste => {
                 self.paste = true;
             }
+            PointerSignal::Undo => {
+                self.undo = true;
+            }
+            PointerSignal::Redo => {
+                self.redo = true;
+            }
             PointerSignal::Cancel => {
                 self.cancel = true;
             }
-            PointerSignal::Scroll { x, y, delta_y } => {
+            PointerSignal::Shortcut(shortcut) => {
+                self.shortcut = Some(shortcut);
+            }
+            PointerSignal::Scroll {
+                x,
+                y,
+                delta_x,
+                delta_y,
+            } => {
                 self.x = x;
                 self.y = y;
+                self.scroll_x += delta_x;
                 self.scroll_y += delta_y;
             }
         }
@@ -221,6 +299,7 @@ impl PointerState {
     pub fn reset_transient(&mut self) {
         self.just_pressed = false;
         self.just_released = false;
+        self.secondary_click = false;
         self.activate_primary = false;
         self.focus_next = false;
         self.focus_prev = false;
@@ -247,7 +326,11 @@ impl PointerState {
         self.copy = false;
         self.cut = false;
         self.paste = false;
+        self.undo = false;
+        self.redo = false;
         self.cancel = false;
+        self.shortcut = None;
+        self.scroll_x = 0.0;
         self.scroll_y = 0.0;
     }
 }
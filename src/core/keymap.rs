@@ -0,0 +1,122 @@
+use crate::core::input::Modifiers;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Shortcut {
+    pub key: String,
+    pub modifiers: Modifiers,
+}
+
+impl Shortcut {
+    pub fn new(key: &str, modifiers: Modifiers) -> Self {
+        Self {
+            key: normalize_key(key),
+            modifiers,
+        }
+    }
+
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut modifiers = Modifiers::default();
+        let mut key = None;
+        for part in text.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" | "control" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "" => return None,
+                _ if key.is_none() => key = Some(part),
+                _ => return None,
+            }
+        }
+        Some(Self::new(key?, modifiers))
+    }
+
+    pub fn label(&self) -> String {
+        let mut parts = Vec::new();
+        if self.modifiers.ctrl {
+            parts.push("Ctrl");
+        }
+        if self.modifiers.alt {
+            parts.push("Alt");
+        }
+        if self.modifiers.shift {
+            parts.push("Shift");
+        }
+        parts.push(&self.key);
+        parts.join("+")
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => ch.to_uppercase().collect(),
+        _ => key.to_string(),
+    }
+}
+
+pub const EDIT_SELECT_ALL: &str = "edit_select_all";
+pub const EDIT_COPY: &str = "edit_copy";
+pub const EDIT_CUT: &str = "edit_cut";
+pub const EDIT_PASTE: &str = "edit_paste";
+pub const EDIT_UNDO: &str = "edit_undo";
+pub const EDIT_REDO: &str = "edit_redo";
+
+#[derive(Clone, Default)]
+pub struct Keymap {
+    bindings: Vec<(Shortcut, &'static str)>,
+}
+
+impl Keymap {
+    pub fn bind(&mut self, shortcut: Shortcut, command: &'static str) {
+        self.bindings.retain(|(existing, _)| *existing != shortcut);
+        self.bindings.push((shortcut, command));
+    }
+
+    pub fn resolve(&self, shortcut: &Shortcut) -> Option<&'static str> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| bound == shortcut)
+            .map(|(_, command)| *command)
+    }
+
+    pub fn shortcut_for(&self, command: &str) -> Option<&Shortcut> {
+        self.bindings
+            .iter()
+            .find(|(_, bound)| *bound == command)
+            .map(|(shortcut, _)| shortcut)
+    }
+}
+
+pub fn editing_keymap() -> Keymap {
+    let mut keymap = Keymap::default();
+    for (shortcut, command) in [
+        ("Ctrl+A", EDIT_SELECT_ALL),
+        ("Ctrl+C", EDIT_COPY),
+        ("Ctrl+X", EDIT_CUT),
+        ("Ctrl+V", EDIT_PASTE),
+        ("Ctrl+Z", EDIT_UNDO),
+        ("Ctrl+Shift+Z", EDIT_REDO),
+        ("Ctrl+Y", EDIT_REDO),
+    ] {
+        if let Some(shortcut) = Shortcut::parse(shortcut) {
+            keymap.bind(shortcut, command);
+        }
+    }
+    keymap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn editing_keymap_resolves_undo_redo_and_clipboard() {
+        let keymap = editing_keymap();
+        let resolve = |text: &str| keymap.resolve(&Shortcut::parse(text).unwrap());
+        assert_eq!(resolve("Ctrl+Z"), Some(EDIT_UNDO));
+        assert_eq!(resolve("Cmd+Shift+z"), Some(EDIT_REDO));
+        assert_eq!(resolve("Ctrl+Y"), Some(EDIT_REDO));
+        assert_eq!(resolve("Ctrl+V"), Some(EDIT_PASTE));
+        assert_eq!(resolve("Alt+Z"), None);
+    }
+}
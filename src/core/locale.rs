@@ -0,0 +1,143 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DateOrder {
+    YearMonthDay,
+    MonthDayYear,
+    DayMonthYear,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Locale {
+    pub month_names: [&'static str; 12],
+    pub weekday_names: [&'static str; 7],
+    pub week_start: u32,
+    pub date_order: DateOrder,
+    pub date_separator: char,
+    pub decimal_separator: char,
+    pub group_separator: Option<char>,
+}
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const ENGLISH_WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+impl Locale {
+    pub const EN_US: Self = Self {
+        month_names: ENGLISH_MONTHS,
+        weekday_names: ENGLISH_WEEKDAYS,
+        week_start: 6,
+        date_order: DateOrder::MonthDayYear,
+        date_separator: '/',
+        decimal_separator: '.',
+        group_separator: Some(','),
+    };
+
+    #[allow(dead_code)]
+    pub const EN_GB: Self = Self {
+        month_names: ENGLISH_MONTHS,
+        weekday_names: ENGLISH_WEEKDAYS,
+        week_start: 0,
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '/',
+        decimal_separator: '.',
+        group_separator: Some(','),
+    };
+
+    pub const DE_DE: Self = Self {
+        month_names: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        weekday_names: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        week_start: 0,
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '.',
+        decimal_separator: ',',
+        group_separator: Some('.'),
+    };
+
+    #[allow(dead_code)]
+    pub const ISO: Self = Self {
+        month_names: ENGLISH_MONTHS,
+        weekday_names: ENGLISH_WEEKDAYS,
+        week_start: 0,
+        date_order: DateOrder::YearMonthDay,
+        date_separator: '-',
+        decimal_separator: '.',
+        group_separator: None,
+    };
+
+    pub fn month_name(&self, month: u32) -> &'static str {
+        self.month_names[(month.clamp(1, 12) - 1) as usize]
+    }
+
+    pub fn weekday_name(&self, weekday: u32) -> &'static str {
+        self.weekday_names[(weekday % 7) as usize]
+    }
+
+    pub fn format_number(&self, value: f64, precision: usize) -> String {
+        let fixed = format!("{:.*}", precision, value.abs());
+        let (whole, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let mut grouped = String::new();
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0
+                && (whole.len() - index) % 3 == 0
+                && let Some(separator) = self.group_separator
+            {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        let sign = if value < 0.0 && fixed.chars().any(|ch| ch != '0' && ch != '.') {
+            "-"
+        } else {
+            ""
+        };
+        if fraction.is_empty() {
+            format!("{}{}", sign, grouped)
+        } else {
+            format!("{}{}{}{}", sign, grouped, self.decimal_separator, fraction)
+        }
+    }
+
+    pub fn parse_number(&self, text: &str) -> Option<f64> {
+        let normalized = text
+            .trim()
+            .chars()
+            .filter(|ch| Some(*ch) != self.group_separator && !ch.is_whitespace() && *ch != '\'')
+            .map(|ch| if ch == self.decimal_separator { '.' } else { ch })
+            .collect::<String>();
+        if normalized.is_empty() || !normalized.chars().all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | '-' | '+')) {
+            return None;
+        }
+        normalized.parse::<f64>().ok().filter(|value| value.is_finite())
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::EN_US
+    }
+}
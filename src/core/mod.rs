@@ -1,3 +1,10 @@
+pub mod date;
+pub mod frame;
 pub mod geometry;
+pub mod history;
 pub mod input;
+pub mod keymap;
+pub mod locale;
 pub mod navigation;
+pub mod selection;
+pub mod validation;
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 pub fn step_clamped(current: Option<usize>, delta: isize, len: usize) -> Option<usize> {
     if len == 0 {
         return None;
@@ -55,3 +57,260 @@ pub fn find_next_prefix(items: &[String], query: &str, start_after: Option<usize
     None
 }
 
+pub const TYPE_AHEAD_TIMEOUT_MS: f64 = 800.0;
+
+pub type TypeAheadMatcher = fn(&[String], &str, Option<usize>) -> Option<usize>;
+
+#[derive(Clone, Default)]
+pub struct TypeAhead {
+    buffer: String,
+    last_input_ms: f64,
+}
+
+impl TypeAhead {
+    pub fn push(&mut self, text: &str, time_ms: f64) -> &str {
+        if time_ms - self.last_input_ms > TYPE_AHEAD_TIMEOUT_MS {
+            self.buffer.clear();
+        }
+        self.last_input_ms = time_ms;
+        self.buffer.push_str(text);
+        &self.buffer
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    #[allow(dead_code)]
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn search(
+        &mut self,
+        items: &[String],
+        text: &str,
+        time_ms: f64,
+        current: Option<usize>,
+        matcher: TypeAheadMatcher,
+    ) -> Option<usize> {
+        if text.trim().is_empty() && self.buffer.is_empty() {
+            return None;
+        }
+        let buffer = self.push(text, time_ms).to_string();
+        let mut chars = buffer.chars();
+        let first = chars.next()?;
+        let repeated = chars.all(|ch| chars_equal_folded(ch, first));
+
+        if repeated {
+            let needle = first.to_string();
+            return matcher(items, &needle, current);
+        }
+        let start_after = current.and_then(|index| index.checked_sub(1));
+        matcher(items, &buffer, start_after).or_else(|| {
+            let needle = text.to_string();
+            self.buffer = needle.clone();
+            matcher(items, &needle, current)
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuzzyMatch {
+    pub index: usize,
+    pub score: i32,
+    pub ranges: Vec<Range<usize>>,
+}
+
+const MATCH_SCORE: i32 = 16;
+const CONSECUTIVE_BONUS: i32 = 12;
+const FIRST_CHAR_BONUS: i32 = 12;
+const WORD_START_BONUS: i32 = 10;
+const GAP_START_PENALTY: i32 = 5;
+const GAP_EXTENSION_PENALTY: i32 = 1;
+const MAX_LEADING_PENALTY: i32 = 8;
+
+fn chars_equal_folded(left: char, right: char) -> bool {
+    left == right || left.to_lowercase().eq(right.to_lowercase())
+}
+
+fn word_start_bonus(previous: Option<char>, current: char) -> i32 {
+    match previous {
+        None => FIRST_CHAR_BONUS,
+        Some(previous) if !previous.is_alphanumeric() => WORD_START_BONUS,
+        Some(previous) if previous.is_lowercase() && current.is_uppercase() => WORD_START_BONUS,
+        Some(previous) if !previous.is_numeric() && current.is_numeric() => WORD_START_BONUS / 2,
+        _ => 0,
+    }
+}
+
+pub fn fuzzy_score(candidate: &str, query: &str) -> Option<(i32, Vec<Range<usize>>)> {
+    let needle = query.chars().filter(|ch| !ch.is_whitespace()).collect::<Vec<_>>();
+    if needle.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let haystack = candidate.char_indices().collect::<Vec<_>>();
+    if haystack.len() < needle.len() {
+        return None;
+    }
+
+    let mut cursor = 0;
+    for ch in &needle {
+        let offset = haystack[cursor..]
+            .iter()
+            .position(|(_, candidate)| chars_equal_folded(*candidate, *ch))?;
+        cursor += offset + 1;
+    }
+
+    let columns = haystack.len();
+    let bonuses = haystack
+        .iter()
+        .enumerate()
+        .map(|(position, (_, ch))| word_start_bonus(position.checked_sub(1).map(|previous| haystack[previous].1), *ch))
+        .collect::<Vec<_>>();
+    let mut scores = vec![vec![None::<i32>; columns]; needle.len()];
+    let mut parents = vec![vec![0usize; columns]; needle.len()];
+
+    for (row, ch) in needle.iter().enumerate() {
+        let mut gapped = None::<(i32, usize)>;
+        for column in row..columns {
+            if row > 0 && column >= 2 {
+                let extended = gapped.map(|(score, previous)| (score - GAP_EXTENSION_PENALTY, previous));
+                let opened = scores[row - 1][column - 2].map(|score| (score - GAP_START_PENALTY, column - 2));
+                gapped = match (extended, opened) {
+                    (Some(extended), Some(opened)) if opened.0 >= extended.0 => Some(opened),
+                    (Some(extended), _) => Some(extended),
+                    (None, opened) => opened,
+                };
+            }
+            if !chars_equal_folded(haystack[column].1, *ch) {
+                continue;
+            }
+            let base = MATCH_SCORE + bonuses[column];
+            if row == 0 {
+                let leading = (column as i32).min(MAX_LEADING_PENALTY);
+                scores[row][column] = Some(base - leading);
+                continue;
+            }
+            let consecutive = scores[row - 1][column - 1]
+                .map(|score| (score + MATCH_SCORE + CONSECUTIVE_BONUS.max(bonuses[column]), column - 1));
+            let skipped = gapped.map(|(score, previous)| (score + base, previous));
+            let best = match (consecutive, skipped) {
+                (Some(consecutive), Some(skipped)) if skipped.0 > consecutive.0 => Some(skipped),
+                (Some(consecutive), _) => Some(consecutive),
+                (None, skipped) => skipped,
+            };
+            if let Some((score, previous)) = best {
+                scores[row][column] = Some(score);
+                parents[row][column] = previous;
+            }
+        }
+    }
+
+    let last = needle.len() - 1;
+    let (mut column, score) = scores[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(column, score)| score.map(|score| (column, score)))
+        .max_by_key(|(column, score)| (*score, std::cmp::Reverse(*column)))?;
+
+    let mut positions = vec![0; needle.len()];
+    for row in (0..needle.len()).rev() {
+        positions[row] = column;
+        column = parents[row][column];
+    }
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for position in positions {
+        let (start, ch) = haystack[position];
+        let end = start + ch.len_utf8();
+        match ranges.last_mut() {
+            Some(range) if range.end == start => range.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    Some((score, ranges))
+}
+
+pub fn fuzzy_rank(items: &[String], query: &str) -> Vec<FuzzyMatch> {
+    if query.trim().is_empty() {
+        return (0..items.len())
+            .map(|index| FuzzyMatch {
+                index,
+                score: 0,
+                ranges: Vec::new(),
+            })
+            .collect();
+    }
+
+    let mut ranked = items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            let (score, ranges) = fuzzy_score(item, query)?;
+            Some(FuzzyMatch { index, score, ranges })
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|left, right| {
+        right
+            .score
+            .cmp(&left.score)
+            .then_with(|| items[left.index].len().cmp(&items[right.index].len()))
+            .then_with(|| left.index.cmp(&right.index))
+    });
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|label| label.to_string()).collect()
+    }
+
+    #[test]
+    fn fuzzy_score_reports_merged_byte_ranges() {
+        let (_, ranges) = fuzzy_score("settings", "set").unwrap();
+        assert_eq!(ranges, vec![0..3]);
+
+        let (_, ranges) = fuzzy_score("FileOpen", "fo").unwrap();
+        assert_eq!(ranges, vec![0..1, 4..5]);
+
+        let (_, ranges) = fuzzy_score("Über", "üb").unwrap();
+        assert_eq!(ranges, vec![0..3]);
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_missing_or_out_of_order_chars() {
+        assert_eq!(fuzzy_score("abc", "abd"), None);
+        assert_eq!(fuzzy_score("abc", "cba"), None);
+        assert_eq!(fuzzy_score("ab", "abc"), None);
+        assert_eq!(fuzzy_score("anything", "  "), Some((0, Vec::new())));
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_word_starts_and_runs() {
+        let (run, _) = fuzzy_score("abc", "abc").unwrap();
+        let (word_starts, _) = fuzzy_score("a_b_c", "abc").unwrap();
+        let (scattered, _) = fuzzy_score("xaxbxc", "abc").unwrap();
+        assert!(run > word_starts);
+        assert!(word_starts > scattered);
+    }
+
+    #[test]
+    fn fuzzy_rank_orders_by_score_then_length_then_index() {
+        let ranked = fuzzy_rank(&items(&["xaxbxc", "abc", "a_b_c", "nope"]), "abc");
+        assert_eq!(ranked.iter().map(|entry| entry.index).collect::<Vec<_>>(), vec![1, 2, 0]);
+
+        let ranked = fuzzy_rank(&items(&["abcdef", "abc", "abcd"]), "abc");
+        assert_eq!(ranked.iter().map(|entry| entry.index).collect::<Vec<_>>(), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn fuzzy_rank_keeps_every_item_for_blank_query() {
+        let ranked = fuzzy_rank(&items(&["b", "a"]), " ");
+        assert_eq!(ranked.iter().map(|entry| entry.index).collect::<Vec<_>>(), vec![0, 1]);
+        assert!(ranked.iter().all(|entry| entry.ranges.is_empty()));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::core::input::Modifiers;
+use std::collections::BTreeSet;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum SelectionMode {
+    None,
+    Single,
+    Multiple,
+    Range,
+}
+
+#[derive(Clone)]
+pub struct SelectionModel {
+    pub mode: SelectionMode,
+    selected: BTreeSet<usize>,
+    anchor: Option<usize>,
+    lead: Option<usize>,
+}
+
+impl SelectionModel {
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            selected: BTreeSet::new(),
+            anchor: None,
+            lead: None,
+        }
+    }
+
+    pub fn lead(&self) -> Option<usize> {
+        self.lead
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    pub fn indices(&self) -> Vec<usize> {
+        self.selected.iter().copied().collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.selected.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    pub fn clear(&mut self) -> bool {
+        let changed = !self.selected.is_empty();
+        self.selected.clear();
+        self.anchor = None;
+        self.lead = None;
+        changed
+    }
+
+    pub fn select_only(&mut self, index: usize) -> bool {
+        if self.mode == SelectionMode::None {
+            return false;
+        }
+        let changed = self.selected.len() != 1 || !self.selected.contains(&index);
+        self.selected.clear();
+        self.selected.insert(index);
+        self.anchor = Some(index);
+        self.lead = Some(index);
+        changed
+    }
+
+    pub fn toggle(&mut self, index: usize) -> bool {
+        match self.mode {
+            SelectionMode::None => false,
+            SelectionMode::Single | SelectionMode::Range => self.select_only(index),
+            SelectionMode::Multiple => {
+                if !self.selected.remove(&index) {
+                    self.selected.insert(index);
+                }
+                self.anchor = Some(index);
+                self.lead = Some(index);
+                true
+            }
+        }
+    }
+
+    pub fn extend_to(&mut self, index: usize, additive: bool) -> bool {
+        match self.mode {
+            SelectionMode::None => false,
+            SelectionMode::Single => self.select_only(index),
+            SelectionMode::Multiple | SelectionMode::Range => {
+                let anchor = self.anchor.unwrap_or(index);
+                let before = self.selected.clone();
+                if !(additive && self.mode == SelectionMode::Multiple) {
+                    self.selected.clear();
+                }
+                self.selected.extend(anchor.min(index)..=anchor.max(index));
+                self.anchor = Some(anchor);
+                self.lead = Some(index);
+                self.selected != before
+            }
+        }
+    }
+
+    pub fn select_all(&mut self, count: usize) -> bool {
+        if !matches!(self.mode, SelectionMode::Multiple | SelectionMode::Range) || count == 0 {
+            return false;
+        }
+        let changed = self.selected.len() != count;
+        self.selected = (0..count).collect();
+        self.anchor = Some(0);
+        self.lead = Some(self.lead.unwrap_or(0).min(count - 1));
+        changed
+    }
+
+    pub fn click(&mut self, index: usize, modifiers: Modifiers) -> bool {
+        if modifiers.shift {
+            self.extend_to(index, modifiers.ctrl)
+        } else if modifiers.ctrl {
+            self.toggle(index)
+        } else {
+            self.select_only(index)
+        }
+    }
+
+    pub fn remap(&mut self, map: impl Fn(usize) -> Option<usize>) {
+        self.selected = self.selected.iter().filter_map(|index| map(*index)).collect();
+        self.anchor = self.anchor.and_then(&map);
+        self.lead = self.lead.and_then(&map);
+    }
+
+    pub fn truncate(&mut self, count: usize) -> bool {
+        let before = self.selected.len();
+        self.selected.retain(|index| *index < count);
+        if self.lead.is_some_and(|lead| lead >= count) {
+            self.lead = count.checked_sub(1);
+        }
+        if self.anchor.is_some_and(|anchor| anchor >= count) {
+            self.anchor = self.lead;
+        }
+        self.selected.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: Modifiers = Modifiers { ctrl: false, shift: false, alt: false };
+    const CTRL: Modifiers = Modifiers { ctrl: true, shift: false, alt: false };
+    const SHIFT: Modifiers = Modifiers { ctrl: false, shift: true, alt: false };
+    const CTRL_SHIFT: Modifiers = Modifiers { ctrl: true, shift: true, alt: false };
+
+    #[test]
+    fn multiple_mode_clicks_follow_modifiers() {
+        let mut selection = SelectionModel::new(SelectionMode::Multiple);
+        assert!(selection.click(2, PLAIN));
+        assert!(selection.click(5, CTRL));
+        assert_eq!(selection.indices(), vec![2, 5]);
+
+        assert!(selection.click(7, SHIFT));
+        assert_eq!(selection.indices(), vec![5, 6, 7]);
+        assert_eq!(selection.lead(), Some(7));
+
+        assert!(selection.click(3, CTRL_SHIFT));
+        assert_eq!(selection.indices(), vec![3, 4, 5, 6, 7]);
+
+        assert!(selection.click(5, CTRL));
+        assert!(!selection.is_selected(5));
+        assert!(selection.click(7, CTRL_SHIFT));
+        assert_eq!(selection.indices(), vec![3, 4, 5, 6, 7]);
+        assert!(!selection.click(6, CTRL_SHIFT));
+    }
+
+    #[test]
+    fn range_and_single_modes_never_keep_disjoint_rows() {
+        let mut range = SelectionModel::new(SelectionMode::Range);
+        range.click(1, PLAIN);
+        range.click(4, CTRL);
+        assert_eq!(range.indices(), vec![4]);
+        range.click(2, CTRL_SHIFT);
+        assert_eq!(range.indices(), vec![2, 3, 4]);
+
+        let mut single = SelectionModel::new(SelectionMode::Single);
+        single.click(1, PLAIN);
+        single.click(4, SHIFT);
+        assert_eq!(single.indices(), vec![4]);
+        assert!(!single.select_all(10));
+
+        let mut none = SelectionModel::new(SelectionMode::None);
+        assert!(!none.click(1, PLAIN));
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn remap_follows_removed_rows() {
+        let mut selection = SelectionModel::new(SelectionMode::Multiple);
+        selection.click(1, PLAIN);
+        selection.click(3, CTRL);
+        selection.click(5, CTRL);
+        selection.remap(|index| match index {
+            3 => None,
+            index if index > 3 => Some(index - 1),
+            index => Some(index),
+        });
+        assert_eq!(selection.indices(), vec![1, 4]);
+        assert_eq!(selection.lead(), Some(4));
+
+        selection.click(1, SHIFT);
+        assert_eq!(selection.indices(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn truncate_drops_rows_past_the_end() {
+        let mut selection = SelectionModel::new(SelectionMode::Multiple);
+        assert!(selection.select_all(6));
+        selection.click(5, CTRL);
+        selection.click(5, CTRL);
+        assert!(selection.truncate(3));
+        assert_eq!(selection.indices(), vec![0, 1, 2]);
+        assert_eq!(selection.lead(), Some(2));
+        assert!(!selection.truncate(3));
+        assert_eq!(selection.len(), 3);
+    }
+}
@@ -0,0 +1,208 @@
+type ValidateFn = dyn Fn(&str) -> Option<String>;
+
+pub struct Validator {
+    check: Box<ValidateFn>,
+}
+
+impl Validator {
+    pub fn new(check: impl Fn(&str) -> Option<String> + 'static) -> Self {
+        Self {
+            check: Box::new(check),
+        }
+    }
+
+    pub fn required(message: &'static str) -> Self {
+        Self::new(move |value| {
+            if value.trim().is_empty() {
+                Some(message.to_string())
+            } else {
+                None
+            }
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn min_length(min: usize, message: &'static str) -> Self {
+        Self::new(move |value| {
+            if value.chars().count() < min {
+                Some(message.to_string())
+            } else {
+                None
+            }
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn chars(allowed: fn(char) -> bool, message: &'static str) -> Self {
+        Self::new(move |value| {
+            if value.chars().all(allowed) {
+                None
+            } else {
+                Some(message.to_string())
+            }
+        })
+    }
+
+    pub fn validate(&self, value: &str) -> Option<String> {
+        (self.check)(value)
+    }
+}
+
+pub fn first_error(validators: &[Validator], value: &str) -> Option<String> {
+    validators
+        .iter()
+        .find_map(|validator| validator.validate(value))
+}
+
+#[derive(Clone, Copy)]
+pub struct InputMask {
+    pub pattern: &'static str,
+}
+
+impl InputMask {
+    #[allow(dead_code)]
+    pub fn phone() -> Self {
+        Self {
+            pattern: "(999) 999-9999",
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn date() -> Self {
+        Self {
+            pattern: "9999-99-99",
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn credit_card() -> Self {
+        Self {
+            pattern: "9999 9999 9999 9999",
+        }
+    }
+
+    fn slot_accepts(slot: char, ch: char) -> Option<bool> {
+        match slot {
+            '9' => Some(ch.is_ascii_digit()),
+            'a' => Some(ch.is_alphabetic()),
+            '*' => Some(ch.is_alphanumeric()),
+            _ => None,
+        }
+    }
+
+    pub fn is_literal_at(&self, char_index: usize) -> bool {
+        self.pattern
+            .chars()
+            .nth(char_index)
+            .map(|slot| Self::slot_accepts(slot, ' ').is_none())
+            .unwrap_or(false)
+    }
+
+    pub fn accepts_any_slot(&self, ch: char) -> bool {
+        self.pattern
+            .chars()
+            .any(|slot| Self::slot_accepts(slot, ch) == Some(true))
+    }
+
+    pub fn apply(&self, value: &str, cursor: usize) -> (String, usize) {
+        let raw_before_cursor = self.raw_chars(&value[..cursor.min(value.len())]).len();
+        let mut raw = self.raw_chars(value).into_iter().peekable();
+        let mut formatted = String::new();
+        let mut next_cursor = 0;
+        let mut consumed = 0;
+
+        for slot in self.pattern.chars() {
+            if raw.peek().is_none() {
+                break;
+            }
+            match Self::slot_accepts(slot, ' ') {
+                None => formatted.push(slot),
+                Some(_) => {
+                    let Some(ch) = raw.by_ref().find(|ch| Self::slot_accepts(slot, *ch) == Some(true))
+                    else {
+                        break;
+                    };
+                    formatted.push(ch);
+                    consumed += 1;
+                }
+            }
+            if consumed <= raw_before_cursor {
+                next_cursor = formatted.len();
+            }
+        }
+
+        (formatted, next_cursor)
+    }
+
+    fn raw_chars(&self, value: &str) -> Vec<char> {
+        let literals = self
+            .pattern
+            .chars()
+            .filter(|slot| Self::slot_accepts(*slot, ' ').is_none())
+            .collect::<Vec<_>>();
+        value
+            .chars()
+            .filter(|ch| !literals.contains(ch) && self.accepts_any_slot(*ch))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phone_mask_inserts_literals_as_digits_arrive() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.apply("", 0), (String::new(), 0));
+        assert_eq!(mask.apply("555", 3), ("(555".to_string(), 4));
+        assert_eq!(mask.apply("5551234567", 10), ("(555) 123-4567".to_string(), 14));
+        assert_eq!(mask.apply("(555) 123-4567", 14), ("(555) 123-4567".to_string(), 14));
+    }
+
+    #[test]
+    fn date_and_card_masks_format_raw_digits() {
+        assert_eq!(InputMask::date().apply("20240229", 8), ("2024-02-29".to_string(), 10));
+        assert_eq!(InputMask::date().apply("2024/1", 6), ("2024-1".to_string(), 6));
+        assert_eq!(
+            InputMask::credit_card().apply("4111111111111111", 16),
+            ("4111 1111 1111 1111".to_string(), 19)
+        );
+    }
+
+    #[test]
+    fn cursor_keeps_its_raw_position_across_literals() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.apply("5551", 3), ("(555) 1".to_string(), 6));
+        assert_eq!(mask.apply("(555) 1234", 6), ("(555) 123-4".to_string(), 6));
+        assert_eq!(mask.apply("(555) 1234", 1), ("(555) 123-4".to_string(), 1));
+        assert_eq!(InputMask::credit_card().apply("41111111", 4), ("4111 1111".to_string(), 5));
+    }
+
+    #[test]
+    fn rejected_and_overflowing_input_is_dropped() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.apply("abc", 3), (String::new(), 0));
+        assert_eq!(mask.apply("55x5-12", 7), ("(555) 12".to_string(), 8));
+        assert_eq!(mask.apply("555123456789", 12), ("(555) 123-4567".to_string(), 14));
+        assert_eq!(
+            InputMask::credit_card().apply("4111 1111 1111 1111 99", 22),
+            ("4111 1111 1111 1111".to_string(), 19)
+        );
+
+        let plate = InputMask { pattern: "aa-99" };
+        assert_eq!(plate.apply("ab12", 4), ("ab-12".to_string(), 5));
+        assert_eq!(plate.apply("1ab2", 4), ("ab-2".to_string(), 4));
+    }
+
+    #[test]
+    fn literal_positions_follow_the_pattern() {
+        let mask = InputMask::phone();
+        assert!(mask.is_literal_at(0));
+        assert!(!mask.is_literal_at(1));
+        assert!(mask.is_literal_at(4));
+        assert!(!mask.is_literal_at(14));
+        assert!(mask.accepts_any_slot('7'));
+        assert!(!mask.accepts_any_slot('x'));
+    }
+}
@@ -2,6 +2,7 @@ mod app;
 mod core;
 mod platform;
 mod render;
+mod text;
 mod theme;
 mod ui;
 mod widgets;
@@ -0,0 +1,75 @@
+use crate::text::font::{with_registry, FontFaceSpec, FontSource, FontStyle};
+use std::cell::RefCell;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, FontFace, FontFaceLoadStatus};
+
+struct LoadingFace {
+    family: &'static str,
+    weight: u16,
+    style: FontStyle,
+    face: FontFace,
+}
+
+thread_local! {
+    static LOADING_FACES: RefCell<Vec<LoadingFace>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn start_font_loading(document: &Document, on_settled: impl Fn() + 'static) -> Result<(), JsValue> {
+    let font_set = document.fonts();
+    let on_loading_settled = Closure::<dyn FnMut(_)>::new(move |_event: Event| {
+        LOADING_FACES.with(|faces| {
+            with_registry(|registry| {
+                faces.borrow_mut().retain(|loading| {
+                    let loaded = match loading.face.status() {
+                        FontFaceLoadStatus::Loaded => true,
+                        FontFaceLoadStatus::Error => false,
+                        _ => return true,
+                    };
+                    registry.mark_loaded(loading.family, loading.weight, loading.style, loaded);
+                    false
+                });
+                registry.invalidate();
+            });
+        });
+        on_settled();
+    });
+    font_set.add_event_listener_with_callback("loadingdone", on_loading_settled.as_ref().unchecked_ref())?;
+    font_set.add_event_listener_with_callback("loadingerror", on_loading_settled.as_ref().unchecked_ref())?;
+    on_loading_settled.forget();
+    load_queued_faces(document)
+}
+
+pub fn load_queued_faces(document: &Document) -> Result<(), JsValue> {
+    let queued = with_registry(|registry| registry.take_queued());
+    if queued.is_empty() {
+        return Ok(());
+    }
+    let font_set = document.fonts();
+    for spec in queued {
+        let face = create_face(&spec)?;
+        font_set.add(&face)?;
+        let _ = face.load();
+        LOADING_FACES.with(|faces| {
+            faces.borrow_mut().push(LoadingFace {
+                family: spec.family,
+                weight: spec.weight,
+                style: spec.style,
+                face,
+            })
+        });
+    }
+    Ok(())
+}
+
+fn create_face(spec: &FontFaceSpec) -> Result<FontFace, JsValue> {
+    let face = match &spec.source {
+        FontSource::Url(url) => FontFace::new_with_str(spec.family, &format!("url(\"{}\")", url))?,
+        FontSource::Local(name) => FontFace::new_with_str(spec.family, &format!("local(\"{}\")", name))?,
+        FontSource::Bytes(bytes) => FontFace::new_with_u8_array(spec.family, bytes)?,
+    };
+    face.set_weight(&spec.weight.to_string());
+    face.set_style(spec.style.css());
+    Ok(face)
+}
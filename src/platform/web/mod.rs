@@ -1,4 +1,4 @@
+mod fonts;
 mod runtime;
 
 pub use runtime::start;
-
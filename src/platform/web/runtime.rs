@@ -1,12 +1,18 @@
 use crate::app::demo::DemoApp;
-use crate::core::input::PointerSignal;
+use crate::core::frame;
+use crate::core::input::{Modifiers, PointerButton, PointerSignal};
+use crate::core::keymap::{
+    EDIT_COPY, EDIT_CUT, EDIT_PASTE, EDIT_REDO, EDIT_SELECT_ALL, EDIT_UNDO, Shortcut,
+    editing_keymap,
+};
+use crate::platform::web::fonts;
 use crate::render::canvas2d;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, KeyboardEvent, PointerEvent, WheelEvent, Window};
+use web_sys::{CanvasRenderingContext2d, Event, HtmlCanvasElement, KeyboardEvent, PointerEvent, WheelEvent, Window};
 
 pub fn start() -> Result<(), JsValue> {
     let window = web_sys::window().ok_or_else(|| JsValue::from_str("window not available"))?;
@@ -34,48 +40,32 @@ pub fn start() -> Result<(), JsValue> {
         let context_ref = context.clone();
         let canvas_ref = canvas.clone();
         let window_ref = window.clone();
+        fonts::start_font_loading(&document, move || {
+            if let Err(error) = render_now(&app_ref, &context_ref, &canvas_ref, &window_ref) {
+                web_sys::console::error_1(&error);
+            }
+        })?;
+    }
+
+    {
+        let app_ref = Rc::clone(&app);
+        let context_ref = context.clone();
+        let canvas_ref = canvas.clone();
+        let window_ref = window.clone();
+        let editing = editing_keymap();
         let on_keydown = Closure::<dyn FnMut(_)>::new(move |event: KeyboardEvent| {
             let key = event.key();
-            if (event.ctrl_key() || event.meta_key()) && key.eq_ignore_ascii_case("a") {
-                event.prevent_default();
-                dispatch_and_render(
-                    &app_ref,
-                    &context_ref,
-                    &canvas_ref,
-                    &window_ref,
-                    PointerSignal::SelectAll,
-                );
-                return;
-            } else if (event.ctrl_key() || event.meta_key()) && key.eq_ignore_ascii_case("c") {
-                event.prevent_default();
-                dispatch_and_render(
-                    &app_ref,
-                    &context_ref,
-                    &canvas_ref,
-                    &window_ref,
-                    PointerSignal::Copy,
-                );
-                return;
-            } else if (event.ctrl_key() || event.meta_key()) && key.eq_ignore_ascii_case("x") {
-                event.prevent_default();
-                dispatch_and_render(
-                    &app_ref,
-                    &context_ref,
-                    &canvas_ref,
-                    &window_ref,
-                    PointerSignal::Cut,
-                );
-                return;
-            } else if (event.ctrl_key() || event.meta_key()) && key.eq_ignore_ascii_case("v") {
-                event.prevent_default();
-                dispatch_and_render(
-                    &app_ref,
-                    &context_ref,
-                    &canvas_ref,
-                    &window_ref,
-                    PointerSignal::Paste,
-                );
-                return;
+            if let Some(shortcut) = keyboard_shortcut(&event) {
+                let signal = if app_ref.borrow().handles_shortcut(&shortcut) {
+                    Some(PointerSignal::Shortcut(shortcut))
+                } else {
+                    editing.resolve(&shortcut).and_then(editing_signal)
+                };
+                if let Some(signal) = signal {
+                    event.prevent_default();
+                    dispatch_and_render(&app_ref, &context_ref, &canvas_ref, &window_ref, signal);
+                    return;
+                }
             }
             if key == "Enter" || key == " " {
                 event.prevent_default();
@@ -222,7 +212,7 @@ pub fn start() -> Result<(), JsValue> {
                     &window_ref,
                     PointerSignal::Cancel,
                 );
-            } else if key.len() == 1 && !event.ctrl_key() && !event.meta_key() {
+            } else if key.chars().count() == 1 && (event.alt_key() || !(event.ctrl_key() || event.meta_key())) {
                 dispatch_and_render(
                     &app_ref,
                     &context_ref,
@@ -267,7 +257,13 @@ pub fn start() -> Result<(), JsValue> {
                 &context_ref,
                 &canvas_ref,
                 &window_ref,
-                PointerSignal::Down { x, y },
+                PointerSignal::Down {
+                    x,
+                    y,
+                    button: pointer_button(&event),
+                    modifiers: pointer_modifiers(&event),
+                    touch: event.pointer_type() == "touch",
+                },
             );
         });
         canvas.add_event_listener_with_callback("pointerdown", on_down.as_ref().unchecked_ref())?;
@@ -286,7 +282,12 @@ pub fn start() -> Result<(), JsValue> {
                 &context_ref,
                 &canvas_ref,
                 &window_ref,
-                PointerSignal::Up { x, y },
+                PointerSignal::Up {
+                    x,
+                    y,
+                    button: pointer_button(&event),
+                    modifiers: pointer_modifiers(&event),
+                },
             );
         });
         canvas.add_event_listener_with_callback("pointerup", on_up.as_ref().unchecked_ref())?;
@@ -294,6 +295,14 @@ pub fn start() -> Result<(), JsValue> {
         on_up.forget();
     }
 
+    {
+        let on_context_menu = Closure::<dyn FnMut(_)>::new(move |event: Event| {
+            event.prevent_default();
+        });
+        canvas.add_event_listener_with_callback("contextmenu", on_context_menu.as_ref().unchecked_ref())?;
+        on_context_menu.forget();
+    }
+
     {
         let app_ref = Rc::clone(&app);
         let context_ref = context.clone();
@@ -332,6 +341,7 @@ pub fn start() -> Result<(), JsValue> {
                 PointerSignal::Scroll {
                     x,
                     y,
+                    delta_x: event.delta_x(),
                     delta_y: event.delta_y(),
                 },
             );
@@ -343,14 +353,106 @@ pub fn start() -> Result<(), JsValue> {
     Ok(())
 }
 
+fn pointer_button(event: &PointerEvent) -> PointerButton {
+    match event.button() {
+        1 => PointerButton::Middle,
+        2 => PointerButton::Secondary,
+        _ => PointerButton::Primary,
+    }
+}
+
+fn editing_signal(command: &str) -> Option<PointerSignal> {
+    match command {
+        EDIT_SELECT_ALL => Some(PointerSignal::SelectAll),
+        EDIT_COPY => Some(PointerSignal::Copy),
+        EDIT_CUT => Some(PointerSignal::Cut),
+        EDIT_PASTE => Some(PointerSignal::Paste),
+        EDIT_UNDO => Some(PointerSignal::Undo),
+        EDIT_REDO => Some(PointerSignal::Redo),
+        _ => None,
+    }
+}
+
+fn keyboard_shortcut(event: &KeyboardEvent) -> Option<Shortcut> {
+    let key = event.key();
+    let function_key = key.len() > 1 && key.starts_with('F') && key[1..].parse::<u8>().is_ok();
+    let modified = event.ctrl_key() || event.meta_key() || event.alt_key();
+    let single_char = key.chars().count() == 1;
+    if !function_key && (!modified || !single_char) {
+        return None;
+    }
+    let code = event.code();
+    let key = code
+        .strip_prefix("Key")
+        .or_else(|| code.strip_prefix("Digit"))
+        .map(str::to_string)
+        .unwrap_or(key);
+    Some(Shortcut::new(
+        &key,
+        Modifiers {
+            ctrl: event.ctrl_key() || event.meta_key(),
+            shift: event.shift_key(),
+            alt: event.alt_key(),
+        },
+    ))
+}
+
+fn pointer_modifiers(event: &PointerEvent) -> Modifiers {
+    Modifiers {
+        ctrl: event.ctrl_key() || event.meta_key(),
+        shift: event.shift_key(),
+        alt: event.alt_key(),
+    }
+}
+
 fn render_now(
     app: &Rc<RefCell<DemoApp>>,
     context: &CanvasRenderingContext2d,
     canvas: &HtmlCanvasElement,
     window: &Window,
 ) -> Result<(), JsValue> {
-    let mut app_ref = app.borrow_mut();
-    app_ref.render(context, canvas, window.device_pixel_ratio())
+    if let Some(document) = window.document() {
+        fonts::load_queued_faces(&document)?;
+    }
+    let time_ms = window
+        .performance()
+        .map(|performance| performance.now())
+        .unwrap_or(0.0);
+    let result = {
+        let mut app_ref = app.borrow_mut();
+        app_ref.render(context, canvas, window.device_pixel_ratio(), time_ms)
+    };
+    if frame::take_frame_request() {
+        schedule_frame(app, context, canvas, window)?;
+    }
+    result
+}
+
+thread_local! {
+    static FRAME_SCHEDULED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn schedule_frame(
+    app: &Rc<RefCell<DemoApp>>,
+    context: &CanvasRenderingContext2d,
+    canvas: &HtmlCanvasElement,
+    window: &Window,
+) -> Result<(), JsValue> {
+    if FRAME_SCHEDULED.with(|scheduled| scheduled.replace(true)) {
+        return Ok(());
+    }
+    let app_ref = Rc::clone(app);
+    let context_ref = context.clone();
+    let canvas_ref = canvas.clone();
+    let window_ref = window.clone();
+    let on_frame = Closure::once_into_js(move |_timestamp: f64| {
+        FRAME_SCHEDULED.with(|scheduled| scheduled.set(false));
+        if let Err(error) = render_now(&app_ref, &context_ref, &canvas_ref, &window_ref) {
+            web_sys::console::error_1(&error);
+        }
+    });
+    window.request_animation_frame(on_frame.unchecked_ref())?;
+    Ok(())
 }
 
 fn dispatch_and_render(
@@ -360,13 +462,8 @@ fn dispatch_and_render(
     window: &Window,
     signal: PointerSignal,
 ) {
-    let result = {
-        let mut app_ref = app.borrow_mut();
-        app_ref.handle_pointer(signal);
-        app_ref.render(context, canvas, window.device_pixel_ratio())
-    };
-
-    if let Err(error) = result {
+    app.borrow_mut().handle_pointer(signal);
+    if let Err(error) = render_now(app, context, canvas, window) {
         web_sys::console::error_1(&error);
     }
 }
@@ -0,0 +1,222 @@
+use crate::core::frame::request_frame;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+}
+
+impl FontStyle {
+    pub fn css(self) -> &'static str {
+        match self {
+            FontStyle::Normal => "normal",
+            FontStyle::Italic => "italic",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FontDescriptor {
+    pub family: &'static str,
+    pub weight: u16,
+    pub size: f64,
+    pub style: FontStyle,
+}
+
+impl FontDescriptor {
+    pub const fn new(family: &'static str, size: f64) -> Self {
+        Self {
+            family,
+            weight: 400,
+            size,
+            style: FontStyle::Normal,
+        }
+    }
+
+    pub const fn weight(mut self, weight: u16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub const fn italic(mut self) -> Self {
+        self.style = FontStyle::Italic;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub const fn with_size(mut self, size: f64) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn css(&self) -> Rc<str> {
+        let key = (self.family, self.weight, self.size.to_bits(), self.style);
+        if let Some(css) = FONT_CSS.with(|cache| cache.borrow().get(&key).cloned()) {
+            return css;
+        }
+        let families = with_registry(|registry| registry.family_list(self.family));
+        let css: Rc<str> = match self.style {
+            FontStyle::Normal => format!("{} {}px {}", self.weight, self.size, families),
+            FontStyle::Italic => format!("italic {} {}px {}", self.weight, self.size, families),
+        }
+        .into();
+        FONT_CSS.with(|cache| cache.borrow_mut().insert(key, Rc::clone(&css)));
+        css
+    }
+}
+
+#[derive(Clone)]
+#[allow(dead_code)]
+pub enum FontSource {
+    Url(&'static str),
+    Local(&'static str),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Clone)]
+pub struct FontFaceSpec {
+    pub family: &'static str,
+    pub source: FontSource,
+    pub weight: u16,
+    pub style: FontStyle,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FontLoadState {
+    Pending,
+    Loaded,
+    Failed,
+}
+
+struct FallbackChain {
+    family: &'static str,
+    fallbacks: Vec<&'static str>,
+    generic: &'static str,
+}
+
+pub struct FontRegistry {
+    chains: Vec<FallbackChain>,
+    faces: Vec<(FontFaceSpec, FontLoadState)>,
+    queued: Vec<FontFaceSpec>,
+    generation: u64,
+}
+
+impl Default for FontRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            chains: Vec::new(),
+            faces: Vec::new(),
+            queued: Vec::new(),
+            generation: 0,
+        };
+        registry.set_fallbacks(
+            "Consolas",
+            &["Menlo", "DejaVu Sans Mono", "Liberation Mono", "Courier New"],
+            "monospace",
+        );
+        registry
+    }
+}
+
+impl FontRegistry {
+    pub fn set_fallbacks(&mut self, family: &'static str, fallbacks: &[&'static str], generic: &'static str) {
+        self.chains.retain(|chain| chain.family != family);
+        self.chains.push(FallbackChain {
+            family,
+            fallbacks: fallbacks.to_vec(),
+            generic,
+        });
+    }
+
+    fn family_list(&self, family: &'static str) -> String {
+        let mut names = vec![quote_family(family)];
+        match self.chains.iter().find(|chain| chain.family == family) {
+            Some(chain) => {
+                names.extend(chain.fallbacks.iter().map(|name| quote_family(name)));
+                names.push(chain.generic.to_string());
+            }
+            None => names.push("sans-serif".to_string()),
+        }
+        names.join(", ")
+    }
+
+    pub fn register_face(&mut self, spec: FontFaceSpec) {
+        self.faces.push((spec.clone(), FontLoadState::Pending));
+        self.queued.push(spec);
+    }
+
+    pub fn take_queued(&mut self) -> Vec<FontFaceSpec> {
+        std::mem::take(&mut self.queued)
+    }
+
+    pub fn mark_loaded(&mut self, family: &str, weight: u16, style: FontStyle, loaded: bool) {
+        let state = if loaded {
+            FontLoadState::Loaded
+        } else {
+            FontLoadState::Failed
+        };
+        for (spec, current) in &mut self.faces {
+            if spec.family == family && spec.weight == weight && spec.style == style && *current == FontLoadState::Pending {
+                *current = state;
+            }
+        }
+    }
+
+    pub fn load_state(&self, font: &FontDescriptor) -> Option<FontLoadState> {
+        self.faces
+            .iter()
+            .find(|(spec, _)| spec.family == font.family && spec.weight == font.weight && spec.style == font.style)
+            .map(|(_, state)| *state)
+    }
+
+    pub fn invalidate(&mut self) {
+        self.generation += 1;
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+fn quote_family(family: &str) -> String {
+    if family.contains(' ') {
+        format!("\"{}\"", family)
+    } else {
+        family.to_string()
+    }
+}
+
+type FontCssKey = (&'static str, u16, u64, FontStyle);
+
+thread_local! {
+    static FONT_REGISTRY: RefCell<FontRegistry> = RefCell::new(FontRegistry::default());
+    static FONT_CSS: RefCell<HashMap<FontCssKey, Rc<str>>> = RefCell::new(HashMap::new());
+}
+
+pub fn with_registry<R>(f: impl FnOnce(&mut FontRegistry) -> R) -> R {
+    FONT_REGISTRY.with(|registry| f(&mut registry.borrow_mut()))
+}
+
+pub fn register_fallbacks(family: &'static str, fallbacks: &[&'static str], generic: &'static str) {
+    with_registry(|registry| {
+        registry.set_fallbacks(family, fallbacks, generic);
+        registry.invalidate();
+    });
+    FONT_CSS.with(|cache| cache.borrow_mut().clear());
+}
+
+pub fn register_face(spec: FontFaceSpec) {
+    with_registry(|registry| registry.register_face(spec));
+    request_frame();
+}
+
+pub fn load_state(font: &FontDescriptor) -> Option<FontLoadState> {
+    with_registry(|registry| registry.load_state(font))
+}
+
+pub fn font_generation() -> u64 {
+    with_registry(|registry| registry.generation())
+}
@@ -0,0 +1,325 @@
+use crate::core::geometry::Rect;
+use crate::text::measure::cached_width;
+use std::ops::Range;
+use web_sys::CanvasRenderingContext2d;
+
+pub const ELLIPSIS: &str = "\u{2026}";
+
+pub trait TextMeasure {
+    fn text_width(&self, font: &str, text: &str) -> f64;
+}
+
+impl TextMeasure for CanvasRenderingContext2d {
+    fn text_width(&self, font: &str, text: &str) -> f64 {
+        cached_width(self, font, text)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum TextAlign {
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum VerticalAlign {
+    Top,
+    Middle,
+    Bottom,
+}
+
+#[derive(Clone, Copy)]
+pub struct TextLayoutOptions {
+    pub max_width: Option<f64>,
+    pub wrap: bool,
+    pub max_lines: Option<usize>,
+    pub line_height: f64,
+    pub ellipsis: bool,
+}
+
+pub struct TextLine {
+    pub range: Range<usize>,
+    pub text: String,
+    pub width: f64,
+}
+
+#[allow(dead_code)]
+pub struct TextLayout {
+    pub lines: Vec<TextLine>,
+    pub width: f64,
+    pub height: f64,
+    pub line_height: f64,
+    pub truncated: bool,
+}
+
+pub fn font_size_px(font: &str) -> f64 {
+    font.split_whitespace()
+        .find_map(|token| token.strip_suffix("px"))
+        .and_then(|size| size.parse::<f64>().ok())
+        .unwrap_or(16.0)
+}
+
+pub fn layout_text(
+    measure: &impl TextMeasure,
+    font: &str,
+    text: &str,
+    options: TextLayoutOptions,
+) -> TextLayout {
+    let line_height = font_size_px(font) * options.line_height.max(0.1);
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for paragraph in text.split('\n') {
+        match options.max_width {
+            Some(max_width) if options.wrap => {
+                wrap_paragraph(measure, font, paragraph, offset, max_width, &mut lines);
+            }
+            _ => lines.push(TextLine {
+                range: offset..offset + paragraph.len(),
+                text: paragraph.to_string(),
+                width: measure.text_width(font, paragraph),
+            }),
+        }
+        offset += paragraph.len() + 1;
+    }
+
+    let mut truncated = false;
+    if let Some(max_lines) = options.max_lines
+        && lines.len() > max_lines.max(1)
+    {
+        lines.truncate(max_lines.max(1));
+        truncated = true;
+    }
+    if options.ellipsis
+        && let Some(max_width) = options.max_width
+    {
+        let last = lines.len().saturating_sub(1);
+        for (index, line) in lines.iter_mut().enumerate() {
+            if line.width > max_width || (truncated && index == last) {
+                ellipsize_line(measure, font, line, max_width);
+                truncated = true;
+            }
+        }
+    }
+
+    let width = lines.iter().map(|line| line.width).fold(0.0, f64::max);
+    TextLayout {
+        height: lines.len() as f64 * line_height,
+        lines,
+        width,
+        line_height,
+        truncated,
+    }
+}
+
+fn wrap_paragraph(
+    measure: &impl TextMeasure,
+    font: &str,
+    paragraph: &str,
+    offset: usize,
+    max_width: f64,
+    lines: &mut Vec<TextLine>,
+) {
+    let mut line_start: Option<usize> = None;
+    let mut line_end = 0;
+    for (start, end) in word_spans(paragraph) {
+        let current_start = line_start.unwrap_or(start);
+        if measure.text_width(font, paragraph[current_start..end].trim_end()) <= max_width {
+            line_start = Some(current_start);
+            line_end = end;
+            continue;
+        }
+
+        if let Some(previous_start) = line_start {
+            push_line(measure, font, paragraph, previous_start..line_end, offset, lines);
+        }
+        let mut word_start = start;
+        if measure.text_width(font, paragraph[start..end].trim_end()) > max_width {
+            word_start = break_long_word(measure, font, paragraph, start, end, offset, max_width, lines);
+        }
+        line_start = Some(word_start);
+        line_end = end;
+    }
+    push_line(
+        measure,
+        font,
+        paragraph,
+        line_start.unwrap_or(0)..line_end,
+        offset,
+        lines,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn break_long_word(
+    measure: &impl TextMeasure,
+    font: &str,
+    paragraph: &str,
+    start: usize,
+    end: usize,
+    offset: usize,
+    max_width: f64,
+    lines: &mut Vec<TextLine>,
+) -> usize {
+    let mut line_start = start;
+    let mut last_fit = start;
+    for (index, ch) in paragraph[start..end].char_indices() {
+        let boundary = start + index + ch.len_utf8();
+        if measure.text_width(font, &paragraph[line_start..boundary]) > max_width && last_fit > line_start {
+            push_line(measure, font, paragraph, line_start..last_fit, offset, lines);
+            line_start = last_fit;
+        }
+        last_fit = boundary;
+    }
+    line_start
+}
+
+fn push_line(
+    measure: &impl TextMeasure,
+    font: &str,
+    paragraph: &str,
+    range: Range<usize>,
+    offset: usize,
+    lines: &mut Vec<TextLine>,
+) {
+    let text = paragraph[range.clone()].trim_end();
+    lines.push(TextLine {
+        range: offset + range.start..offset + range.start + text.len(),
+        text: text.to_string(),
+        width: measure.text_width(font, text),
+    });
+}
+
+pub fn word_spans(paragraph: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    let mut in_space = false;
+    for (index, ch) in paragraph.char_indices() {
+        match start {
+            None => {
+                start = Some(index);
+                in_space = ch.is_whitespace();
+            }
+            Some(word_start) => {
+                if in_space && !ch.is_whitespace() {
+                    spans.push((word_start, index));
+                    start = Some(index);
+                    in_space = false;
+                } else if ch.is_whitespace() {
+                    in_space = true;
+                }
+            }
+        }
+    }
+    if let Some(word_start) = start {
+        spans.push((word_start, paragraph.len()));
+    }
+    spans
+}
+
+fn ellipsize_line(measure: &impl TextMeasure, font: &str, line: &mut TextLine, max_width: f64) {
+    line.text = truncate_with_ellipsis(measure, font, &line.text, max_width);
+    line.range = line.range.start..line.range.start + line.text.len() - ELLIPSIS.len();
+    line.width = measure.text_width(font, &line.text);
+}
+
+pub fn ellipsize(measure: &impl TextMeasure, font: &str, text: &str, max_width: f64) -> String {
+    if measure.text_width(font, text) <= max_width {
+        return text.to_string();
+    }
+    truncate_with_ellipsis(measure, font, text, max_width)
+}
+
+pub fn truncate_with_ellipsis(measure: &impl TextMeasure, font: &str, text: &str, max_width: f64) -> String {
+    let boundaries = text
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(text.len()))
+        .collect::<Vec<_>>();
+    let fits = |end: usize| {
+        let candidate = format!("{}{}", text[..end].trim_end(), ELLIPSIS);
+        measure.text_width(font, &candidate) <= max_width
+    };
+
+    let (mut low, mut high) = (0, boundaries.len() - 1);
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if fits(boundaries[mid]) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    format!("{}{}", text[..boundaries[low]].trim_end(), ELLIPSIS)
+}
+
+pub fn draw_layout(
+    context: &CanvasRenderingContext2d,
+    layout: &TextLayout,
+    rect: Rect,
+    align: TextAlign,
+    vertical_align: VerticalAlign,
+) {
+    let top = match vertical_align {
+        VerticalAlign::Top => rect.y,
+        VerticalAlign::Middle => rect.y + (rect.height - layout.height) * 0.5,
+        VerticalAlign::Bottom => rect.y + rect.height - layout.height,
+    };
+    context.set_text_align("left");
+    context.set_text_baseline("middle");
+    for (index, line) in layout.lines.iter().enumerate() {
+        let x = match align {
+            TextAlign::Start => rect.x,
+            TextAlign::Center => rect.x + (rect.width - line.width) * 0.5,
+            TextAlign::End => rect.x + rect.width - line.width,
+        };
+        let y = top + layout.line_height * (index as f64 + 0.5);
+        let _ = context.fill_text(&line.text, x, y);
+    }
+}
+
+pub fn draw_highlighted(
+    context: &CanvasRenderingContext2d,
+    font: &str,
+    text: &str,
+    ranges: &[Range<usize>],
+    rect: Rect,
+    colors: (&str, &str),
+) {
+    let fitted = ellipsize(context, font, text, rect.width.max(0.0));
+    let kept = if fitted.len() == text.len() {
+        text.len()
+    } else {
+        fitted.strip_suffix(ELLIPSIS).unwrap_or(&fitted).len()
+    };
+
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+    for range in ranges {
+        let start = range.start.min(kept).max(cursor);
+        let end = range.end.min(kept);
+        if start >= end {
+            continue;
+        }
+        segments.push((cursor..start, false));
+        segments.push((start..end, true));
+        cursor = end;
+    }
+    segments.push((cursor..fitted.len(), false));
+
+    context.set_font(font);
+    context.set_text_align("left");
+    context.set_text_baseline("middle");
+    let y = rect.y + rect.height * 0.5;
+    for (range, highlighted) in segments {
+        if range.is_empty() {
+            continue;
+        }
+        let x = rect.x + cached_width(context, font, &fitted[..range.start]);
+        context.set_fill_style_str(if highlighted { colors.1 } else { colors.0 });
+        let _ = context.fill_text(&fitted[range], x, y);
+    }
+}
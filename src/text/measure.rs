@@ -0,0 +1,172 @@
+use crate::text::font::font_generation;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::CanvasRenderingContext2d;
+
+const MAX_ENTRIES_PER_FONT: usize = 2048;
+
+pub struct ShapedText {
+    offsets: Vec<usize>,
+    advances: Vec<f64>,
+    byte_advances: Vec<f64>,
+}
+
+impl ShapedText {
+    pub fn width(&self) -> f64 {
+        self.advances.last().copied().unwrap_or(0.0)
+    }
+
+    pub fn prefix_width(&self, byte_index: usize) -> f64 {
+        self.byte_advances
+            .get(byte_index)
+            .or(self.byte_advances.last())
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn char_advance(&self, char_index: usize) -> f64 {
+        self.advances
+            .get(char_index)
+            .or(self.advances.last())
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn char_offset(&self, char_index: usize) -> usize {
+        self.offsets
+            .get(char_index)
+            .or(self.offsets.last())
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn nearest_char(&self, x: f64) -> usize {
+        let after = self.advances.partition_point(|advance| *advance < x);
+        if after == 0 {
+            return 0;
+        }
+        if after >= self.advances.len() {
+            return self.advances.len() - 1;
+        }
+        if x - self.advances[after - 1] <= self.advances[after] - x {
+            after - 1
+        } else {
+            after
+        }
+    }
+}
+
+#[derive(Default)]
+struct FontMetrics {
+    widths: HashMap<String, f64>,
+    chars: HashMap<char, f64>,
+    shaped: HashMap<String, Rc<ShapedText>>,
+}
+
+#[derive(Default)]
+struct MeasureCache {
+    generation: u64,
+    fonts: HashMap<String, FontMetrics>,
+}
+
+impl MeasureCache {
+    fn metrics(&mut self, font: &str) -> &mut FontMetrics {
+        let generation = font_generation();
+        if generation != self.generation {
+            self.fonts.clear();
+            self.generation = generation;
+        }
+        if !self.fonts.contains_key(font) {
+            self.fonts.insert(font.to_string(), FontMetrics::default());
+        }
+        self.fonts.get_mut(font).expect("font metrics inserted above")
+    }
+}
+
+thread_local! {
+    static MEASURE_CACHE: RefCell<MeasureCache> = RefCell::new(MeasureCache::default());
+}
+
+fn raw_width(context: &CanvasRenderingContext2d, font: &str, text: &str) -> f64 {
+    let previous = context.font();
+    context.set_font(font);
+    let width = context
+        .measure_text(text)
+        .ok()
+        .map(|metrics| metrics.width())
+        .unwrap_or(0.0);
+    context.set_font(&previous);
+    width
+}
+
+fn width_in(metrics: &mut FontMetrics, context: &CanvasRenderingContext2d, font: &str, text: &str) -> f64 {
+    if let Some(width) = metrics.widths.get(text) {
+        return *width;
+    }
+    if metrics.widths.len() >= MAX_ENTRIES_PER_FONT {
+        metrics.widths.clear();
+    }
+    let width = raw_width(context, font, text);
+    metrics.widths.insert(text.to_string(), width);
+    width
+}
+
+pub fn cached_width(context: &CanvasRenderingContext2d, font: &str, text: &str) -> f64 {
+    if text.is_empty() {
+        return 0.0;
+    }
+    MEASURE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        width_in(cache.metrics(font), context, font, text)
+    })
+}
+
+pub fn shape_text(context: &CanvasRenderingContext2d, font: &str, text: &str) -> Rc<ShapedText> {
+    MEASURE_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let metrics = cache.metrics(font);
+        if let Some(shaped) = metrics.shaped.get(text) {
+            return Rc::clone(shaped);
+        }
+
+        let mut offsets = Vec::with_capacity(text.len() + 1);
+        let mut advances = Vec::with_capacity(text.len() + 1);
+        let mut x = 0.0;
+        for (offset, ch) in text.char_indices() {
+            offsets.push(offset);
+            advances.push(x);
+            x += *metrics
+                .chars
+                .entry(ch)
+                .or_insert_with(|| raw_width(context, font, ch.encode_utf8(&mut [0; 4])));
+        }
+        offsets.push(text.len());
+        advances.push(x);
+
+        let total = width_in(metrics, context, font, text);
+        if x > 0.0 && total > 0.0 {
+            let scale = total / x;
+            for advance in &mut advances {
+                *advance *= scale;
+            }
+        }
+
+        let mut byte_advances = vec![0.0; text.len() + 1];
+        for (index, offset) in offsets.iter().enumerate() {
+            let end = offsets.get(index + 1).copied().unwrap_or(text.len() + 1);
+            byte_advances[*offset..end].fill(advances[index]);
+        }
+
+        let shaped = Rc::new(ShapedText {
+            offsets,
+            advances,
+            byte_advances,
+        });
+        if metrics.shaped.len() >= MAX_ENTRIES_PER_FONT {
+            metrics.shaped.clear();
+        }
+        metrics.shaped.insert(text.to_string(), Rc::clone(&shaped));
+        shaped
+    })
+}
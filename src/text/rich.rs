@@ -0,0 +1,296 @@
+use crate::core::geometry::Rect;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::{
+    truncate_with_ellipsis, word_spans, TextAlign, TextLayoutOptions, TextMeasure, VerticalAlign,
+};
+use std::rc::Rc;
+use web_sys::CanvasRenderingContext2d;
+
+#[derive(Clone, Copy, Default)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub code: bool,
+    pub color: Option<&'static str>,
+    pub link: Option<&'static str>,
+}
+
+#[derive(Clone)]
+pub struct TextSpan {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+#[allow(dead_code)]
+impl TextSpan {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: SpanStyle::default(),
+        }
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.style.bold = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.style.italic = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.style.underline = true;
+        self
+    }
+
+    pub fn code(mut self) -> Self {
+        self.style.code = true;
+        self
+    }
+
+    pub fn color(mut self, color: &'static str) -> Self {
+        self.style.color = Some(color);
+        self
+    }
+
+    pub fn link(mut self, target: &'static str) -> Self {
+        self.style.link = Some(target);
+        self.style.underline = true;
+        self
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RichTextTheme {
+    pub font: FontDescriptor,
+    pub code_font: FontDescriptor,
+    pub color: &'static str,
+    pub link_color: &'static str,
+    pub code_background: &'static str,
+}
+
+pub fn span_font(theme: &RichTextTheme, style: &SpanStyle) -> Rc<str> {
+    let mut font = if style.code { theme.code_font } else { theme.font };
+    if style.bold {
+        font = font.weight(700);
+    }
+    if style.italic {
+        font = font.italic();
+    }
+    font.css()
+}
+
+pub struct RichFragment {
+    pub span: usize,
+    pub line: usize,
+    pub text: String,
+    pub x: f64,
+    pub width: f64,
+}
+
+#[allow(dead_code)]
+pub struct RichLayout {
+    pub fragments: Vec<RichFragment>,
+    pub line_widths: Vec<f64>,
+    pub line_height: f64,
+    pub height: f64,
+    pub truncated: bool,
+}
+
+impl RichLayout {
+    fn line_origin(&self, line: usize, rect: Rect, align: TextAlign, vertical_align: VerticalAlign) -> (f64, f64) {
+        let top = match vertical_align {
+            VerticalAlign::Top => rect.y,
+            VerticalAlign::Middle => rect.y + (rect.height - self.height) * 0.5,
+            VerticalAlign::Bottom => rect.y + rect.height - self.height,
+        };
+        let width = self.line_widths.get(line).copied().unwrap_or(0.0);
+        let x = match align {
+            TextAlign::Start => rect.x,
+            TextAlign::Center => rect.x + (rect.width - width) * 0.5,
+            TextAlign::End => rect.x + rect.width - width,
+        };
+        (x, top + self.line_height * line as f64)
+    }
+
+    pub fn fragment_rect(
+        &self,
+        fragment: &RichFragment,
+        rect: Rect,
+        align: TextAlign,
+        vertical_align: VerticalAlign,
+    ) -> Rect {
+        let (x, y) = self.line_origin(fragment.line, rect, align, vertical_align);
+        Rect {
+            x: x + fragment.x,
+            y,
+            width: fragment.width,
+            height: self.line_height,
+        }
+    }
+
+    pub fn hit_test(
+        &self,
+        rect: Rect,
+        align: TextAlign,
+        vertical_align: VerticalAlign,
+        x: f64,
+        y: f64,
+    ) -> Option<usize> {
+        self.fragments
+            .iter()
+            .find(|fragment| {
+                self.fragment_rect(fragment, rect, align, vertical_align)
+                    .contains(x, y)
+            })
+            .map(|fragment| fragment.span)
+    }
+}
+
+pub fn layout_rich(
+    measure: &impl TextMeasure,
+    spans: &[TextSpan],
+    theme: &RichTextTheme,
+    options: TextLayoutOptions,
+) -> RichLayout {
+    let line_height = theme.font.size * options.line_height.max(0.1);
+    let wrap_width = if options.wrap { options.max_width } else { None };
+    let mut fragments: Vec<RichFragment> = Vec::new();
+    let mut line_widths = vec![0.0];
+    let mut line = 0;
+
+    for (span_index, span) in spans.iter().enumerate() {
+        let font = span_font(theme, &span.style);
+        for (paragraph_index, paragraph) in span.text.split('\n').enumerate() {
+            if paragraph_index > 0 {
+                line += 1;
+                line_widths.push(0.0);
+            }
+            for (start, end) in word_spans(paragraph) {
+                let token = &paragraph[start..end];
+                let trimmed_width = measure.text_width(&font, token.trim_end());
+                let x = line_widths[line];
+                if let Some(max_width) = wrap_width
+                    && x > 0.0
+                    && x + trimmed_width > max_width
+                {
+                    if let Some(last) = fragments.last_mut().filter(|fragment| fragment.line == line) {
+                        last.text.truncate(last.text.trim_end().len());
+                        last.width = measure.text_width(&span_font(theme, &spans[last.span].style), &last.text);
+                        line_widths[line] = last.x + last.width;
+                    }
+                    line += 1;
+                    line_widths.push(0.0);
+                }
+
+                let x = line_widths[line];
+                match fragments.last_mut() {
+                    Some(last) if last.span == span_index && last.line == line => {
+                        last.text.push_str(token);
+                        last.width = measure.text_width(&font, &last.text);
+                        line_widths[line] = last.x + last.width;
+                    }
+                    _ => {
+                        let width = measure.text_width(&font, token);
+                        fragments.push(RichFragment {
+                            span: span_index,
+                            line,
+                            text: token.to_string(),
+                            x,
+                            width,
+                        });
+                        line_widths[line] = x + width;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut truncated = false;
+    if let Some(max_lines) = options.max_lines
+        && line_widths.len() > max_lines.max(1)
+    {
+        let keep = max_lines.max(1);
+        fragments.retain(|fragment| fragment.line < keep);
+        line_widths.truncate(keep);
+        truncated = true;
+    }
+    if options.ellipsis
+        && let Some(limit) = options.max_width
+    {
+        let last_line = line_widths.len() - 1;
+        let overflowing = line_widths
+            .iter()
+            .enumerate()
+            .filter(|(line, width)| **width > limit || (truncated && *line == last_line))
+            .map(|(line, _)| line)
+            .collect::<Vec<_>>();
+        for line in overflowing {
+            fragments.retain(|fragment| fragment.line != line || fragment.x < limit || fragment.x == 0.0);
+            if let Some(last) = fragments.iter_mut().rfind(|fragment| fragment.line == line) {
+                let font = span_font(theme, &spans[last.span].style);
+                last.text = truncate_with_ellipsis(measure, &font, &last.text, limit - last.x);
+                last.width = measure.text_width(&font, &last.text);
+                line_widths[line] = last.x + last.width;
+            }
+            truncated = true;
+        }
+    }
+
+    RichLayout {
+        height: line_widths.len() as f64 * line_height,
+        fragments,
+        line_widths,
+        line_height,
+        truncated,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn draw_rich(
+    context: &CanvasRenderingContext2d,
+    spans: &[TextSpan],
+    layout: &RichLayout,
+    theme: &RichTextTheme,
+    rect: Rect,
+    align: TextAlign,
+    vertical_align: VerticalAlign,
+    highlighted_span: Option<usize>,
+) {
+    context.set_text_align("left");
+    context.set_text_baseline("middle");
+    for fragment in &layout.fragments {
+        let style = &spans[fragment.span].style;
+        let area = layout.fragment_rect(fragment, rect, align, vertical_align);
+        let color = if style.link.is_some() {
+            style.color.unwrap_or(theme.link_color)
+        } else {
+            style.color.unwrap_or(theme.color)
+        };
+
+        if style.code || highlighted_span == Some(fragment.span) {
+            context.set_fill_style_str(theme.code_background);
+            context.fill_rect(area.x - 1.0, area.y + 1.0, area.width + 2.0, area.height - 2.0);
+        }
+
+        let font = span_font(theme, style);
+        context.set_font(&font);
+        context.set_fill_style_str(color);
+        let mid_y = area.y + area.height * 0.5;
+        let _ = context.fill_text(&fragment.text, area.x, mid_y);
+
+        if style.underline {
+            let underline_width = context.text_width(&font, fragment.text.trim_end());
+            let underline_y = mid_y + theme.font.size * 0.55;
+            context.set_stroke_style_str(color);
+            context.set_line_width(1.0);
+            context.begin_path();
+            context.move_to(area.x, underline_y);
+            context.line_to(area.x + underline_width, underline_y);
+            context.stroke();
+        }
+    }
+}
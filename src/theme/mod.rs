@@ -1,11 +1,13 @@
+use crate::text::font::FontDescriptor;
+
 pub struct Theme {
     pub background: &'static str,
     pub accent_primary: &'static str,
     pub accent_secondary: &'static str,
     pub text_primary: &'static str,
     pub text_muted: &'static str,
-    pub font_label: &'static str,
-    pub font_button: &'static str,
+    pub font_label: FontDescriptor,
+    pub font_button: FontDescriptor,
 }
 
 pub const REACTRON_THEME: Theme = Theme {
@@ -14,7 +16,7 @@ pub const REACTRON_THEME: Theme = Theme {
     accent_secondary: "#27ffd8",
     text_primary: "#d8e3ff",
     text_muted: "#9eb4ff",
-    font_label: "14px Consolas",
-    font_button: "600 22px Consolas",
+    font_label: FontDescriptor::new("Consolas", 14.0),
+    font_button: FontDescriptor::new("Consolas", 22.0).weight(600),
 };
 
@@ -1,2 +1,4 @@
+pub mod overlay;
+pub mod toast;
 pub mod tree;
 
@@ -0,0 +1,228 @@
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use std::cell::RefCell;
+use web_sys::CanvasRenderingContext2d;
+
+type OverlayDraw = Box<dyn FnOnce(&CanvasRenderingContext2d)>;
+
+struct OverlayRegion {
+    owner: &'static str,
+    rect: Rect,
+}
+
+#[derive(Default)]
+struct OverlayLayer {
+    active: Vec<OverlayRegion>,
+    pending: Vec<OverlayRegion>,
+    draws: Vec<OverlayDraw>,
+    captured: Option<(&'static str, PointerState)>,
+    keyboard_owner: Option<&'static str>,
+    pending_keyboard_owner: Option<&'static str>,
+    keyboard: Option<PointerState>,
+    viewport: Option<Rect>,
+}
+
+thread_local! {
+    static OVERLAYS: RefCell<OverlayLayer> = RefCell::new(OverlayLayer::default());
+}
+
+pub fn push_overlay(owner: &'static str, rect: Rect, draw: impl FnOnce(&CanvasRenderingContext2d) + 'static) {
+    OVERLAYS.with(|layer| {
+        let mut layer = layer.borrow_mut();
+        layer.pending.push(OverlayRegion { owner, rect });
+        layer.draws.push(Box::new(draw));
+    });
+}
+
+pub fn capture_keyboard(owner: &'static str) {
+    OVERLAYS.with(|layer| layer.borrow_mut().pending_keyboard_owner = Some(owner));
+}
+
+pub fn push_decoration(draw: impl FnOnce(&CanvasRenderingContext2d) + 'static) {
+    OVERLAYS.with(|layer| layer.borrow_mut().draws.push(Box::new(draw)));
+}
+
+pub fn set_viewport(rect: Rect) {
+    OVERLAYS.with(|layer| layer.borrow_mut().viewport = Some(rect));
+}
+
+pub fn place_popup(anchor: Rect, width: f64, height: f64, gap: f64, prefer_above: bool) -> Rect {
+    let viewport = OVERLAYS.with(|layer| layer.borrow().viewport);
+    let above = anchor.y - gap - height;
+    let below = anchor.y + anchor.height + gap;
+    let Some(viewport) = viewport else {
+        return Rect {
+            x: anchor.x,
+            y: if prefer_above { above } else { below },
+            width,
+            height,
+        };
+    };
+    let fits_above = above >= viewport.y;
+    let fits_below = below + height <= viewport.y + viewport.height;
+    let y = match (prefer_above, fits_above, fits_below) {
+        (true, true, _) | (false, true, false) => above,
+        (false, _, true) | (true, false, true) => below,
+        _ => above.max(viewport.y),
+    };
+    let max_x = (viewport.x + viewport.width - width).max(viewport.x);
+    Rect {
+        x: (anchor.x + (anchor.width - width) * 0.5).clamp(viewport.x, max_x),
+        y,
+        width,
+        height,
+    }
+}
+
+pub fn place_menu(anchor: Rect, width: f64, height: f64) -> Rect {
+    let below = anchor.y + anchor.height;
+    let Some(viewport) = OVERLAYS.with(|layer| layer.borrow().viewport) else {
+        return Rect {
+            x: anchor.x,
+            y: below,
+            width,
+            height,
+        };
+    };
+    let above = anchor.y - height;
+    let y = if below + height <= viewport.y + viewport.height || above < viewport.y {
+        below
+    } else {
+        above
+    };
+    clamp_to_viewport(
+        Rect {
+            x: anchor.x,
+            y,
+            width,
+            height,
+        },
+        viewport,
+    )
+}
+
+pub fn place_beside(anchor: Rect, width: f64, height: f64) -> Rect {
+    let right = anchor.x + anchor.width;
+    let Some(viewport) = OVERLAYS.with(|layer| layer.borrow().viewport) else {
+        return Rect {
+            x: right,
+            y: anchor.y,
+            width,
+            height,
+        };
+    };
+    let left = anchor.x - width;
+    let x = if right + width <= viewport.x + viewport.width || left < viewport.x {
+        right
+    } else {
+        left
+    };
+    clamp_to_viewport(
+        Rect {
+            x,
+            y: anchor.y,
+            width,
+            height,
+        },
+        viewport,
+    )
+}
+
+fn clamp_to_viewport(rect: Rect, viewport: Rect) -> Rect {
+    let max_x = (viewport.x + viewport.width - rect.width).max(viewport.x);
+    let max_y = (viewport.y + viewport.height - rect.height).max(viewport.y);
+    Rect {
+        x: rect.x.clamp(viewport.x, max_x),
+        y: rect.y.clamp(viewport.y, max_y),
+        ..rect
+    }
+}
+
+pub fn route_pointer(pointer: &PointerState) -> PointerState {
+    OVERLAYS.with(|layer| {
+        let mut layer = layer.borrow_mut();
+        let owner = layer
+            .active
+            .iter()
+            .rev()
+            .find(|region| region.rect.contains(pointer.x, pointer.y))
+            .map(|region| region.owner);
+        layer.captured = owner.map(|owner| (owner, pointer.clone()));
+        layer.keyboard = layer.keyboard_owner.map(|_| pointer.clone());
+
+        let mut routed = pointer.clone();
+        if owner.is_some() {
+            routed.x = f64::NEG_INFINITY;
+            routed.y = f64::NEG_INFINITY;
+            routed.just_pressed = false;
+            routed.just_released = false;
+            routed.secondary_click = false;
+            routed.scroll_x = 0.0;
+            routed.scroll_y = 0.0;
+        }
+        if layer.keyboard_owner.is_some() {
+            routed.suppress_focus_and_text_input();
+        }
+        routed
+    })
+}
+
+pub fn pointer_for(owner: &'static str, pointer: &PointerState) -> PointerState {
+    OVERLAYS.with(|layer| {
+        let layer = layer.borrow();
+        let mut routed = pointer.clone();
+        if layer.keyboard_owner == Some(owner)
+            && let Some(keyboard) = &layer.keyboard
+        {
+            routed = PointerState {
+                x: pointer.x,
+                y: pointer.y,
+                is_down: pointer.is_down,
+                just_pressed: pointer.just_pressed,
+                just_released: pointer.just_released,
+                secondary_click: pointer.secondary_click,
+                scroll_x: pointer.scroll_x,
+                scroll_y: pointer.scroll_y,
+                ..keyboard.clone()
+            };
+        }
+        if let Some((captured_owner, captured)) = &layer.captured
+            && *captured_owner == owner
+        {
+            routed.x = captured.x;
+            routed.y = captured.y;
+            routed.is_down = captured.is_down;
+            routed.just_pressed = captured.just_pressed;
+            routed.just_released = captured.just_released;
+            routed.secondary_click = captured.secondary_click;
+            routed.scroll_x = captured.scroll_x;
+            routed.scroll_y = captured.scroll_y;
+        }
+        routed
+    })
+}
+
+pub fn pending_overlay_count() -> usize {
+    OVERLAYS.with(|layer| layer.borrow().pending.len())
+}
+
+pub fn flush_overlays(context: &CanvasRenderingContext2d) {
+    let draws = OVERLAYS.with(|layer| {
+        let mut layer = layer.borrow_mut();
+        layer.active = std::mem::take(&mut layer.pending);
+        layer.keyboard_owner = layer.pending_keyboard_owner.take();
+        layer.captured = None;
+        layer.keyboard = None;
+        std::mem::take(&mut layer.draws)
+    });
+    for draw in draws {
+        draw(context);
+    }
+}
+
+pub fn paint_overlays(context: &CanvasRenderingContext2d) {
+    let draws = OVERLAYS.with(|layer| std::mem::take(&mut layer.borrow_mut().draws));
+    for draw in draws {
+        draw(context);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core::frame::request_frame;
+use std::cell::RefCell;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[allow(dead_code)]
+pub enum ToastLevel {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ToastAction {
+    pub key: &'static str,
+    pub label: &'static str,
+}
+
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub id: u64,
+    pub message: String,
+    pub level: ToastLevel,
+    pub duration_ms: Option<f64>,
+    pub action: Option<ToastAction>,
+}
+
+#[derive(Default)]
+struct ToastQueue {
+    next_id: u64,
+    pending: Vec<Toast>,
+    dismissed: Vec<u64>,
+}
+
+thread_local! {
+    static TOASTS: RefCell<ToastQueue> = RefCell::new(ToastQueue::default());
+}
+
+pub fn notify(message: impl Into<String>, level: ToastLevel, duration_ms: Option<f64>, action: Option<ToastAction>) -> u64 {
+    let id = TOASTS.with(|queue| {
+        let mut queue = queue.borrow_mut();
+        queue.next_id += 1;
+        let id = queue.next_id;
+        queue.pending.push(Toast {
+            id,
+            message: message.into(),
+            level,
+            duration_ms,
+            action,
+        });
+        id
+    });
+    request_frame();
+    id
+}
+
+#[allow(dead_code)]
+pub fn dismiss(id: u64) {
+    TOASTS.with(|queue| queue.borrow_mut().dismissed.push(id));
+    request_frame();
+}
+
+pub fn take_pending() -> (Vec<Toast>, Vec<u64>) {
+    TOASTS.with(|queue| {
+        let mut queue = queue.borrow_mut();
+        (std::mem::take(&mut queue.pending), std::mem::take(&mut queue.dismissed))
+    })
+}
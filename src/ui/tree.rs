@@ -1,14 +1,83 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
 use std::any::Any;
+use std::collections::BTreeMap;
 use web_sys::CanvasRenderingContext2d;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+pub type FormValues = BTreeMap<&'static str, FieldValue>;
+
 pub enum UiEvent {
     Action(UiAction),
     ValueChanged {
         key: &'static str,
         value: String,
     },
+    SelectionChanged {
+        key: &'static str,
+        indices: Vec<usize>,
+    },
+    LoadMore {
+        key: &'static str,
+        offset: usize,
+    },
+    RowEvent {
+        key: &'static str,
+        index: usize,
+        event: Box<UiEvent>,
+    },
+    LinkActivated {
+        key: &'static str,
+        target: &'static str,
+    },
+    SortChanged {
+        key: &'static str,
+        column: &'static str,
+        direction: Option<SortDirection>,
+    },
+    CellActivated {
+        key: &'static str,
+        row: usize,
+        column: &'static str,
+    },
+    ChildrenRequested {
+        key: &'static str,
+        id: String,
+    },
+    Validation {
+        key: &'static str,
+        error: Option<String>,
+    },
+    CheckedChanged {
+        key: &'static str,
+        ids: Vec<String>,
+    },
+    ToastAction {
+        key: &'static str,
+        id: u64,
+    },
+    MenuCommand {
+        key: &'static str,
+        command: &'static str,
+        checked: Option<bool>,
+    },
+    FormSubmitted {
+        key: &'static str,
+        values: FormValues,
+    },
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -16,6 +85,8 @@ pub enum UiAction {
     ToggleAccent,
     SetNeon(bool),
     OpenModal,
+    SubmitForm,
+    RemoveRow,
 }
 
 pub trait Widget {
@@ -35,6 +106,15 @@ pub trait Widget {
     fn focus_prev_in_children(&mut self) -> bool {
         false
     }
+    fn focus_rect(&self) -> Option<Rect> {
+        None
+    }
+    fn scrollable_at(&self, _x: f64, _y: f64) -> bool {
+        false
+    }
+    fn handles_shortcut(&self, _shortcut: &Shortcut) -> bool {
+        false
+    }
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
@@ -103,8 +183,16 @@ struct WidgetEntry {
     widget: Box<dyn Widget>,
     layout: LayoutProps,
     focus_order: i32,
+    rect: Rect,
 }
 
+const EMPTY_RECT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 0.0,
+    height: 0.0,
+};
+
 pub struct UiTree {
     widgets: Vec<WidgetEntry>,
     area: Rect,
@@ -161,6 +249,7 @@ impl UiTree {
             widget,
             layout: LayoutProps::auto(),
             focus_order: i32::MAX,
+            rect: EMPTY_RECT,
         });
     }
 
@@ -171,6 +260,7 @@ impl UiTree {
             widget,
             layout: LayoutProps::auto(),
             focus_order: i32::MAX,
+            rect: EMPTY_RECT,
         });
     }
 
@@ -180,6 +270,7 @@ impl UiTree {
             widget,
             layout,
             focus_order: i32::MAX,
+            rect: EMPTY_RECT,
         });
     }
 
@@ -195,6 +286,7 @@ impl UiTree {
             widget,
             layout,
             focus_order,
+            rect: EMPTY_RECT,
         });
     }
 
@@ -316,6 +408,7 @@ impl UiTree {
                 self.focus_index = Some(index);
             }
             entry.widget.set_focused(self.focus_index == Some(index));
+            entry.rect = rect;
             entry.widget.set_rect(rect);
             events.extend(entry.widget.draw(context, pointer));
             if pointer.activate_primary && self.focus_index == Some(index) {
@@ -400,6 +493,7 @@ impl UiTree {
                 self.focus_index = Some(index);
             }
             entry.widget.set_focused(self.focus_index == Some(index));
+            entry.rect = rect;
             entry.widget.set_rect(rect);
 
             events.extend(entry.widget.draw(context, pointer));
@@ -468,6 +562,7 @@ impl UiTree {
                 self.focus_index = Some(index);
             }
             entry.widget.set_focused(self.focus_index == Some(index));
+            entry.rect = rect;
             entry.widget.set_rect(rect);
             events.extend(entry.widget.draw(context, pointer));
             if pointer.activate_primary && self.focus_index == Some(index) {
@@ -480,6 +575,61 @@ impl UiTree {
         events
     }
 
+    pub fn content_size(&self) -> (f64, f64) {
+        let extent = |spec: SizeSpec, desired: f64| match spec {
+            SizeSpec::Fixed(value) => value.max(0.0),
+            SizeSpec::Auto => desired.max(0.0),
+            SizeSpec::Flex(_) => 0.0,
+        };
+        let total_gap = self.gap * (self.widgets.len().saturating_sub(1) as f64);
+        let (mut width, mut height) = (0.0_f64, 0.0_f64);
+        for entry in &self.widgets {
+            let (desired_w, desired_h) = entry.widget.desired_size();
+            let entry_w = extent(entry.layout.width, desired_w);
+            let entry_h = extent(entry.layout.height, desired_h);
+            match self.direction {
+                LayoutDirection::Column => {
+                    width = width.max(entry_w);
+                    height += entry_h;
+                }
+                LayoutDirection::Row => {
+                    width += entry_w;
+                    height = height.max(entry_h);
+                }
+                LayoutDirection::Stack => {
+                    width = width.max(entry_w);
+                    height = height.max(entry_h);
+                }
+            }
+        }
+        match self.direction {
+            LayoutDirection::Column => height += total_gap,
+            LayoutDirection::Row => width += total_gap,
+            LayoutDirection::Stack => {}
+        }
+        (
+            width + self.padding.left + self.padding.right,
+            height + self.padding.top + self.padding.bottom,
+        )
+    }
+
+    pub fn focus_rect(&self) -> Option<Rect> {
+        let entry = &self.widgets[self.focus_index?];
+        entry.widget.focus_rect().or(Some(entry.rect))
+    }
+
+    pub fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.widgets
+            .iter()
+            .any(|entry| entry.widget.scrollable_at(x, y))
+    }
+
+    pub fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.widgets
+            .iter()
+            .any(|entry| entry.widget.handles_shortcut(shortcut))
+    }
+
     fn inner_area(&self) -> Rect {
         let x = self.area.x + self.padding.left;
         let y = self.area.y + self.padding.top;
@@ -493,12 +643,7 @@ impl UiTree {
         }
     }
 
-    pub fn focus_next(&mut self) -> bool {
-        if self.widgets.is_empty() {
-            self.focus_index = None;
-            return false;
-        }
-
+    fn ordered_focusables(&self) -> Vec<usize> {
         let mut ordered = self
             .widgets
             .iter()
@@ -506,57 +651,69 @@ impl UiTree {
             .filter(|(_, entry)| entry.widget.focusable())
             .map(|(index, entry)| (entry.focus_order, index))
             .collect::<Vec<_>>();
+        ordered.sort_by(|(a_order, a_index), (b_order, b_index)| {
+            a_order.cmp(b_order).then(a_index.cmp(b_index))
+        });
+        ordered.into_iter().map(|(_, index)| index).collect()
+    }
+
+    pub fn focus_next(&mut self) -> bool {
+        let ordered = self.ordered_focusables();
         if ordered.is_empty() {
             self.focus_index = None;
             return false;
         }
 
-        ordered.sort_by(|(a_order, a_index), (b_order, b_index)| {
-            a_order.cmp(b_order).then(a_index.cmp(b_index))
-        });
-
         let current = self.focus_index;
         let next_pos = ordered
             .iter()
-            .position(|(_, index)| Some(*index) == current)
+            .position(|index| Some(*index) == current)
             .map(|pos| (pos + 1) % ordered.len())
             .unwrap_or(0);
-        self.focus_index = Some(ordered[next_pos].1);
+        self.focus_index = Some(ordered[next_pos]);
         true
     }
 
     pub fn focus_prev(&mut self) -> bool {
-        if self.widgets.is_empty() {
-            self.focus_index = None;
-            return false;
-        }
-
-        let mut ordered = self
-            .widgets
-            .iter()
-            .enumerate()
-            .filter(|(_, entry)| entry.widget.focusable())
-            .map(|(index, entry)| (entry.focus_order, index))
-            .collect::<Vec<_>>();
+        let ordered = self.ordered_focusables();
         if ordered.is_empty() {
             self.focus_index = None;
             return false;
         }
 
-        ordered.sort_by(|(a_order, a_index), (b_order, b_index)| {
-            a_order.cmp(b_order).then(a_index.cmp(b_index))
-        });
-
         let current = self.focus_index;
         let prev_pos = ordered
             .iter()
-            .position(|(_, index)| Some(*index) == current)
+            .position(|index| Some(*index) == current)
             .map(|pos| if pos == 0 { ordered.len() - 1 } else { pos - 1 })
             .unwrap_or(ordered.len() - 1);
-        self.focus_index = Some(ordered[prev_pos].1);
+        self.focus_index = Some(ordered[prev_pos]);
         true
     }
 
+    pub fn focus_next_bounded(&mut self) -> bool {
+        let ordered = self.ordered_focusables();
+        let current = self.focus_index;
+        let next_pos = ordered
+            .iter()
+            .position(|index| Some(*index) == current)
+            .map(|pos| pos + 1)
+            .unwrap_or(0);
+        self.focus_index = ordered.get(next_pos).copied();
+        self.focus_index.is_some()
+    }
+
+    pub fn focus_prev_bounded(&mut self) -> bool {
+        let ordered = self.ordered_focusables();
+        let current = self.focus_index;
+        self.focus_index = match ordered.iter().position(|index| Some(*index) == current) {
+            Some(0) => None,
+            Some(pos) => Some(ordered[pos - 1]),
+            None => ordered.last().copied(),
+        };
+        self.focus_index.is_some()
+    }
+
     pub fn focus_next_in_focused_child(&mut self) -> bool {
         match self.focus_index {
             Some(index) => self.widgets[index].widget.focus_next_in_children(),
@@ -570,4 +727,31 @@ impl UiTree {
             None => false,
         }
     }
+
+    pub fn focused_key(&self) -> Option<&'static str> {
+        self.focus_index.map(|index| self.widgets[index].key)
+    }
+
+    pub fn focus_key(&mut self, key: &str) -> bool {
+        match self
+            .widgets
+            .iter()
+            .position(|entry| entry.key == key && entry.widget.focusable())
+        {
+            Some(index) => {
+                self.focus_index = Some(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn clear_focus(&mut self) {
+        self.focus_index = None;
+    }
+
+    pub fn focus_first(&mut self) -> bool {
+        self.focus_index = None;
+        self.focus_next()
+    }
 }
@@ -1,5 +1,6 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiAction, UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -19,7 +20,7 @@ pub struct ButtonStyle {
     pub border: &'static str,
     pub focus_border: &'static str,
     pub text: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
 }
 
 impl Default for ButtonStyle {
@@ -31,7 +32,7 @@ impl Default for ButtonStyle {
             border: "#3d5387",
             focus_border: "#27ffd8",
             text: "#d8e3ff",
-            font: "600 22px Consolas",
+            font: FontDescriptor::new("Consolas", 22.0).weight(600),
         }
     }
 }
@@ -70,7 +71,7 @@ impl Button {
         context.set_line_width(2.0);
         context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_fill_style_str(self.style.text);
         context.set_text_align("center");
         context.set_text_baseline("middle");
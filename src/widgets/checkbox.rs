@@ -1,13 +1,47 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl CheckState {
+    pub fn from_bool(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            CheckState::Checked => CheckState::Unchecked,
+            CheckState::Unchecked | CheckState::Indeterminate => CheckState::Checked,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CheckState::Checked => "true",
+            CheckState::Unchecked => "false",
+            CheckState::Indeterminate => "mixed",
+        }
+    }
+}
+
 pub struct Checkbox {
     pub key: &'static str,
     pub rect: Rect,
-    pub value: bool,
+    pub state: CheckState,
     pub label: &'static str,
     pub focused: bool,
     pub style: CheckboxStyle,
@@ -19,7 +53,7 @@ pub struct CheckboxStyle {
     pub focus_border: &'static str,
     pub check: &'static str,
     pub text: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
 }
 
 impl Default for CheckboxStyle {
@@ -30,20 +64,62 @@ impl Default for CheckboxStyle {
             focus_border: "#27ffd8",
             check: "#27ffd8",
             text: "#d8e3ff",
-            font: "600 14px Consolas",
+            font: FontDescriptor::new("Consolas", 14.0).weight(600),
+        }
+    }
+}
+
+pub fn draw_check_box(
+    context: &CanvasRenderingContext2d,
+    style: &CheckboxStyle,
+    box_rect: Rect,
+    state: CheckState,
+    focused: bool,
+) {
+    context.set_fill_style_str(style.fill);
+    context.fill_rect(box_rect.x, box_rect.y, box_rect.width, box_rect.height);
+    context.set_stroke_style_str(if focused { style.focus_border } else { style.border });
+    context.set_line_width(2.0);
+    context.stroke_rect(box_rect.x, box_rect.y, box_rect.width, box_rect.height);
+
+    match state {
+        CheckState::Checked => {
+            context.set_stroke_style_str(style.check);
+            context.set_line_width(2.5);
+            context.begin_path();
+            context.move_to(box_rect.x + 4.0, box_rect.y + 9.5);
+            context.line_to(box_rect.x + 8.0, box_rect.y + 14.0);
+            context.line_to(box_rect.x + 14.0, box_rect.y + 5.0);
+            context.stroke();
+        }
+        CheckState::Indeterminate => {
+            context.set_fill_style_str(style.check);
+            context.fill_rect(
+                box_rect.x + 4.0,
+                box_rect.y + box_rect.height * 0.5 - 1.5,
+                box_rect.width - 8.0,
+                3.0,
+            );
         }
+        CheckState::Unchecked => {}
     }
 }
 
 impl Checkbox {
     pub fn set_value(&mut self, value: bool) {
-        self.value = value;
+        self.state = CheckState::from_bool(value);
+    }
+
+    #[allow(dead_code)]
+    pub fn set_state(&mut self, state: CheckState) {
+        self.state = state;
     }
 
-    fn emit_changed(&self) -> UiEvent {
+    fn toggle(&mut self) -> UiEvent {
+        self.state = self.state.toggled();
         UiEvent::ValueChanged {
             key: self.key,
-            value: self.value.to_string(),
+            value: self.state.as_str().to_string(),
         }
     }
 }
@@ -62,16 +138,14 @@ impl Widget for Checkbox {
         let hovered = self.rect.contains(pointer.x, pointer.y);
 
         if hovered && pointer.just_released {
-            self.value = !self.value;
-            events.push(self.emit_changed());
+            events.push(self.toggle());
         } else if self.focused
             && (pointer.activate_primary || pointer.move_left || pointer.move_right)
         {
-            self.value = !self.value;
-            events.push(self.emit_changed());
+            events.push(self.toggle());
         }
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_fill_style_str(self.style.text);
         context.set_text_align("left");
         context.set_text_baseline("middle");
@@ -80,26 +154,15 @@ impl Widget for Checkbox {
         let box_x = self.rect.x + 2.0;
         let box_y = self.rect.y + (self.rect.height - box_size) * 0.5;
 
-        context.set_fill_style_str(self.style.fill);
-        context.fill_rect(box_x, box_y, box_size, box_size);
-        context.set_stroke_style_str(if self.focused {
-            self.style.focus_border
-        } else {
-            self.style.border
-        });
-        context.set_line_width(2.0);
-        context.stroke_rect(box_x, box_y, box_size, box_size);
-
-        if self.value {
-            context.set_stroke_style_str(self.style.check);
-            context.set_line_width(2.5);
-            context.begin_path();
-            context.move_to(box_x + 4.0, box_y + 9.5);
-            context.line_to(box_x + 8.0, box_y + 14.0);
-            context.line_to(box_x + 14.0, box_y + 5.0);
-            context.stroke();
-        }
+        let box_rect = Rect {
+            x: box_x,
+            y: box_y,
+            width: box_size,
+            height: box_size,
+        };
+        draw_check_box(context, &self.style, box_rect, self.state, self.focused);
 
+        context.set_fill_style_str(self.style.text);
         let _ = context.fill_text(
             self.label,
             box_x + box_size + 8.0,
@@ -118,8 +181,7 @@ impl Widget for Checkbox {
     }
 
     fn activate(&mut self) -> Option<UiEvent> {
-        self.value = !self.value;
-        Some(self.emit_changed())
+        Some(self.toggle())
     }
 
     fn as_any_mut(&mut self) -> &mut dyn Any {
@@ -0,0 +1,220 @@
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::ui::tree::{UiEvent, Widget};
+use crate::widgets::checkbox::{CheckState, CheckboxStyle, draw_check_box};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+pub struct CheckboxItem {
+    pub id: String,
+    pub label: String,
+    pub checked: bool,
+}
+
+impl CheckboxItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            checked: false,
+        }
+    }
+
+    pub fn checked(mut self) -> Self {
+        self.checked = true;
+        self
+    }
+}
+
+pub struct CheckboxGroup {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub label: String,
+    pub items: Vec<CheckboxItem>,
+    pub row_height: f64,
+    pub indent: f64,
+    pub style: CheckboxStyle,
+    pub focused: bool,
+    focused_row: usize,
+}
+
+impl CheckboxGroup {
+    pub fn new(key: &'static str, label: impl Into<String>, items: Vec<CheckboxItem>) -> Self {
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            label: label.into(),
+            items,
+            row_height: 28.0,
+            indent: 24.0,
+            style: CheckboxStyle::default(),
+            focused: false,
+            focused_row: 0,
+        }
+    }
+
+    pub fn parent_state(&self) -> CheckState {
+        let checked = self.items.iter().filter(|item| item.checked).count();
+        if checked == 0 {
+            CheckState::Unchecked
+        } else if checked == self.items.len() {
+            CheckState::Checked
+        } else {
+            CheckState::Indeterminate
+        }
+    }
+
+    pub fn checked_ids(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|item| item.checked)
+            .map(|item| item.id.clone())
+            .collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn set_checked(&mut self, ids: &[String]) {
+        for item in &mut self.items {
+            item.checked = ids.contains(&item.id);
+        }
+    }
+
+    fn emit_changed(&self) -> UiEvent {
+        UiEvent::CheckedChanged {
+            key: self.key,
+            ids: self.checked_ids(),
+        }
+    }
+
+    fn toggle_row(&mut self, row: usize) -> Option<UiEvent> {
+        if row == 0 {
+            let checked = self.parent_state().toggled() == CheckState::Checked;
+            for item in &mut self.items {
+                item.checked = checked;
+            }
+        } else {
+            let item = self.items.get_mut(row - 1)?;
+            item.checked = !item.checked;
+        }
+        Some(self.emit_changed())
+    }
+
+    fn row_rect(&self, row: usize) -> Rect {
+        Rect {
+            x: self.rect.x,
+            y: self.rect.y + row as f64 * self.row_height,
+            width: self.rect.width,
+            height: self.row_height,
+        }
+    }
+
+    fn row_at(&self, x: f64, y: f64) -> Option<usize> {
+        if !self.rect.contains(x, y) {
+            return None;
+        }
+        let row = ((y - self.rect.y) / self.row_height).floor() as usize;
+        (row <= self.items.len()).then_some(row)
+    }
+
+    fn draw_row(&self, context: &CanvasRenderingContext2d, row: usize, label: &str, state: CheckState) {
+        let rect = self.row_rect(row);
+        let box_size = 18.0;
+        let indent = if row == 0 { 0.0 } else { self.indent };
+        let box_rect = Rect {
+            x: rect.x + 2.0 + indent,
+            y: rect.y + (rect.height - box_size) * 0.5,
+            width: box_size,
+            height: box_size,
+        };
+        draw_check_box(context, &self.style, box_rect, state, self.focused && row == self.focused_row);
+        context.set_fill_style_str(self.style.text);
+        let _ = context.fill_text(label, box_rect.x + box_size + 8.0, rect.y + rect.height * 0.5);
+    }
+}
+
+impl Widget for CheckboxGroup {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.row_height * (self.items.len() + 1) as f64)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let mut events = Vec::new();
+        self.focused_row = self.focused_row.min(self.items.len());
+
+        if pointer.just_released
+            && let Some(row) = self.row_at(pointer.x, pointer.y)
+        {
+            self.focused_row = row;
+            events.extend(self.toggle_row(row));
+        }
+        if self.focused {
+            if pointer.move_up {
+                self.focused_row = self.focused_row.saturating_sub(1);
+            } else if pointer.move_down {
+                self.focused_row = (self.focused_row + 1).min(self.items.len());
+            } else if pointer.move_home {
+                self.focused_row = 0;
+            } else if pointer.move_end {
+                self.focused_row = self.items.len();
+            }
+        }
+
+        context.set_font(&self.style.font.css());
+        context.set_text_align("left");
+        context.set_text_baseline("middle");
+        self.draw_row(context, 0, &self.label, self.parent_state());
+        for (index, item) in self.items.iter().enumerate() {
+            self.draw_row(context, index + 1, &item.label, CheckState::from_bool(item.checked));
+        }
+
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.focused_row = 0;
+        }
+    }
+
+    fn focus_next_in_children(&mut self) -> bool {
+        if self.focused_row < self.items.len() {
+            self.focused_row += 1;
+            return true;
+        }
+        false
+    }
+
+    fn focus_prev_in_children(&mut self) -> bool {
+        if self.focused_row > 0 {
+            self.focused_row -= 1;
+            return true;
+        }
+        false
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        Some(self.row_rect(self.focused_row))
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        self.toggle_row(self.focused_row)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,397 @@
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::navigation::{FuzzyMatch, fuzzy_rank};
+use crate::text::font::FontDescriptor;
+use crate::text::layout::draw_highlighted;
+use crate::ui::overlay::{pointer_for, push_overlay};
+use crate::ui::tree::{UiEvent, Widget};
+use crate::widgets::text_input::TextInput;
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+#[derive(Clone, Copy)]
+pub struct ComboBoxStyle {
+    pub popup_fill: &'static str,
+    pub option_hover_fill: &'static str,
+    pub border: &'static str,
+    pub focus_border: &'static str,
+    pub button_fill: &'static str,
+    pub text: &'static str,
+    pub muted_text: &'static str,
+    pub match_text: &'static str,
+    pub font: FontDescriptor,
+    pub option_height: f64,
+    pub button_width: f64,
+    pub max_visible: usize,
+}
+
+impl Default for ComboBoxStyle {
+    fn default() -> Self {
+        Self {
+            popup_fill: "#0f172a",
+            option_hover_fill: "#1f2937",
+            border: "#2a3350",
+            focus_border: "#27ffd8",
+            button_fill: "#111827",
+            text: "#d8e3ff",
+            muted_text: "#6f7fa8",
+            match_text: "#27ffd8",
+            font: FontDescriptor::new("Consolas", 14.0),
+            option_height: 30.0,
+            button_width: 32.0,
+            max_visible: 6,
+        }
+    }
+}
+
+pub struct ComboBox {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub input: TextInput,
+    pub options: Vec<String>,
+    pub allow_free_text: bool,
+    pub style: ComboBoxStyle,
+    pub focused: bool,
+    pub open: bool,
+    value: String,
+    query: String,
+    matches: Vec<FuzzyMatch>,
+    highlighted: usize,
+    first_visible: usize,
+}
+
+impl ComboBox {
+    pub fn new(key: &'static str, options: Vec<String>, placeholder: &'static str) -> Self {
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+        Self {
+            key,
+            rect,
+            input: TextInput::new(key, placeholder),
+            options,
+            allow_free_text: false,
+            style: ComboBoxStyle::default(),
+            focused: false,
+            open: false,
+            value: String::new(),
+            query: String::new(),
+            matches: Vec::new(),
+            highlighted: 0,
+            first_visible: 0,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    #[allow(dead_code)]
+    pub fn set_value(&mut self, value: String) {
+        if self.value != value {
+            self.input.set_value(value.clone());
+            self.query = value.clone();
+            self.value = value;
+        }
+    }
+
+    fn button_rect(&self) -> Rect {
+        Rect {
+            x: self.rect.x + self.rect.width - self.style.button_width,
+            width: self.style.button_width,
+            ..self.rect
+        }
+    }
+
+    fn visible_slots(&self) -> usize {
+        self.matches.len().clamp(1, self.style.max_visible.max(1))
+    }
+
+    fn popup_rect(&self) -> Rect {
+        Rect {
+            x: self.rect.x,
+            y: self.rect.y + self.rect.height + 2.0,
+            width: self.rect.width,
+            height: self.visible_slots() as f64 * self.style.option_height,
+        }
+    }
+
+    fn option_at(&self, x: f64, y: f64) -> Option<usize> {
+        let popup = self.popup_rect();
+        if !popup.contains(x, y) {
+            return None;
+        }
+        let slot = ((y - popup.y) / self.style.option_height).floor() as usize;
+        let position = self.first_visible + slot;
+        (position < self.matches.len()).then_some(position)
+    }
+
+    fn open_with(&mut self, query: &str) {
+        self.matches = fuzzy_rank(&self.options, query);
+        self.highlighted = 0;
+        self.first_visible = 0;
+        self.open = true;
+    }
+
+    fn move_highlight(&mut self, delta: isize) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let last = self.matches.len() as isize - 1;
+        self.highlighted = (self.highlighted as isize + delta).clamp(0, last) as usize;
+        let slots = self.visible_slots();
+        if self.highlighted < self.first_visible {
+            self.first_visible = self.highlighted;
+        } else if self.highlighted >= self.first_visible + slots {
+            self.first_visible = self.highlighted + 1 - slots;
+        }
+    }
+
+    fn commit(&mut self, value: String) -> Option<UiEvent> {
+        self.open = false;
+        self.input.set_value(value.clone());
+        self.query = value.clone();
+        if self.value == value {
+            return None;
+        }
+        self.value = value.clone();
+        Some(UiEvent::ValueChanged {
+            key: self.key,
+            value,
+        })
+    }
+
+    fn commit_input(&mut self) -> Option<UiEvent> {
+        let typed = self.input.value.trim().to_string();
+        let exact = self
+            .options
+            .iter()
+            .find(|option| option.to_lowercase() == typed.to_lowercase())
+            .cloned();
+        match exact {
+            Some(option) => self.commit(option),
+            None if self.allow_free_text => self.commit(typed),
+            None => self.revert(),
+        }
+    }
+
+    fn revert(&mut self) -> Option<UiEvent> {
+        self.open = false;
+        self.input.set_value(self.value.clone());
+        self.query = self.value.clone();
+        None
+    }
+
+    fn commit_highlighted(&mut self) -> Option<UiEvent> {
+        match self.matches.get(self.highlighted).map(|found| self.options[found.index].clone()) {
+            Some(option) => self.commit(option),
+            None => self.commit_input(),
+        }
+    }
+
+    fn handle_keys(&mut self, pointer: &PointerState) -> Option<UiEvent> {
+        let page = self.visible_slots() as isize;
+        if self.open {
+            if pointer.move_up {
+                self.move_highlight(-1);
+            } else if pointer.move_down {
+                self.move_highlight(1);
+            } else if pointer.move_page_up {
+                self.move_highlight(-page);
+            } else if pointer.move_page_down {
+                self.move_highlight(page);
+            } else if pointer.cancel {
+                self.open = false;
+            }
+            None
+        } else if pointer.move_down || pointer.move_up {
+            let query = if self.input.value == self.value {
+                String::new()
+            } else {
+                self.input.value.clone()
+            };
+            self.open_with(&query);
+            None
+        } else if pointer.cancel {
+            self.revert()
+        } else {
+            None
+        }
+    }
+
+    fn push_popup(&self, hover: Option<usize>) {
+        let popup = self.popup_rect();
+        let style = self.style;
+        let slots = self.visible_slots();
+        let labels = self
+            .matches
+            .iter()
+            .skip(self.first_visible)
+            .take(slots)
+            .map(|found| (self.options[found.index].clone(), found.ranges.clone()))
+            .collect::<Vec<_>>();
+        let highlighted = self.highlighted.checked_sub(self.first_visible);
+        let hover = hover.and_then(|position| position.checked_sub(self.first_visible));
+        let scrollbar = (self.matches.len() > slots).then(|| {
+            let fraction = slots as f64 / self.matches.len() as f64;
+            let offset = self.first_visible as f64 / self.matches.len() as f64;
+            (offset * popup.height, fraction * popup.height)
+        });
+
+        push_overlay(self.key, popup, move |context| {
+            context.set_fill_style_str(style.popup_fill);
+            context.fill_rect(popup.x, popup.y, popup.width, popup.height);
+            let font = style.font.css();
+            context.set_font(&font);
+            context.set_text_align("left");
+            context.set_text_baseline("middle");
+            if labels.is_empty() {
+                context.set_fill_style_str(style.muted_text);
+                let _ = context.fill_text("No matches", popup.x + 10.0, popup.y + style.option_height * 0.5);
+            }
+            for (slot, (label, ranges)) in labels.iter().enumerate() {
+                let y = popup.y + slot as f64 * style.option_height;
+                if Some(slot) == highlighted || Some(slot) == hover {
+                    context.set_fill_style_str(style.option_hover_fill);
+                    context.fill_rect(popup.x, y, popup.width, style.option_height);
+                }
+                let text_rect = Rect {
+                    x: popup.x + 10.0,
+                    y,
+                    width: popup.width - 24.0,
+                    height: style.option_height,
+                };
+                draw_highlighted(context, &font, label, ranges, text_rect, (style.text, style.match_text));
+            }
+            if let Some((offset, length)) = scrollbar {
+                context.set_fill_style_str(style.border);
+                context.fill_rect(popup.x + popup.width - 5.0, popup.y + offset, 3.0, length);
+            }
+            context.set_stroke_style_str(style.border);
+            context.set_line_width(1.0);
+            context.stroke_rect(popup.x, popup.y, popup.width, popup.height);
+        });
+    }
+}
+
+impl Widget for ComboBox {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let pointer = pointer_for(self.key, pointer);
+        let mut events = Vec::new();
+        if !self.focused && self.input.value != self.value {
+            events.extend(self.commit_input());
+        }
+
+        let mut input_pointer = pointer.clone();
+        if self.focused {
+            events.extend(self.handle_keys(&pointer));
+            input_pointer.move_up = false;
+            input_pointer.move_down = false;
+            input_pointer.cancel = false;
+        }
+
+        let button = self.button_rect();
+        self.input.focused = self.focused;
+        self.input.set_rect(Rect {
+            width: (self.rect.width - self.style.button_width).max(0.0),
+            ..self.rect
+        });
+        let _ = self.input.draw(context, &input_pointer);
+        if self.input.value != self.query {
+            self.query = self.input.value.clone();
+            let query = self.query.clone();
+            self.open_with(&query);
+        }
+
+        let popup = self.popup_rect();
+        if self.open && popup.contains(pointer.x, pointer.y) && pointer.scroll_y != 0.0 {
+            let slots = self.visible_slots();
+            let max_first = self.matches.len().saturating_sub(slots);
+            let step = if pointer.scroll_y > 0.0 { 1 } else { -1 };
+            self.first_visible = (self.first_visible as isize + step).clamp(0, max_first as isize) as usize;
+        }
+        if pointer.just_released {
+            if button.contains(pointer.x, pointer.y) {
+                if self.open {
+                    self.open = false;
+                } else {
+                    self.open_with("");
+                }
+            } else if self.open
+                && let Some(position) = self.option_at(pointer.x, pointer.y)
+            {
+                let option = self.options[self.matches[position].index].clone();
+                events.extend(self.commit(option));
+            } else if self.open && !self.rect.contains(pointer.x, pointer.y) && !popup.contains(pointer.x, pointer.y) {
+                self.open = false;
+            }
+        }
+
+        context.set_fill_style_str(self.style.button_fill);
+        context.fill_rect(button.x, button.y, button.width, button.height);
+        context.set_stroke_style_str(if self.focused {
+            self.style.focus_border
+        } else {
+            self.style.border
+        });
+        context.set_line_width(2.0);
+        context.stroke_rect(button.x, button.y, button.width, button.height);
+        let center_x = button.x + button.width * 0.5;
+        let center_y = button.y + button.height * 0.5;
+        context.set_fill_style_str(self.style.text);
+        context.begin_path();
+        if self.open {
+            context.move_to(center_x - 5.0, center_y + 3.0);
+            context.line_to(center_x + 5.0, center_y + 3.0);
+            context.line_to(center_x, center_y - 3.0);
+        } else {
+            context.move_to(center_x - 5.0, center_y - 3.0);
+            context.line_to(center_x + 5.0, center_y - 3.0);
+            context.line_to(center_x, center_y + 3.0);
+        }
+        context.close_path();
+        context.fill();
+
+        if self.open {
+            let hover = self.option_at(pointer.x, pointer.y);
+            self.push_popup(hover);
+        }
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.input.set_focused(focused);
+        if !focused {
+            self.open = false;
+        }
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        if self.open {
+            self.commit_highlighted()
+        } else {
+            self.commit_input()
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,605 @@
+use crate::core::date::{Date, month_grid};
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::locale::Locale;
+use crate::text::font::FontDescriptor;
+use crate::ui::overlay::{pointer_for, push_overlay};
+use crate::ui::tree::{UiEvent, Widget};
+use crate::widgets::text_input::TextInput;
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+const RANGE_SEPARATOR: &str = " \u{2013} ";
+
+#[derive(Clone, Copy)]
+pub struct DatePickerStyle {
+    pub popup_fill: &'static str,
+    pub border: &'static str,
+    pub focus_border: &'static str,
+    pub button_fill: &'static str,
+    pub text: &'static str,
+    pub muted_text: &'static str,
+    pub disabled_text: &'static str,
+    pub cursor_fill: &'static str,
+    pub selected_fill: &'static str,
+    pub range_fill: &'static str,
+    pub today_border: &'static str,
+    pub font: FontDescriptor,
+    pub header_font: FontDescriptor,
+    pub cell_size: f64,
+    pub header_height: f64,
+    pub button_width: f64,
+}
+
+impl Default for DatePickerStyle {
+    fn default() -> Self {
+        Self {
+            popup_fill: "#0f172a",
+            border: "#2a3350",
+            focus_border: "#27ffd8",
+            button_fill: "#111827",
+            text: "#d8e3ff",
+            muted_text: "#6f7fa8",
+            disabled_text: "#3b4768",
+            cursor_fill: "#1f2937",
+            selected_fill: "#1f3b66",
+            range_fill: "#172844",
+            today_border: "#27ffd8",
+            font: FontDescriptor::new("Consolas", 13.0),
+            header_font: FontDescriptor::new("Consolas", 14.0).weight(600),
+            cell_size: 34.0,
+            header_height: 36.0,
+            button_width: 32.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DateSelection {
+    Single,
+    Range,
+}
+
+#[derive(Clone)]
+struct DayCell {
+    rect: Rect,
+    day: u32,
+    in_month: bool,
+    selectable: bool,
+    selected: bool,
+    in_range: bool,
+    today: bool,
+    cursor: bool,
+}
+
+pub struct DatePicker {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub input: TextInput,
+    pub locale: Locale,
+    pub min: Option<Date>,
+    pub max: Option<Date>,
+    pub disabled: Option<fn(Date) -> bool>,
+    pub selection: DateSelection,
+    pub today: Date,
+    pub style: DatePickerStyle,
+    pub focused: bool,
+    pub open: bool,
+    start: Option<Date>,
+    end: Option<Date>,
+    anchor: Option<Date>,
+    cursor: Date,
+    view: Date,
+    committed: String,
+}
+
+impl DatePicker {
+    pub fn new(key: &'static str, today: Date, placeholder: &'static str) -> Self {
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+        Self {
+            key,
+            rect,
+            input: TextInput::new(key, placeholder),
+            locale: Locale::default(),
+            min: None,
+            max: None,
+            disabled: None,
+            selection: DateSelection::Single,
+            today,
+            style: DatePickerStyle::default(),
+            focused: false,
+            open: false,
+            start: None,
+            end: None,
+            anchor: None,
+            cursor: today,
+            view: today.first_of_month(),
+            committed: String::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn selected(&self) -> Option<Date> {
+        self.start
+    }
+
+    #[allow(dead_code)]
+    pub fn selected_range(&self) -> Option<(Date, Date)> {
+        Some((self.start?, self.end.or(self.start)?))
+    }
+
+    #[allow(dead_code)]
+    pub fn set_value(&mut self, value: &str) {
+        let mut parts = value.splitn(2, '/');
+        self.start = parts.next().and_then(Date::parse_iso);
+        self.end = match self.selection {
+            DateSelection::Single => None,
+            DateSelection::Range => parts.next().and_then(Date::parse_iso).or(self.start),
+        };
+        self.anchor = None;
+        self.committed = self.value_string();
+        self.input.set_value(self.display_text());
+    }
+
+    pub fn is_selectable(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self.disabled.is_some_and(|disabled| disabled(date))
+    }
+
+    fn value_string(&self) -> String {
+        match (self.start, self.end, self.selection) {
+            (Some(start), Some(end), DateSelection::Range) => format!("{}/{}", start, end),
+            (Some(start), _, _) => start.to_string(),
+            (None, _, _) => String::new(),
+        }
+    }
+
+    fn display_text(&self) -> String {
+        match (self.start, self.end, self.selection) {
+            (Some(start), Some(end), DateSelection::Range) => format!(
+                "{}{}{}",
+                start.format_local(&self.locale),
+                RANGE_SEPARATOR,
+                end.format_local(&self.locale)
+            ),
+            (Some(start), _, _) => start.format_local(&self.locale),
+            (None, _, _) => String::new(),
+        }
+    }
+
+    fn parse_input(&self) -> Option<(Date, Option<Date>)> {
+        let text = self.input.value.trim();
+        match self.selection {
+            DateSelection::Single => Some((Date::parse_local(text, &self.locale)?, None)),
+            DateSelection::Range => {
+                let (first, second) = text
+                    .split_once(RANGE_SEPARATOR.trim())
+                    .or_else(|| text.split_once(" - "))
+                    .unwrap_or((text, text));
+                let first = Date::parse_local(first, &self.locale)?;
+                let second = Date::parse_local(second, &self.locale)?;
+                Some((first.min(second), Some(first.max(second))))
+            }
+        }
+    }
+
+    fn button_rect(&self) -> Rect {
+        Rect {
+            x: self.rect.x + self.rect.width - self.style.button_width,
+            width: self.style.button_width,
+            ..self.rect
+        }
+    }
+
+    fn popup_rect(&self) -> Rect {
+        let cell = self.style.cell_size;
+        Rect {
+            x: self.rect.x,
+            y: self.rect.y + self.rect.height + 2.0,
+            width: cell * 7.0 + 16.0,
+            height: self.style.header_height + cell * 0.7 + cell * 6.0 + 8.0,
+        }
+    }
+
+    fn can_go_back(&self) -> bool {
+        self.min.is_none_or(|min| self.view > min.first_of_month())
+    }
+
+    fn can_go_forward(&self) -> bool {
+        self.max.is_none_or(|max| self.view < max.first_of_month())
+    }
+
+    fn month_button_rects(&self) -> (Rect, Rect) {
+        let popup = self.popup_rect();
+        let size = self.style.header_height - 8.0;
+        let previous = Rect {
+            x: popup.x + 8.0,
+            y: popup.y + 4.0,
+            width: size,
+            height: size,
+        };
+        let next = Rect {
+            x: popup.x + popup.width - 8.0 - size,
+            ..previous
+        };
+        (previous, next)
+    }
+
+    fn grid_origin(&self) -> (f64, f64) {
+        let popup = self.popup_rect();
+        (popup.x + 8.0, popup.y + self.style.header_height + self.style.cell_size * 0.7)
+    }
+
+    fn date_at(&self, x: f64, y: f64) -> Option<Date> {
+        let (left, top) = self.grid_origin();
+        let cell = self.style.cell_size;
+        if x < left || y < top || x >= left + cell * 7.0 || y >= top + cell * 6.0 {
+            return None;
+        }
+        let column = ((x - left) / cell).floor() as usize;
+        let row = ((y - top) / cell).floor() as usize;
+        month_grid(self.view.year, self.view.month, self.locale.week_start)
+            .get(row * 7 + column)
+            .copied()
+    }
+
+    fn show_month_of(&mut self, date: Date) {
+        self.view = date.first_of_month();
+    }
+
+    fn move_cursor(&mut self, target: Date) {
+        self.cursor = target.clamp_to(self.min, self.max);
+        self.show_month_of(self.cursor);
+    }
+
+    fn open_popup(&mut self) {
+        self.open = true;
+        self.anchor = None;
+        let target = self.start.unwrap_or(self.today);
+        self.move_cursor(target);
+    }
+
+    fn commit(&mut self) -> Option<UiEvent> {
+        self.open = false;
+        self.anchor = None;
+        self.input.set_value(self.display_text());
+        let value = self.value_string();
+        if value == self.committed {
+            return None;
+        }
+        self.committed = value.clone();
+        Some(UiEvent::ValueChanged { key: self.key, value })
+    }
+
+    fn pick(&mut self, date: Date) -> Option<UiEvent> {
+        if !self.is_selectable(date) {
+            return None;
+        }
+        self.cursor = date;
+        match (self.selection, self.anchor) {
+            (DateSelection::Single, _) => {
+                self.start = Some(date);
+                self.commit()
+            }
+            (DateSelection::Range, None) => {
+                self.anchor = Some(date);
+                None
+            }
+            (DateSelection::Range, Some(anchor)) => {
+                self.start = Some(anchor.min(date));
+                self.end = Some(anchor.max(date));
+                self.commit()
+            }
+        }
+    }
+
+    fn commit_input(&mut self) -> Option<UiEvent> {
+        if self.input.value.trim().is_empty() {
+            self.start = None;
+            self.end = None;
+            return self.commit();
+        }
+        match self.parse_input() {
+            Some((start, end)) if self.is_selectable(start) && end.is_none_or(|end| self.is_selectable(end)) => {
+                self.start = Some(start);
+                self.end = end;
+                self.cursor = start;
+                self.commit()
+            }
+            _ => self.commit(),
+        }
+    }
+
+    fn handle_keys(&mut self, pointer: &PointerState) -> Option<UiEvent> {
+        if !self.open {
+            if pointer.move_down {
+                self.open_popup();
+            } else if pointer.cancel {
+                self.input.set_value(self.display_text());
+            }
+            return None;
+        }
+
+        let week_offset = (self.cursor.weekday() + 7 - self.locale.week_start % 7) % 7;
+        let target = if pointer.move_left {
+            Some(self.cursor.add_days(-1))
+        } else if pointer.move_right {
+            Some(self.cursor.add_days(1))
+        } else if pointer.move_up {
+            Some(self.cursor.add_days(-7))
+        } else if pointer.move_down {
+            Some(self.cursor.add_days(7))
+        } else if pointer.move_page_up {
+            Some(self.cursor.add_months(if pointer.modifiers.shift { -12 } else { -1 }))
+        } else if pointer.move_page_down {
+            Some(self.cursor.add_months(if pointer.modifiers.shift { 12 } else { 1 }))
+        } else if pointer.move_home {
+            Some(self.cursor.add_days(-i64::from(week_offset)))
+        } else if pointer.move_end {
+            Some(self.cursor.add_days(6 - i64::from(week_offset)))
+        } else {
+            None
+        };
+        if let Some(target) = target {
+            self.move_cursor(target);
+        } else if pointer.cancel {
+            self.open = false;
+            self.anchor = None;
+        }
+        None
+    }
+
+    fn day_cells(&self, hover: Option<Date>) -> Vec<DayCell> {
+        let (left, top) = self.grid_origin();
+        let cell = self.style.cell_size;
+        let preview = self.anchor.map(|anchor| {
+            let other = hover.unwrap_or(self.cursor);
+            (anchor.min(other), anchor.max(other))
+        });
+        let range = preview.or(match (self.start, self.end) {
+            (Some(start), Some(end)) if self.selection == DateSelection::Range => Some((start, end)),
+            _ => None,
+        });
+        month_grid(self.view.year, self.view.month, self.locale.week_start)
+            .into_iter()
+            .enumerate()
+            .map(|(index, date)| DayCell {
+                rect: Rect {
+                    x: left + (index % 7) as f64 * cell,
+                    y: top + (index / 7) as f64 * cell,
+                    width: cell,
+                    height: cell,
+                },
+                day: date.day,
+                in_month: date.month == self.view.month,
+                selectable: self.is_selectable(date),
+                selected: Some(date) == self.anchor
+                    || (self.anchor.is_none() && (Some(date) == self.start || Some(date) == self.end)),
+                in_range: range.is_some_and(|(start, end)| date >= start && date <= end),
+                today: date == self.today,
+                cursor: self.focused && date == self.cursor,
+            })
+            .collect()
+    }
+
+    fn push_popup(&self, hover: Option<Date>) {
+        let popup = self.popup_rect();
+        let style = self.style;
+        let (previous, next) = self.month_button_rects();
+        let title = format!("{} {}", self.locale.month_name(self.view.month), self.view.year);
+        let weekdays = (0..7)
+            .map(|offset| self.locale.weekday_name(self.locale.week_start + offset))
+            .collect::<Vec<_>>();
+        let (left, top) = self.grid_origin();
+        let cells = self.day_cells(hover);
+        let can_go_back = self.can_go_back();
+        let can_go_forward = self.can_go_forward();
+
+        push_overlay(self.key, popup, move |context| {
+            context.set_fill_style_str(style.popup_fill);
+            context.fill_rect(popup.x, popup.y, popup.width, popup.height);
+            context.set_stroke_style_str(style.border);
+            context.set_line_width(1.0);
+            context.stroke_rect(popup.x, popup.y, popup.width, popup.height);
+
+            context.set_text_align("center");
+            context.set_text_baseline("middle");
+            context.set_font(&style.header_font.css());
+            context.set_fill_style_str(style.text);
+            let _ = context.fill_text(&title, popup.x + popup.width * 0.5, popup.y + style.header_height * 0.5);
+            for (button, enabled, glyph) in [(previous, can_go_back, "\u{2039}"), (next, can_go_forward, "\u{203a}")] {
+                context.set_fill_style_str(style.button_fill);
+                context.fill_rect(button.x, button.y, button.width, button.height);
+                context.set_fill_style_str(if enabled { style.text } else { style.disabled_text });
+                let _ = context.fill_text(glyph, button.x + button.width * 0.5, button.y + button.height * 0.5);
+            }
+
+            context.set_font(&style.font.css());
+            context.set_fill_style_str(style.muted_text);
+            for (column, name) in weekdays.iter().enumerate() {
+                let x = left + (column as f64 + 0.5) * style.cell_size;
+                let _ = context.fill_text(name, x, top - style.cell_size * 0.35);
+            }
+
+            for cell in &cells {
+                let inner = Rect {
+                    x: cell.rect.x + 2.0,
+                    y: cell.rect.y + 2.0,
+                    width: cell.rect.width - 4.0,
+                    height: cell.rect.height - 4.0,
+                };
+                if cell.in_range {
+                    context.set_fill_style_str(style.range_fill);
+                    context.fill_rect(cell.rect.x, inner.y, cell.rect.width, inner.height);
+                }
+                if cell.selected {
+                    context.set_fill_style_str(style.selected_fill);
+                    context.fill_rect(inner.x, inner.y, inner.width, inner.height);
+                } else if cell.cursor {
+                    context.set_fill_style_str(style.cursor_fill);
+                    context.fill_rect(inner.x, inner.y, inner.width, inner.height);
+                }
+                if cell.cursor {
+                    context.set_stroke_style_str(style.focus_border);
+                    context.set_line_width(1.5);
+                    context.stroke_rect(inner.x, inner.y, inner.width, inner.height);
+                } else if cell.today {
+                    context.set_stroke_style_str(style.today_border);
+                    context.set_line_width(1.0);
+                    context.stroke_rect(inner.x + 3.0, inner.y + 3.0, inner.width - 6.0, inner.height - 6.0);
+                }
+                context.set_fill_style_str(if !cell.selectable {
+                    style.disabled_text
+                } else if cell.in_month {
+                    style.text
+                } else {
+                    style.muted_text
+                });
+                let _ = context.fill_text(
+                    &cell.day.to_string(),
+                    cell.rect.x + cell.rect.width * 0.5,
+                    cell.rect.y + cell.rect.height * 0.5,
+                );
+            }
+        });
+    }
+}
+
+impl Widget for DatePicker {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let pointer = pointer_for(self.key, pointer);
+        let mut events = Vec::new();
+        if !self.focused && self.input.value != self.display_text() {
+            events.extend(self.commit_input());
+        }
+
+        let mut input_pointer = pointer.clone();
+        if self.focused {
+            events.extend(self.handle_keys(&pointer));
+            input_pointer.move_up = false;
+            input_pointer.move_down = false;
+            input_pointer.cancel = false;
+            if self.open {
+                input_pointer.move_left = false;
+                input_pointer.move_right = false;
+                input_pointer.move_home = false;
+                input_pointer.move_end = false;
+            }
+        }
+
+        let typed = self.input.value.clone();
+        self.input.focused = self.focused;
+        self.input.set_rect(Rect {
+            width: (self.rect.width - self.style.button_width).max(0.0),
+            ..self.rect
+        });
+        let _ = self.input.draw(context, &input_pointer);
+        if self.input.value != typed
+            && let Some((start, _)) = self.parse_input()
+        {
+            self.move_cursor(start);
+        }
+
+        let button = self.button_rect();
+        let popup = self.popup_rect();
+        let (previous, next) = self.month_button_rects();
+        if self.open && popup.contains(pointer.x, pointer.y) {
+            if pointer.scroll_y > 0.0 && self.can_go_forward() {
+                self.view = self.view.add_months(1);
+            } else if pointer.scroll_y < 0.0 && self.can_go_back() {
+                self.view = self.view.add_months(-1);
+            }
+        }
+        if pointer.just_released {
+            if button.contains(pointer.x, pointer.y) {
+                if self.open {
+                    self.open = false;
+                    self.anchor = None;
+                } else {
+                    self.open_popup();
+                }
+            } else if self.open && previous.contains(pointer.x, pointer.y) {
+                if self.can_go_back() {
+                    self.view = self.view.add_months(-1);
+                }
+            } else if self.open && next.contains(pointer.x, pointer.y) {
+                if self.can_go_forward() {
+                    self.view = self.view.add_months(1);
+                }
+            } else if self.open
+                && let Some(date) = self.date_at(pointer.x, pointer.y)
+            {
+                events.extend(self.pick(date));
+            } else if self.open && !self.rect.contains(pointer.x, pointer.y) && !popup.contains(pointer.x, pointer.y) {
+                self.open = false;
+                self.anchor = None;
+            }
+        }
+
+        context.set_fill_style_str(self.style.button_fill);
+        context.fill_rect(button.x, button.y, button.width, button.height);
+        context.set_stroke_style_str(if self.focused {
+            self.style.focus_border
+        } else {
+            self.style.border
+        });
+        context.set_line_width(2.0);
+        context.stroke_rect(button.x, button.y, button.width, button.height);
+        let icon = Rect {
+            x: button.x + button.width * 0.5 - 7.0,
+            y: button.y + button.height * 0.5 - 6.0,
+            width: 14.0,
+            height: 12.0,
+        };
+        context.set_stroke_style_str(self.style.text);
+        context.set_line_width(1.5);
+        context.stroke_rect(icon.x, icon.y, icon.width, icon.height);
+        context.set_fill_style_str(self.style.text);
+        context.fill_rect(icon.x, icon.y, icon.width, 3.0);
+
+        if self.open {
+            let hover = self.date_at(pointer.x, pointer.y);
+            self.push_popup(hover);
+        }
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.input.set_focused(focused);
+        if !focused {
+            self.open = false;
+            self.anchor = None;
+        }
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        if self.open {
+            self.pick(self.cursor)
+        } else {
+            self.commit_input()
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,469 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::ellipsize;
+use crate::text::measure::cached_width;
+use crate::ui::overlay::pending_overlay_count;
+use crate::ui::tree::{UiEvent, UiTree};
+use web_sys::CanvasRenderingContext2d;
+
+#[derive(Clone, Copy)]
+pub struct DialogStyle {
+    pub overlay: &'static str,
+    pub panel_fill: &'static str,
+    pub panel_border: &'static str,
+    pub focus_border: &'static str,
+    pub title: &'static str,
+    pub button_idle: &'static str,
+    pub button_hover: &'static str,
+    pub button_primary: &'static str,
+    pub button_primary_hover: &'static str,
+    pub button_text: &'static str,
+    pub title_font: FontDescriptor,
+    pub button_font: FontDescriptor,
+    pub padding: f64,
+    pub title_height: f64,
+    pub button_height: f64,
+    pub button_min_width: f64,
+    pub button_gap: f64,
+    pub margin: f64,
+}
+
+impl Default for DialogStyle {
+    fn default() -> Self {
+        Self {
+            overlay: "rgba(3,8,20,0.7)",
+            panel_fill: "#0f172a",
+            panel_border: "#2a3350",
+            focus_border: "#27ffd8",
+            title: "#e7eeff",
+            button_idle: "#1a2743",
+            button_hover: "#283960",
+            button_primary: "#1d4d5c",
+            button_primary_hover: "#23677a",
+            button_text: "#e2ebff",
+            title_font: FontDescriptor::new("Consolas", 18.0).weight(700),
+            button_font: FontDescriptor::new("Consolas", 14.0).weight(600),
+            padding: 16.0,
+            title_height: 24.0,
+            button_height: 34.0,
+            button_min_width: 96.0,
+            button_gap: 8.0,
+            margin: 16.0,
+        }
+    }
+}
+
+pub struct DialogButton<R> {
+    pub label: &'static str,
+    pub result: R,
+    pub primary: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DialogFocus {
+    Content,
+    Button(usize),
+}
+
+pub struct Dialog<R> {
+    pub key: &'static str,
+    pub title: String,
+    pub content: UiTree,
+    pub buttons: Vec<DialogButton<R>>,
+    pub dismiss: Option<R>,
+    pub width: f64,
+    pub height: Option<f64>,
+    pub style: DialogStyle,
+    focus: Option<DialogFocus>,
+    pressed_outside: bool,
+    content_overlay: bool,
+}
+
+impl<R: Clone> Dialog<R> {
+    pub fn new(key: &'static str, title: impl Into<String>, content: UiTree) -> Self {
+        Self {
+            key,
+            title: title.into(),
+            content,
+            buttons: Vec::new(),
+            dismiss: None,
+            width: 460.0,
+            height: None,
+            style: DialogStyle::default(),
+            focus: None,
+            pressed_outside: false,
+            content_overlay: false,
+        }
+    }
+
+    pub fn button(mut self, label: &'static str, result: R) -> Self {
+        self.buttons.push(DialogButton {
+            label,
+            result,
+            primary: false,
+        });
+        self
+    }
+
+    pub fn primary_button(mut self, label: &'static str, result: R) -> Self {
+        self.buttons.push(DialogButton {
+            label,
+            result,
+            primary: true,
+        });
+        self
+    }
+
+    pub fn dismissible(mut self, result: R) -> Self {
+        self.dismiss = Some(result);
+        self
+    }
+
+    pub fn size(mut self, width: f64, height: Option<f64>) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.focus == Some(DialogFocus::Content) && self.content.handles_shortcut(shortcut)
+    }
+
+    pub fn panel_rect(&self, viewport: Rect) -> Rect {
+        let style = &self.style;
+        let chrome = style.padding * 4.0 + style.title_height + style.button_height;
+        let width = self.width.min(viewport.width - style.margin * 2.0).max(0.0);
+        let height = self
+            .height
+            .unwrap_or_else(|| chrome + self.content.content_size().1)
+            .min(viewport.height - style.margin * 2.0)
+            .max(chrome);
+        Rect {
+            x: viewport.x + (viewport.width - width) * 0.5,
+            y: viewport.y + (viewport.height - height) * 0.5,
+            width,
+            height,
+        }
+    }
+
+    fn content_rect(&self, panel: Rect) -> Rect {
+        let style = &self.style;
+        let top = panel.y + style.padding * 2.0 + style.title_height;
+        let bottom = panel.y + panel.height - style.padding * 2.0 - style.button_height;
+        Rect {
+            x: panel.x + style.padding,
+            y: top,
+            width: (panel.width - style.padding * 2.0).max(0.0),
+            height: (bottom - top).max(0.0),
+        }
+    }
+
+    fn button_rects(&self, context: &CanvasRenderingContext2d, panel: Rect) -> Vec<Rect> {
+        let style = &self.style;
+        let font = style.button_font.css();
+        let y = panel.y + panel.height - style.padding - style.button_height;
+        let mut right = panel.x + panel.width - style.padding;
+        let mut rects = self
+            .buttons
+            .iter()
+            .rev()
+            .map(|button| {
+                let width = (cached_width(context, &font, button.label) + style.padding * 2.0).max(style.button_min_width);
+                right -= width;
+                let rect = Rect {
+                    x: right.max(panel.x + style.padding),
+                    y,
+                    width,
+                    height: style.button_height,
+                };
+                right -= style.button_gap;
+                rect
+            })
+            .collect::<Vec<_>>();
+        rects.reverse();
+        rects
+    }
+
+    fn initial_focus(&mut self) -> Option<DialogFocus> {
+        self.content.clear_focus();
+        if self.content.focus_next_bounded() {
+            return Some(DialogFocus::Content);
+        }
+        self.buttons
+            .iter()
+            .position(|button| button.primary)
+            .or((!self.buttons.is_empty()).then_some(0))
+            .map(DialogFocus::Button)
+    }
+
+    fn enter_content(&mut self, forward: bool) -> bool {
+        self.content.clear_focus();
+        if forward {
+            self.content.focus_next_bounded()
+        } else {
+            self.content.focus_prev_bounded()
+        }
+    }
+
+    fn focus_next(&mut self) {
+        let in_content = match self.focus {
+            Some(DialogFocus::Button(index)) if index + 1 < self.buttons.len() => {
+                self.focus = Some(DialogFocus::Button(index + 1));
+                return;
+            }
+            Some(DialogFocus::Content) => {
+                self.content.focus_next_in_focused_child() || self.content.focus_next_bounded()
+            }
+            _ => self.enter_content(true),
+        };
+        self.focus = if in_content {
+            Some(DialogFocus::Content)
+        } else if !self.buttons.is_empty() {
+            Some(DialogFocus::Button(0))
+        } else {
+            self.enter_content(true).then_some(DialogFocus::Content)
+        };
+    }
+
+    fn focus_prev(&mut self) {
+        let in_content = match self.focus {
+            Some(DialogFocus::Button(index)) if index > 0 => {
+                self.focus = Some(DialogFocus::Button(index - 1));
+                return;
+            }
+            Some(DialogFocus::Content) => {
+                self.content.focus_prev_in_focused_child() || self.content.focus_prev_bounded()
+            }
+            _ => self.enter_content(false),
+        };
+        self.focus = if in_content {
+            Some(DialogFocus::Content)
+        } else if !self.buttons.is_empty() {
+            Some(DialogFocus::Button(self.buttons.len() - 1))
+        } else {
+            self.enter_content(false).then_some(DialogFocus::Content)
+        };
+    }
+
+    pub fn draw(
+        &mut self,
+        context: &CanvasRenderingContext2d,
+        pointer: &PointerState,
+        viewport: Rect,
+    ) -> (Vec<UiEvent>, Option<R>) {
+        let panel = self.panel_rect(viewport);
+        let content_rect = self.content_rect(panel);
+        let button_rects = self.button_rects(context, panel);
+        let inside_panel = panel.contains(pointer.x, pointer.y);
+        let mut result = None;
+
+        if self.focus.is_none() {
+            self.focus = self.initial_focus();
+        }
+        if pointer.focus_next {
+            self.focus_next();
+        } else if pointer.focus_prev {
+            self.focus_prev();
+        }
+
+        if pointer.just_pressed {
+            self.pressed_outside = !inside_panel;
+            if let Some(index) = button_rects.iter().position(|rect| rect.contains(pointer.x, pointer.y)) {
+                self.content.clear_focus();
+                self.focus = Some(DialogFocus::Button(index));
+            }
+        }
+        if pointer.cancel {
+            result = self.dismiss.clone().filter(|_| !self.content_overlay);
+        } else if pointer.just_released {
+            if let Some(index) = button_rects.iter().position(|rect| rect.contains(pointer.x, pointer.y)) {
+                result = Some(self.buttons[index].result.clone());
+            } else if !inside_panel && self.pressed_outside {
+                result = self.dismiss.clone();
+            }
+        } else if pointer.activate_primary
+            && let Some(DialogFocus::Button(index)) = self.focus
+        {
+            result = Some(self.buttons[index].result.clone());
+        }
+
+        context.set_fill_style_str(self.style.overlay);
+        context.fill_rect(viewport.x, viewport.y, viewport.width, viewport.height);
+
+        context.set_fill_style_str(self.style.panel_fill);
+        context.fill_rect(panel.x, panel.y, panel.width, panel.height);
+        context.set_stroke_style_str(self.style.panel_border);
+        context.set_line_width(2.0);
+        context.stroke_rect(panel.x, panel.y, panel.width, panel.height);
+
+        let title_font = self.style.title_font.css();
+        let title_width = panel.width - self.style.padding * 2.0;
+        context.set_fill_style_str(self.style.title);
+        context.set_font(&title_font);
+        context.set_text_align("left");
+        context.set_text_baseline("middle");
+        let _ = context.fill_text(
+            &ellipsize(context, &title_font, &self.title, title_width),
+            panel.x + self.style.padding,
+            panel.y + self.style.padding + self.style.title_height * 0.5,
+        );
+
+        let mut child_pointer = pointer.clone();
+        child_pointer.focus_next = false;
+        child_pointer.focus_prev = false;
+        if self.focus != Some(DialogFocus::Content) {
+            child_pointer.suppress_focus_and_text_input();
+        }
+        self.content.set_area(content_rect);
+        let overlays_before = pending_overlay_count();
+        let events = self.content.draw(context, &child_pointer);
+        self.content_overlay = pending_overlay_count() > overlays_before;
+        if pointer.just_pressed
+            && content_rect.contains(pointer.x, pointer.y)
+            && self.content.focused_key().is_some()
+        {
+            self.focus = Some(DialogFocus::Content);
+        }
+
+        context.set_font(&self.style.button_font.css());
+        context.set_text_align("center");
+        context.set_text_baseline("middle");
+        for (index, (button, rect)) in self.buttons.iter().zip(&button_rects).enumerate() {
+            let hovered = rect.contains(pointer.x, pointer.y);
+            context.set_fill_style_str(match (button.primary, hovered) {
+                (true, true) => self.style.button_primary_hover,
+                (true, false) => self.style.button_primary,
+                (false, true) => self.style.button_hover,
+                (false, false) => self.style.button_idle,
+            });
+            context.fill_rect(rect.x, rect.y, rect.width, rect.height);
+            let focused = self.focus == Some(DialogFocus::Button(index));
+            context.set_stroke_style_str(if focused {
+                self.style.focus_border
+            } else {
+                self.style.panel_border
+            });
+            context.set_line_width(if focused { 2.0 } else { 1.0 });
+            context.stroke_rect(rect.x, rect.y, rect.width, rect.height);
+            context.set_fill_style_str(self.style.button_text);
+            let _ = context.fill_text(
+                button.label,
+                rect.x + rect.width * 0.5,
+                rect.y + rect.height * 0.5,
+            );
+        }
+
+        (events, result)
+    }
+}
+
+pub struct DialogClosed<R> {
+    pub key: &'static str,
+    pub result: R,
+    pub opener: Option<&'static str>,
+}
+
+struct DialogEntry<R> {
+    dialog: Dialog<R>,
+    opener: Option<&'static str>,
+}
+
+pub struct DialogStack<R> {
+    entries: Vec<DialogEntry<R>>,
+    closed: Vec<DialogClosed<R>>,
+}
+
+impl<R> Default for DialogStack<R> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            closed: Vec::new(),
+        }
+    }
+}
+
+impl<R: Clone> DialogStack<R> {
+    pub fn push(&mut self, dialog: Dialog<R>, opener: Option<&'static str>) {
+        self.entries.push(DialogEntry { dialog, opener });
+        request_frame();
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    pub fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.entries.last().is_some_and(|entry| entry.dialog.handles_shortcut(shortcut))
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.iter().any(|entry| entry.dialog.key == key)
+    }
+
+    #[allow(dead_code)]
+    pub fn dialog_mut(&mut self, key: &str) -> Option<&mut Dialog<R>> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.dialog.key == key)
+            .map(|entry| &mut entry.dialog)
+    }
+
+    #[allow(dead_code)]
+    pub fn close(&mut self, key: &str, result: R) {
+        if let Some(index) = self.entries.iter().position(|entry| entry.dialog.key == key) {
+            self.finish(index, result);
+        }
+    }
+
+    fn finish(&mut self, index: usize, result: R) {
+        let entry = self.entries.remove(index);
+        self.closed.push(DialogClosed {
+            key: entry.dialog.key,
+            result,
+            opener: entry.opener,
+        });
+        request_frame();
+    }
+
+    pub fn take_closed(&mut self) -> Vec<DialogClosed<R>> {
+        std::mem::take(&mut self.closed)
+    }
+
+    pub fn draw(
+        &mut self,
+        context: &CanvasRenderingContext2d,
+        pointer: &PointerState,
+        viewport: Rect,
+    ) -> Vec<UiEvent> {
+        let mut inactive = pointer.clone();
+        inactive.x = f64::NEG_INFINITY;
+        inactive.y = f64::NEG_INFINITY;
+        inactive.just_pressed = false;
+        inactive.just_released = false;
+        inactive.is_down = false;
+        inactive.secondary_click = false;
+        inactive.cancel = false;
+        inactive.scroll_x = 0.0;
+        inactive.scroll_y = 0.0;
+        inactive.suppress_focus_and_text_input();
+
+        let top = self.entries.len().saturating_sub(1);
+        let mut events = Vec::new();
+        let mut finished = None;
+        for (index, entry) in self.entries.iter_mut().enumerate() {
+            let entry_pointer = if index == top { pointer } else { &inactive };
+            let (entry_events, result) = entry.dialog.draw(context, entry_pointer, viewport);
+            events.extend(entry_events);
+            if let Some(result) = result {
+                finished = Some((index, result));
+            }
+        }
+        if let Some((index, result)) = finished {
+            self.finish(index, result);
+        }
+        events
+    }
+}
@@ -0,0 +1,374 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
+use crate::core::validation::{first_error, Validator};
+use crate::ui::tree::{FieldValue, FormValues, LayoutProps, SizeSpec, UiAction, UiEvent, UiTree, Widget};
+use crate::widgets::form_field::FormField;
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum FieldKind {
+    Text,
+    Number,
+    Bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ValidateOn {
+    Change,
+    Blur,
+    Submit,
+}
+
+pub trait AsyncValidator {
+    fn start(&mut self, value: &str);
+    fn poll(&mut self) -> Option<(String, Option<String>)>;
+}
+
+pub struct FieldSpec {
+    pub name: &'static str,
+    pub value_key: &'static str,
+    pub kind: FieldKind,
+    pub initial: String,
+    pub validate_on: ValidateOn,
+    pub validators: Vec<Validator>,
+    pub async_validator: Option<Box<dyn AsyncValidator>>,
+}
+
+struct FieldState {
+    spec: FieldSpec,
+    value: String,
+    dirty: bool,
+    touched: bool,
+    error: Option<String>,
+    widget_error: Option<String>,
+    pending: Option<String>,
+}
+
+impl FieldState {
+    fn visible_error(&self) -> Option<String> {
+        self.widget_error.clone().or_else(|| self.error.clone())
+    }
+
+    fn typed_value(&self) -> FieldValue {
+        match self.spec.kind {
+            FieldKind::Text => FieldValue::Text(self.value.clone()),
+            FieldKind::Number => FieldValue::Number(self.value.trim().parse::<f64>().unwrap_or(0.0)),
+            FieldKind::Bool => FieldValue::Bool(self.value == "true"),
+        }
+    }
+
+    fn validate(&mut self) {
+        self.pending = None;
+        self.error = if self.spec.kind == FieldKind::Number
+            && !self.value.trim().is_empty()
+            && self.value.trim().parse::<f64>().is_err()
+        {
+            Some("Enter a number.".to_string())
+        } else {
+            first_error(&self.spec.validators, &self.value)
+        };
+        if self.error.is_none()
+            && let Some(validator) = self.spec.async_validator.as_mut()
+        {
+            validator.start(&self.value);
+            self.pending = Some(self.value.clone());
+        }
+    }
+}
+
+pub struct Form {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub body: UiTree,
+    pub focused: bool,
+    fields: Vec<FieldState>,
+    focused_field: Option<&'static str>,
+    submitting: bool,
+}
+
+impl Form {
+    pub fn new(key: &'static str, gap: f64) -> Self {
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+        Self {
+            key,
+            rect,
+            body: UiTree::column(rect, gap),
+            focused: false,
+            fields: Vec::new(),
+            focused_field: None,
+            submitting: false,
+        }
+    }
+
+    pub fn register(&mut self, spec: FieldSpec, field: FormField, height: f64) {
+        let order = self.fields.len() as i32;
+        self.body.push_key_with_order(
+            spec.name,
+            Box::new(field),
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(height),
+                align_self: None,
+            },
+            order,
+        );
+        self.fields.push(FieldState {
+            value: spec.initial.clone(),
+            spec,
+            dirty: false,
+            touched: false,
+            error: None,
+            widget_error: None,
+            pending: None,
+        });
+    }
+
+    pub fn push_widget(&mut self, key: &'static str, widget: Box<dyn Widget>, height: f64) {
+        let order = self.fields.len() as i32 + 1000;
+        self.body.push_key_with_order(
+            key,
+            widget,
+            LayoutProps {
+                width: SizeSpec::Flex(1.0),
+                height: SizeSpec::Fixed(height),
+                align_self: None,
+            },
+            order,
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(|field| field.dirty)
+    }
+
+    #[allow(dead_code)]
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.spec.name == name && field.touched)
+    }
+
+    pub fn values(&self) -> FormValues {
+        self.fields
+            .iter()
+            .map(|field| (field.spec.name, field.typed_value()))
+            .collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn reset(&mut self) {
+        for field in &mut self.fields {
+            field.value = field.spec.initial.clone();
+            field.dirty = false;
+            field.touched = false;
+            field.error = None;
+            field.pending = None;
+        }
+        self.submitting = false;
+    }
+
+    pub fn submit(&mut self) {
+        for field in &mut self.fields {
+            field.touched = true;
+            field.validate();
+        }
+        self.submitting = true;
+    }
+
+    fn handle_body_event(&mut self, event: &UiEvent) {
+        match event {
+            UiEvent::ValueChanged { key, value } => {
+                if let Some(field) = self.fields.iter_mut().find(|field| field.spec.value_key == *key) {
+                    field.value = value.clone();
+                    field.dirty = field.value != field.spec.initial;
+                    if field.spec.validate_on == ValidateOn::Change {
+                        field.validate();
+                    }
+                }
+            }
+            UiEvent::Validation { key, error } => {
+                if let Some(field) = self.fields.iter_mut().find(|field| field.spec.value_key == *key) {
+                    field.widget_error = error.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn track_blur(&mut self) {
+        let focused = if self.focused {
+            self.body.focused_key()
+        } else {
+            None
+        };
+        if focused == self.focused_field {
+            return;
+        }
+        if let Some(previous) = self.focused_field
+            && let Some(field) = self.fields.iter_mut().find(|field| field.spec.name == previous)
+        {
+            field.touched = true;
+            if field.spec.validate_on != ValidateOn::Submit {
+                field.validate();
+            }
+        }
+        self.focused_field = focused;
+    }
+
+    fn poll_async(&mut self) {
+        for field in &mut self.fields {
+            if field.pending.is_none() {
+                continue;
+            }
+            let Some((checked, result)) = field.spec.async_validator.as_mut().and_then(|validator| validator.poll()) else {
+                continue;
+            };
+            if field.pending.as_ref() != Some(&checked) {
+                continue;
+            }
+            if checked == field.value {
+                field.error = result;
+                field.pending = None;
+            } else {
+                field.validate();
+            }
+        }
+    }
+
+    fn finish_submit(&mut self) -> Option<UiEvent> {
+        if !self.submitting || self.fields.iter().any(|field| field.pending.is_some()) {
+            return None;
+        }
+        self.submitting = false;
+
+        let invalid = self
+            .fields
+            .iter()
+            .find(|field| field.visible_error().is_some())
+            .map(|field| field.spec.name);
+        if let Some(name) = invalid {
+            self.body.focus_key(name);
+            if let Some(field) = self.body.widget_mut_by_key::<FormField>(name) {
+                field.child_mut().focus_first();
+            }
+            return None;
+        }
+
+        Some(UiEvent::FormSubmitted {
+            key: self.key,
+            values: self.values(),
+        })
+    }
+
+    fn sync_field_errors(&mut self) {
+        for field in &self.fields {
+            let error = if field.touched {
+                field.visible_error()
+            } else {
+                None
+            };
+            if let Some(widget) = self.body.widget_mut_by_key::<FormField>(field.spec.name) {
+                widget.set_validation(error);
+            }
+        }
+    }
+}
+
+impl Widget for Form {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        self.poll_async();
+        self.sync_field_errors();
+        self.body.set_area(self.rect);
+
+        let mut child_pointer = pointer.clone();
+        child_pointer.focus_next = false;
+        child_pointer.focus_prev = false;
+        if !self.focused {
+            child_pointer.suppress_focus_and_text_input();
+        }
+
+        let mut events = Vec::new();
+        for event in self.body.draw(context, &child_pointer) {
+            self.handle_body_event(&event);
+            if let UiEvent::Action(UiAction::SubmitForm) = event {
+                self.submit();
+            } else {
+                events.push(event);
+            }
+        }
+        self.track_blur();
+        self.poll_async();
+        if let Some(event) = self.finish_submit() {
+            events.push(event);
+        }
+        self.sync_field_errors();
+        if self.fields.iter().any(|field| field.pending.is_some()) {
+            request_frame();
+        }
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn focus_next_in_children(&mut self) -> bool {
+        if self.body.focus_next_in_focused_child() {
+            return true;
+        }
+        if !self.body.focus_next_bounded() {
+            return false;
+        }
+        self.body.focus_next_in_focused_child();
+        true
+    }
+
+    fn focus_prev_in_children(&mut self) -> bool {
+        if self.body.focus_prev_in_focused_child() {
+            return true;
+        }
+        if !self.body.focus_prev_bounded() {
+            return false;
+        }
+        self.body.focus_prev_in_focused_child();
+        true
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        self.body.focus_rect()
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.body.scrollable_at(x, y)
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.body.handles_shortcut(shortcut)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
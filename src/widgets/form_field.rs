@@ -1,5 +1,7 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiEvent, UiTree, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -16,8 +18,8 @@ pub struct FormField {
 }
 
 pub struct FormFieldStyle {
-    pub label_font: &'static str,
-    pub helper_font: &'static str,
+    pub label_font: FontDescriptor,
+    pub helper_font: FontDescriptor,
     pub label_color: &'static str,
     pub helper_color: &'static str,
     pub error_color: &'static str,
@@ -33,8 +35,8 @@ pub struct FormFieldStyle {
 impl Default for FormFieldStyle {
     fn default() -> Self {
         Self {
-            label_font: "600 14px Consolas",
-            helper_font: "13px Consolas",
+            label_font: FontDescriptor::new("Consolas", 14.0).weight(600),
+            helper_font: FontDescriptor::new("Consolas", 13.0),
             label_color: "#d8e3ff",
             helper_color: "#91a4d6",
             error_color: "#ff7a7a",
@@ -81,7 +83,7 @@ impl Widget for FormField {
         let top = self.rect.y;
         let label_y = top + self.style.label_height * 0.5;
 
-        context.set_font(self.style.label_font);
+        context.set_font(&self.style.label_font.css());
         context.set_fill_style_str(self.style.label_color);
         context.set_text_align("left");
         context.set_text_baseline("middle");
@@ -110,12 +112,19 @@ impl Widget for FormField {
         self.child.set_area(child_area);
 
         let mut child_pointer = pointer.clone();
+        child_pointer.focus_next = false;
+        child_pointer.focus_prev = false;
         if !self.focused {
             child_pointer.suppress_focus_and_text_input();
         }
         let events = self.child.draw(context, &child_pointer);
+        for event in &events {
+            if let UiEvent::Validation { error, .. } = event {
+                self.set_validation(error.clone());
+            }
+        }
 
-        context.set_font(self.style.helper_font);
+        context.set_font(&self.style.helper_font.css());
         context.set_fill_style_str(if self.has_error {
             self.style.error_color
         } else {
@@ -146,11 +155,23 @@ impl Widget for FormField {
     }
 
     fn focus_next_in_children(&mut self) -> bool {
-        self.child.focus_next()
+        self.child.focus_next_in_focused_child() || self.child.focus_next_bounded()
     }
 
     fn focus_prev_in_children(&mut self) -> bool {
-        self.child.focus_prev()
+        self.child.focus_prev_in_focused_child() || self.child.focus_prev_bounded()
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        self.child.focus_rect().or(Some(self.rect))
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.child.scrollable_at(x, y)
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.child.handles_shortcut(shortcut)
     }
 
     fn as_any_mut(&mut self) -> &mut dyn Any {
@@ -1,5 +1,10 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::{
+    draw_layout, layout_text, TextAlign, TextLayoutOptions, TextMeasure, VerticalAlign,
+};
+use crate::text::rich::{draw_rich, layout_rich, span_font, RichTextTheme, TextSpan};
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -8,22 +13,74 @@ pub struct Label {
     pub rect: Rect,
     pub text: String,
     pub style: LabelStyle,
+    pub spans: Vec<TextSpan>,
+    pub measured: Option<(f64, f64)>,
 }
 
 pub struct LabelStyle {
-    pub font: &'static str,
+    pub font: FontDescriptor,
     pub color: &'static str,
+    pub link_color: &'static str,
+    pub code_background: &'static str,
+    pub align: TextAlign,
+    pub vertical_align: VerticalAlign,
+    pub wrap: bool,
+    pub max_lines: Option<usize>,
+    pub line_height: f64,
+}
+
+impl Default for LabelStyle {
+    fn default() -> Self {
+        Self {
+            font: FontDescriptor::new("Consolas", 14.0),
+            color: "#d8e3ff",
+            link_color: "#27ffd8",
+            code_background: "#1c2742",
+            align: TextAlign::Center,
+            vertical_align: VerticalAlign::Middle,
+            wrap: false,
+            max_lines: Some(1),
+            line_height: 1.3,
+        }
+    }
 }
 
 impl Label {
     pub fn set_text(&mut self, text: String) {
         self.text = text;
+        self.spans.clear();
+    }
+
+    #[allow(dead_code)]
+    pub fn set_spans(&mut self, spans: Vec<TextSpan>) {
+        self.text = spans.iter().map(|span| span.text.as_str()).collect();
+        self.spans = spans;
+    }
+
+    fn theme(&self) -> RichTextTheme {
+        RichTextTheme {
+            font: self.style.font,
+            code_font: self.style.font,
+            color: self.style.color,
+            link_color: self.style.link_color,
+            code_background: self.style.code_background,
+        }
+    }
+
+    fn layout_options(&self, max_width: f64) -> TextLayoutOptions {
+        TextLayoutOptions {
+            max_width: Some(max_width),
+            wrap: self.style.wrap,
+            max_lines: self.style.max_lines,
+            line_height: self.style.line_height,
+            ellipsis: true,
+        }
     }
 }
 
 impl Widget for Label {
     fn desired_size(&self) -> (f64, f64) {
-        (self.rect.width, self.rect.height)
+        self.measured.unwrap_or((self.rect.width, self.rect.height))
     }
 
     fn set_rect(&mut self, rect: Rect) {
@@ -35,14 +92,55 @@ impl Widget for Label {
         context: &CanvasRenderingContext2d,
         _pointer: &PointerState,
     ) -> Vec<UiEvent> {
-        context.set_font(self.style.font);
-        context.set_fill_style_str(self.style.color);
-        context.set_text_align("center");
-        context.set_text_baseline("middle");
-        let _ = context.fill_text(
+        if !self.spans.is_empty() {
+            let theme = self.theme();
+            let layout = layout_rich(
+                context,
+                &self.spans,
+                &theme,
+                self.layout_options(self.rect.width),
+            );
+            let natural_width = self
+                .spans
+                .iter()
+                .map(|span| context.text_width(&span_font(&theme, &span.style), &span.text))
+                .sum::<f64>();
+            self.measured = Some((natural_width, layout.height));
+            draw_rich(
+                context,
+                &self.spans,
+                &layout,
+                &theme,
+                self.rect,
+                self.style.align,
+                self.style.vertical_align,
+                None,
+            );
+            return Vec::new();
+        }
+
+        let font = self.style.font.css();
+        let layout = layout_text(
+            context,
+            &font,
             &self.text,
-            self.rect.x + self.rect.width / 2.0,
-            self.rect.y + self.rect.height / 2.0,
+            self.layout_options(self.rect.width),
+        );
+        let natural_width = self
+            .text
+            .split('\n')
+            .map(|paragraph| context.text_width(&font, paragraph))
+            .fold(0.0, f64::max);
+        self.measured = Some((natural_width, layout.height));
+
+        context.set_font(&font);
+        context.set_fill_style_str(self.style.color);
+        draw_layout(
+            context,
+            &layout,
+            self.rect,
+            self.style.align,
+            self.style.vertical_align,
         );
         Vec::new()
     }
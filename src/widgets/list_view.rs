@@ -1,18 +1,256 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
-use crate::core::navigation::{find_next_contains, step_clamped};
+use crate::core::navigation::{TypeAhead, find_next_contains, fuzzy_score, step_clamped};
+use crate::core::selection::SelectionModel;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::draw_highlighted;
+use crate::text::measure::cached_width;
 use crate::ui::tree::{UiEvent, Widget};
+use crate::widgets::tooltip::{TOOLTIP_DELAY_MS, TooltipStyle, TooltipTrigger, show_tooltip};
 use std::any::Any;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
 use web_sys::CanvasRenderingContext2d;
 
+const PREFETCH_ROWS: usize = 8;
+
+pub trait ListDataSource {
+    fn item_count(&self) -> usize;
+    fn item_text(&self, index: usize) -> String;
+
+    fn build_row(&mut self, _index: usize, _recycled: Option<Box<dyn Widget>>) -> Option<Box<dyn Widget>> {
+        None
+    }
+
+    fn row_event(&mut self, _index: usize, _event: &UiEvent) -> RowChange {
+        RowChange::None
+    }
+
+    fn page_state(&self) -> PageState {
+        PageState::Complete
+    }
+
+    fn request_more(&mut self) -> bool {
+        false
+    }
+
+    fn placeholder_rows(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum RowChange {
+    None,
+    Updated,
+    Inserted,
+    Removed,
+}
+
+#[derive(Clone, PartialEq, Default)]
+pub enum PageState {
+    #[default]
+    Idle,
+    Loading,
+    Failed(String),
+    Complete,
+}
+
+#[derive(Default)]
+pub struct PageBuffer {
+    pub items: Vec<String>,
+    pub state: PageState,
+}
+
+impl PageBuffer {
+    pub fn append_page(&mut self, items: Vec<String>, has_more: bool) {
+        self.items.extend(items);
+        self.state = if has_more {
+            PageState::Idle
+        } else {
+            PageState::Complete
+        };
+    }
+
+    pub fn fail(&mut self, message: impl Into<String>) {
+        self.state = PageState::Failed(message.into());
+    }
+
+    pub fn reset(&mut self) {
+        self.items.clear();
+        self.state = PageState::Idle;
+    }
+}
+
+pub struct PagedListSource {
+    pub buffer: Rc<RefCell<PageBuffer>>,
+    pub page_size: usize,
+}
+
+impl ListDataSource for PagedListSource {
+    fn item_count(&self) -> usize {
+        self.buffer.borrow().items.len()
+    }
+
+    fn item_text(&self, index: usize) -> String {
+        self.buffer
+            .borrow()
+            .items
+            .get(index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn page_state(&self) -> PageState {
+        self.buffer.borrow().state.clone()
+    }
+
+    fn request_more(&mut self) -> bool {
+        let mut buffer = self.buffer.borrow_mut();
+        match buffer.state {
+            PageState::Idle | PageState::Failed(_) => {
+                buffer.state = PageState::Loading;
+                true
+            }
+            PageState::Loading | PageState::Complete => false,
+        }
+    }
+
+    fn placeholder_rows(&self) -> usize {
+        if self.buffer.borrow().state == PageState::Loading {
+            self.page_size
+        } else {
+            0
+        }
+    }
+}
+
+pub struct StringListSource {
+    pub items: Vec<String>,
+}
+
+impl ListDataSource for StringListSource {
+    fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    fn item_text(&self, index: usize) -> String {
+        self.items.get(index).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+pub struct RowCache {
+    heights: Vec<f64>,
+    offsets: Vec<f64>,
+    active: Vec<(usize, Box<dyn Widget>)>,
+    recycled: Vec<Box<dyn Widget>>,
+    changes: Vec<(usize, RowChange)>,
+}
+
+impl RowCache {
+    fn sync(&mut self, count: usize, estimated_height: f64) {
+        if self.heights.len() != count {
+            self.heights.resize(count, estimated_height);
+            self.rebuild_offsets();
+        }
+    }
+
+    fn rebuild_offsets(&mut self) {
+        self.offsets.clear();
+        self.offsets.push(0.0);
+        let mut y = 0.0;
+        for height in &self.heights {
+            y += height;
+            self.offsets.push(y);
+        }
+    }
+
+    fn content_height(&self) -> f64 {
+        self.offsets.last().copied().unwrap_or(0.0)
+    }
+
+    fn row_top(&self, index: usize) -> f64 {
+        self.offsets.get(index).copied().unwrap_or(self.content_height())
+    }
+
+    fn row_height(&self, index: usize) -> f64 {
+        self.heights.get(index).copied().unwrap_or(0.0)
+    }
+
+    fn index_at(&self, y: f64) -> usize {
+        self.offsets
+            .partition_point(|offset| *offset <= y)
+            .saturating_sub(1)
+            .min(self.heights.len().saturating_sub(1))
+    }
+
+    fn recycle_outside(&mut self, range: std::ops::Range<usize>) {
+        let mut index = 0;
+        while index < self.active.len() {
+            if range.contains(&self.active[index].0) {
+                index += 1;
+            } else {
+                let (_, widget) = self.active.swap_remove(index);
+                self.recycled.push(widget);
+            }
+        }
+    }
+
+    fn recycle_all(&mut self) {
+        self.recycled
+            .extend(self.active.drain(..).map(|(_, widget)| widget));
+    }
+
+    fn apply_change(&mut self, index: usize, change: RowChange, estimated_height: f64) {
+        if matches!(change, RowChange::Updated | RowChange::Removed)
+            && let Some(widget) = self.take_row(index)
+        {
+            self.recycled.push(widget);
+        }
+        match change {
+            RowChange::None | RowChange::Updated => return,
+            RowChange::Inserted => {
+                self.heights.insert(index.min(self.heights.len()), estimated_height);
+                for (row, _) in &mut self.active {
+                    if *row >= index {
+                        *row += 1;
+                    }
+                }
+            }
+            RowChange::Removed => {
+                if index < self.heights.len() {
+                    self.heights.remove(index);
+                }
+                for (row, _) in &mut self.active {
+                    if *row > index {
+                        *row -= 1;
+                    }
+                }
+            }
+        }
+        self.rebuild_offsets();
+    }
+
+    fn take_row(&mut self, index: usize) -> Option<Box<dyn Widget>> {
+        let position = self.active.iter().position(|(row, _)| *row == index)?;
+        Some(self.active.swap_remove(position).1)
+    }
+}
+
 pub struct ListView {
     pub key: &'static str,
     pub rect: Rect,
-    pub items: Vec<String>,
+    pub source: Box<dyn ListDataSource>,
+    pub rows: RowCache,
     pub row_height: f64,
     pub scroll_offset: f64,
-    pub selected: Option<usize>,
-    pub selection_anchor: Option<usize>,
+    pub selection: SelectionModel,
+    pub highlight_query: String,
+    pub type_ahead: TypeAhead,
+    pub tooltip: TooltipTrigger,
     pub style: ListViewStyle,
     pub focused: bool,
 }
@@ -24,7 +262,11 @@ pub struct ListViewStyle {
     pub row_even: &'static str,
     pub row_odd: &'static str,
     pub text: &'static str,
-    pub font: &'static str,
+    pub match_text: &'static str,
+    pub placeholder_fill: &'static str,
+    pub error_text: &'static str,
+    pub font: FontDescriptor,
+    pub tooltip: TooltipStyle,
 }
 
 impl Default for ListViewStyle {
@@ -36,49 +278,137 @@ impl Default for ListViewStyle {
             row_even: "#141d34",
             row_odd: "#10182d",
             text: "#cfe0ff",
-            font: "14px Consolas",
+            match_text: "#27ffd8",
+            placeholder_fill: "#1c2742",
+            error_text: "#ff6b6b",
+            font: FontDescriptor::new("Consolas", 14.0),
+            tooltip: TooltipStyle::default(),
         }
     }
 }
 
 impl ListView {
-    pub fn set_items(&mut self, items: Vec<String>) {
-        self.items = items;
-        if let Some(selected) = self.selected {
-            if selected >= self.items.len() {
-                self.selected = self.items.len().checked_sub(1);
+    pub fn set_source(&mut self, source: Box<dyn ListDataSource>) {
+        self.source = source;
+        self.source_changed();
+    }
+
+    fn source_changed(&mut self) {
+        self.invalidate_rows();
+        let count = self.item_count();
+        self.selection.truncate(count);
+        self.tooltip.reset();
+    }
+
+    fn apply_row_changes(&mut self) -> Vec<UiEvent> {
+        if self.rows.changes.is_empty() {
+            return Vec::new();
+        }
+        let lead_before = self.selection.lead();
+        let selected_before = self.selection.indices();
+        for (index, change) in std::mem::take(&mut self.rows.changes) {
+            self.rows.apply_change(index, change, self.row_height);
+            match change {
+                RowChange::Inserted => self.selection.remap(|row| Some(row + usize::from(row >= index))),
+                RowChange::Removed => self.selection.remap(|row| match row.cmp(&index) {
+                    Ordering::Less => Some(row),
+                    Ordering::Equal => None,
+                    Ordering::Greater => Some(row - 1),
+                }),
+                RowChange::None | RowChange::Updated => {}
             }
         }
+        let count = self.item_count();
+        self.rows.sync(count, self.row_height);
+        self.selection.truncate(count);
+        self.tooltip.reset();
+
+        let mut events = Vec::new();
+        if self.selection.lead() != lead_before {
+            events.extend(self.emit_selection());
+        }
+        if self.selection.indices() != selected_before {
+            events.push(UiEvent::SelectionChanged {
+                key: self.key,
+                indices: self.selection.indices(),
+            });
+        }
+        events
+    }
+
+    pub fn invalidate_rows(&mut self) {
+        self.rows.changes.clear();
+        self.rows.recycle_all();
+        self.rows.heights.clear();
+        self.rows.offsets.clear();
+    }
+
+    fn item_count(&self) -> usize {
+        self.source.item_count()
+    }
+
+    fn item_texts(&self) -> Vec<String> {
+        (0..self.item_count())
+            .map(|index| self.source.item_text(index))
+            .collect()
     }
 
     pub fn set_selected_by_value(&mut self, value: &str) {
-        self.selected = self.items.iter().position(|item| item == value);
-        self.selection_anchor = self.selected;
+        if self.selection.lead().is_some() && self.selected_value() == value {
+            return;
+        }
+        match (0..self.item_count()).find(|index| self.source.item_text(*index) == value) {
+            Some(index) => self.selection.select_only(index),
+            None => self.selection.clear(),
+        };
     }
 
     fn selected_value(&self) -> String {
-        self.selected
-            .and_then(|index| self.items.get(index))
-            .cloned()
+        self.selection
+            .lead()
+            .filter(|index| *index < self.item_count())
+            .map(|index| self.source.item_text(index))
             .unwrap_or_default()
     }
 
     fn emit_selection(&self) -> Option<UiEvent> {
-        self.selected.map(|_| UiEvent::ValueChanged {
+        self.selection.lead().map(|_| UiEvent::ValueChanged {
             key: self.key,
             value: self.selected_value(),
         })
     }
 
+    fn footer_rows(&self, page_state: &PageState) -> usize {
+        match page_state {
+            PageState::Loading => self.source.placeholder_rows().max(1),
+            PageState::Failed(_) => 1,
+            PageState::Idle | PageState::Complete => 0,
+        }
+    }
+
+    fn footer_height(&self, page_state: &PageState) -> f64 {
+        self.footer_rows(page_state) as f64 * self.row_height
+    }
+
     fn max_scroll(&self) -> f64 {
-        let content_height = self.items.len() as f64 * self.row_height;
-        (content_height - self.rect.height).max(0.0)
+        let footer = self.footer_height(&self.source.page_state());
+        (self.rows.content_height() + footer - self.rect.height).max(0.0)
+    }
+
+    fn request_more(&mut self) -> Option<UiEvent> {
+        if !self.source.request_more() {
+            return None;
+        }
+        Some(UiEvent::LoadMore {
+            key: self.key,
+            offset: self.item_count(),
+        })
     }
 
     fn ensure_selected_visible(&mut self) {
-        if let Some(index) = self.selected {
-            let top = index as f64 * self.row_height;
-            let bottom = top + self.row_height;
+        if let Some(index) = self.selection.lead() {
+            let top = self.rows.row_top(index);
+            let bottom = top + self.rows.row_height(index);
             if top < self.scroll_offset {
                 self.scroll_offset = top;
             } else if bottom > self.scroll_offset + self.rect.height {
@@ -88,82 +418,75 @@ impl ListView {
         }
     }
 
-    fn select_by_index(&mut self, index: usize) -> Option<UiEvent> {
-        if self.items.is_empty() {
-            self.selected = None;
-            self.selection_anchor = None;
-            return None;
-        }
-        let next = index.min(self.items.len() - 1);
-        if self.selected != Some(next) {
-            self.selected = Some(next);
-            self.selection_anchor = Some(next);
-            self.ensure_selected_visible();
-            return self.emit_selection();
-        }
+    fn update_selection(&mut self, update: impl FnOnce(&mut SelectionModel) -> bool) -> Vec<UiEvent> {
+        let lead_before = self.selection.lead();
+        let changed = update(&mut self.selection);
         self.ensure_selected_visible();
-        None
-    }
 
-    fn step_selection(&mut self, delta: isize) -> Option<UiEvent> {
-        if self.items.is_empty() {
-            self.selected = None;
-            return None;
+        let mut events = Vec::new();
+        if self.selection.lead() != lead_before {
+            events.extend(self.emit_selection());
+        }
+        if changed {
+            events.push(UiEvent::SelectionChanged {
+                key: self.key,
+                indices: self.selection.indices(),
+            });
         }
+        events
+    }
 
-        if let Some(next) = step_clamped(self.selected, delta, self.items.len()) {
-            return self.select_by_index(next);
+    fn select_by_index(&mut self, index: usize) -> Vec<UiEvent> {
+        let count = self.item_count();
+        if count == 0 {
+            return self.update_selection(|selection| selection.clear());
         }
+        self.update_selection(|selection| selection.select_only(index.min(count - 1)))
+    }
 
-        None
+    fn step_target(&self, delta: isize) -> Option<usize> {
+        step_clamped(self.selection.lead(), delta, self.item_count())
     }
 
     fn page_delta(&self) -> isize {
-        (self.rect.height / self.row_height).floor().max(1.0) as isize
+        let Some(selected) = self.selection.lead() else {
+            return (self.rect.height / self.row_height.max(1.0)).floor().max(1.0) as isize;
+        };
+        let top = self.rows.row_top(selected);
+        let below = self.rows.index_at(top + self.rect.height);
+        (below as isize - selected as isize).max(1)
     }
 
-    fn extend_selection(&mut self, delta: isize) -> Option<UiEvent> {
-        if self.items.is_empty() {
-            return None;
-        }
-        let Some(next) = step_clamped(self.selected, delta, self.items.len()) else {
-            return None;
-        };
-        if self.selection_anchor.is_none() {
-            self.selection_anchor = Some(self.selected.unwrap_or(next));
-        }
-        if self.selected != Some(next) {
-            self.selected = Some(next);
-            self.ensure_selected_visible();
-            return self.emit_selection();
+    fn extend_selection(&mut self, delta: isize) -> Vec<UiEvent> {
+        match self.step_target(delta) {
+            Some(next) => self.update_selection(|selection| selection.extend_to(next, false)),
+            None => Vec::new(),
         }
-        self.ensure_selected_visible();
-        None
     }
 
-    fn selection_range(&self) -> Option<(usize, usize)> {
-        match (self.selection_anchor, self.selected) {
-            (Some(a), Some(b)) => {
-                if a <= b {
-                    Some((a, b))
-                } else {
-                    Some((b, a))
-                }
-            }
-            _ => None,
+    fn route_row_event(&mut self, index: usize, event: UiEvent) -> UiEvent {
+        let change = self.source.row_event(index, &event);
+        if change != RowChange::None {
+            self.rows.changes.push((index, change));
+        }
+        UiEvent::RowEvent {
+            key: self.key,
+            index,
+            event: Box::new(event),
         }
     }
 
-    fn jump_to_match(&mut self, needle: &str) -> Option<UiEvent> {
-        if needle.is_empty() || self.items.is_empty() {
-            return None;
+    fn jump_to_match(&mut self, needle: &str, time_ms: f64) -> Vec<UiEvent> {
+        if needle.is_empty() || self.item_count() == 0 {
+            return Vec::new();
         }
 
-        if let Some(index) = find_next_contains(&self.items, needle, self.selected) {
-            return self.select_by_index(index);
+        let items = self.item_texts();
+        let lead = self.selection.lead();
+        match self.type_ahead.search(&items, needle, time_ms, lead, find_next_contains) {
+            Some(index) => self.select_by_index(index),
+            None => Vec::new(),
         }
-
-        None
     }
 }
 
@@ -177,66 +500,58 @@ impl Widget for ListView {
     }
 
     fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
-        let mut events = Vec::new();
+        let mut events = self.apply_row_changes();
         let hovered = self.rect.contains(pointer.x, pointer.y);
         if hovered && pointer.scroll_y.abs() > 0.0 {
             self.scroll_offset += pointer.scroll_y * 0.75;
         }
 
-        if pointer.just_released && hovered && self.row_height > 0.0 {
+        let count = self.item_count();
+        self.rows.sync(count, self.row_height);
+
+        if pointer.just_released && hovered && count > 0 {
             let y_in_list = (pointer.y - self.rect.y + self.scroll_offset).max(0.0);
-            let index = (y_in_list / self.row_height).floor() as usize;
-            if index < self.items.len() {
-                if let Some(event) = self.select_by_index(index) {
-                    events.push(event);
-                }
+            let index = self.rows.index_at(y_in_list);
+            if y_in_list < self.rows.content_height() {
+                let modifiers = pointer.modifiers;
+                events.extend(self.update_selection(|selection| selection.click(index, modifiers)));
             }
         }
+        if pointer.just_released
+            && hovered
+            && matches!(self.source.page_state(), PageState::Failed(_))
+            && pointer.y - self.rect.y + self.scroll_offset >= self.rows.content_height()
+        {
+            events.extend(self.request_more());
+        }
 
         if self.focused {
-            if pointer.move_up {
-                self.selection_anchor = self.selected;
-                if let Some(event) = self.step_selection(-1) {
-                    events.push(event);
-                }
+            let target = if pointer.move_up {
+                self.step_target(-1)
             } else if pointer.move_down {
-                self.selection_anchor = self.selected;
-                if let Some(event) = self.step_selection(1) {
-                    events.push(event);
-                }
-            } else if pointer.move_up_select {
-                if let Some(event) = self.extend_selection(-1) {
-                    events.push(event);
-                }
-            } else if pointer.move_down_select {
-                if let Some(event) = self.extend_selection(1) {
-                    events.push(event);
-                }
+                self.step_target(1)
             } else if pointer.move_page_up {
-                self.selection_anchor = self.selected;
-                if let Some(event) = self.step_selection(-self.page_delta()) {
-                    events.push(event);
-                }
+                self.step_target(-self.page_delta())
             } else if pointer.move_page_down {
-                self.selection_anchor = self.selected;
-                if let Some(event) = self.step_selection(self.page_delta()) {
-                    events.push(event);
-                }
+                self.step_target(self.page_delta())
             } else if pointer.move_home {
-                self.selection_anchor = self.selected;
-                if let Some(event) = self.select_by_index(0) {
-                    events.push(event);
-                }
-            } else if pointer.move_end && !self.items.is_empty() {
-                self.selection_anchor = self.selected;
-                if let Some(event) = self.select_by_index(self.items.len() - 1) {
-                    events.push(event);
-                }
+                Some(0)
+            } else if pointer.move_end {
+                count.checked_sub(1)
+            } else {
+                None
+            };
+            if let Some(index) = target {
+                events.extend(self.select_by_index(index));
+            } else if pointer.move_up_select {
+                events.extend(self.extend_selection(-1));
+            } else if pointer.move_down_select {
+                events.extend(self.extend_selection(1));
+            } else if pointer.select_all {
+                events.extend(self.update_selection(|selection| selection.select_all(count)));
             }
             if let Some(input) = &pointer.text_input {
-                if let Some(event) = self.jump_to_match(input) {
-                    events.push(event);
-                }
+                events.extend(self.jump_to_match(input, pointer.time_ms));
             }
         }
 
@@ -257,22 +572,29 @@ impl Widget for ListView {
         context.rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
         context.clip();
 
-        context.set_font(self.style.font);
-        context.set_fill_style_str(self.style.text);
-        context.set_text_align("left");
-        context.set_text_baseline("middle");
-
-        let start_index = (self.scroll_offset / self.row_height).floor().max(0.0) as usize;
-        let visible_rows = (self.rect.height / self.row_height).ceil().max(0.0) as usize + 1;
-        let end_index = (start_index + visible_rows).min(self.items.len());
-        let selected_range = self.selection_range();
+        let font = self.style.font.css();
+        let start_index = self.rows.index_at(self.scroll_offset);
+        let end_index = if count == 0 {
+            0
+        } else {
+            (self.rows.index_at(self.scroll_offset + self.rect.height) + 1).min(count)
+        };
+        self.rows.recycle_outside(start_index..end_index);
+
+        let mut row_pointer = pointer.clone();
+        row_pointer.suppress_focus_and_text_input();
+        row_pointer.scroll_y = 0.0;
+        if !hovered {
+            row_pointer.x = f64::NEG_INFINITY;
+            row_pointer.y = f64::NEG_INFINITY;
+        }
 
+        let mut truncated_rows = Vec::new();
+        let mut heights_changed = false;
         for index in start_index..end_index {
-            let y = self.rect.y + (index as f64 * self.row_height - self.scroll_offset);
-            let in_range = selected_range
-                .map(|(start, end)| index >= start && index <= end)
-                .unwrap_or(false);
-            let row_color = if in_range {
+            let y = self.rect.y + self.rows.row_top(index) - self.scroll_offset;
+            let height = self.rows.row_height(index);
+            let row_color = if self.selection.is_selected(index) {
                 "#274060"
             } else if index % 2 == 0 {
                 self.style.row_even
@@ -280,10 +602,108 @@ impl Widget for ListView {
                 self.style.row_odd
             };
             context.set_fill_style_str(row_color);
-            context.fill_rect(self.rect.x, y, self.rect.width, self.row_height);
+            context.fill_rect(self.rect.x, y, self.rect.width, height);
+
+            let row = match self.rows.take_row(index) {
+                Some(row) => Some(row),
+                None => {
+                    let recycled = self.rows.recycled.pop();
+                    self.source.build_row(index, recycled)
+                }
+            };
+            let Some(mut row) = row else {
+                let text = self.source.item_text(index);
+                let ranges = fuzzy_score(&text, &self.highlight_query)
+                    .map(|(_, ranges)| ranges)
+                    .unwrap_or_default();
+                let text_rect = Rect {
+                    x: self.rect.x + 10.0,
+                    y,
+                    width: self.rect.width - 20.0,
+                    height,
+                };
+                draw_highlighted(context, &font, &text, &ranges, text_rect, (self.style.text, self.style.match_text));
+                if cached_width(context, &font, &text) > text_rect.width {
+                    truncated_rows.push((index, text_rect, text));
+                }
+                continue;
+            };
+
+            row.set_rect(Rect {
+                x: self.rect.x,
+                y,
+                width: self.rect.width,
+                height,
+            });
+            for event in row.draw(context, &row_pointer) {
+                let event = self.route_row_event(index, event);
+                events.push(event);
+            }
+            let measured = row.desired_size().1;
+            if measured > 0.0 && (measured - height).abs() > 0.5 {
+                self.rows.heights[index] = measured;
+                heights_changed = true;
+            }
+            self.rows.active.push((index, row));
+        }
+        if heights_changed {
+            self.rows.rebuild_offsets();
+        }
 
-            context.set_fill_style_str(self.style.text);
-            let _ = context.fill_text(&self.items[index], self.rect.x + 10.0, y + self.row_height * 0.5);
+        let hovered_row = truncated_rows
+            .iter()
+            .find(|(_, rect, _)| hovered && pointer.y >= rect.y && pointer.y < rect.y + rect.height)
+            .map(|(index, _, _)| *index);
+        let lead_row = self.selection.lead().filter(|_| self.focused);
+        if let Some(target) = self.tooltip.update(pointer, hovered_row, lead_row, TOOLTIP_DELAY_MS)
+            && let Some((_, rect, text)) = truncated_rows.iter().find(|(index, _, _)| *index == target)
+        {
+            show_tooltip(context, *rect, text, self.style.tooltip);
+        }
+        events.extend(self.apply_row_changes());
+
+        let page_state = self.source.page_state();
+        let footer_top = self.rect.y + self.rows.content_height() - self.scroll_offset;
+        match &page_state {
+            PageState::Loading => {
+                for slot in 0..self.footer_rows(&page_state) {
+                    let y = footer_top + slot as f64 * self.row_height;
+                    if y >= self.rect.y + self.rect.height {
+                        break;
+                    }
+                    if y + self.row_height <= self.rect.y {
+                        continue;
+                    }
+                    let index = count + slot;
+                    context.set_fill_style_str(if index.is_multiple_of(2) {
+                        self.style.row_even
+                    } else {
+                        self.style.row_odd
+                    });
+                    context.fill_rect(self.rect.x, y, self.rect.width, self.row_height);
+                    let bar_width = (self.rect.width - 20.0).max(0.0) * (0.35 + 0.2 * (index % 3) as f64);
+                    let bar_height = self.row_height * 0.4;
+                    context.set_fill_style_str(self.style.placeholder_fill);
+                    context.fill_rect(self.rect.x + 10.0, y + (self.row_height - bar_height) * 0.5, bar_width, bar_height);
+                }
+            }
+            PageState::Failed(message) => {
+                context.set_fill_style_str(self.style.row_odd);
+                context.fill_rect(self.rect.x, footer_top, self.rect.width, self.row_height);
+                context.set_font(&font);
+                context.set_fill_style_str(self.style.error_text);
+                context.set_text_align("left");
+                context.set_text_baseline("middle");
+                let text = format!("{} \u{2014} click to retry", message);
+                let _ = context.fill_text(&text, self.rect.x + 10.0, footer_top + self.row_height * 0.5);
+            }
+            PageState::Idle | PageState::Complete => {}
+        }
+        if page_state == PageState::Idle
+            && end_index + PREFETCH_ROWS >= count
+            && let Some(event) = self.request_more()
+        {
+            events.push(event);
         }
 
         context.restore();
@@ -295,13 +715,29 @@ impl Widget for ListView {
     }
 
     fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.type_ahead.clear();
+        }
         self.focused = focused;
     }
 
     fn activate(&mut self) -> Option<UiEvent> {
+        if let Some(index) = self.selection.lead()
+            && let Some(event) = self.rows.take_row(index).and_then(|mut row| {
+                let event = row.activate();
+                self.rows.active.push((index, row));
+                event
+            })
+        {
+            return Some(self.route_row_event(index, event));
+        }
         self.emit_selection()
     }
 
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.rect.contains(x, y) && self.max_scroll() > 0.0
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
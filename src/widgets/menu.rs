@@ -0,0 +1,842 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::keymap::{Keymap, Shortcut};
+use crate::text::font::FontDescriptor;
+use crate::text::layout::ellipsize;
+use crate::text::measure::cached_width;
+use crate::ui::overlay::{capture_keyboard, place_beside, place_menu, pointer_for, push_overlay};
+use crate::ui::tree::{UiEvent, Widget};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+const MENU_PADDING: f64 = 4.0;
+const LONG_PRESS_MS: f64 = 500.0;
+const LONG_PRESS_SLOP: f64 = 10.0;
+
+#[derive(Clone)]
+pub enum MenuEntryKind {
+    Command,
+    Separator,
+    Submenu(Vec<MenuEntry>),
+}
+
+#[derive(Clone)]
+pub struct MenuEntry {
+    pub kind: MenuEntryKind,
+    pub command: &'static str,
+    pub label: String,
+    pub shortcut: Option<Shortcut>,
+    pub checked: Option<bool>,
+    pub enabled: bool,
+}
+
+impl MenuEntry {
+    pub fn command(command: &'static str, label: impl Into<String>) -> Self {
+        Self {
+            kind: MenuEntryKind::Command,
+            command,
+            label: label.into(),
+            shortcut: None,
+            checked: None,
+            enabled: true,
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            kind: MenuEntryKind::Separator,
+            ..Self::command("", "")
+        }
+    }
+
+    pub fn submenu(label: impl Into<String>, entries: Vec<MenuEntry>) -> Self {
+        Self {
+            kind: MenuEntryKind::Submenu(entries),
+            ..Self::command("", label)
+        }
+    }
+
+    pub fn shortcut(mut self, shortcut: &str) -> Self {
+        self.shortcut = Shortcut::parse(shortcut);
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+
+    fn selectable(&self) -> bool {
+        self.enabled && !matches!(self.kind, MenuEntryKind::Separator)
+    }
+
+    fn height(&self, style: &MenuStyle) -> f64 {
+        match self.kind {
+            MenuEntryKind::Separator => style.separator_height,
+            _ => style.item_height,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct MenuStyle {
+    pub fill: &'static str,
+    pub border: &'static str,
+    pub focus_border: &'static str,
+    pub highlight: &'static str,
+    pub text: &'static str,
+    pub disabled_text: &'static str,
+    pub shortcut_text: &'static str,
+    pub separator: &'static str,
+    pub bar_fill: &'static str,
+    pub font: FontDescriptor,
+    pub item_height: f64,
+    pub separator_height: f64,
+    pub width: f64,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            fill: "#0f172a",
+            border: "#2a3350",
+            focus_border: "#27ffd8",
+            highlight: "#1f3b66",
+            text: "#d8e3ff",
+            disabled_text: "#4b5878",
+            shortcut_text: "#7d8bb0",
+            separator: "#2a3350",
+            bar_fill: "#111827",
+            font: FontDescriptor::new("Consolas", 14.0),
+            item_height: 28.0,
+            separator_height: 9.0,
+            width: 230.0,
+        }
+    }
+}
+
+pub fn bind_menu_shortcuts(keymap: &mut Keymap, entries: &[MenuEntry]) {
+    for entry in entries {
+        match &entry.kind {
+            MenuEntryKind::Submenu(children) => bind_menu_shortcuts(keymap, children),
+            MenuEntryKind::Command => {
+                if let Some(shortcut) = &entry.shortcut {
+                    keymap.bind(shortcut.clone(), entry.command);
+                }
+            }
+            MenuEntryKind::Separator => {}
+        }
+    }
+}
+
+fn find_command_mut<'a>(entries: &'a mut [MenuEntry], command: &str) -> Option<&'a mut MenuEntry> {
+    for entry in entries {
+        if matches!(entry.kind, MenuEntryKind::Command) && entry.command == command {
+            return Some(entry);
+        }
+        if let MenuEntryKind::Submenu(children) = &mut entry.kind
+            && let Some(found) = find_command_mut(children, command)
+        {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn set_checked_in(entries: &mut [MenuEntry], command: &str, checked: bool) {
+    if let Some(entry) = find_command_mut(entries, command) {
+        entry.checked = Some(checked);
+    }
+}
+
+fn trigger(key: &'static str, entry: &mut MenuEntry) -> UiEvent {
+    if let Some(checked) = &mut entry.checked {
+        *checked = !*checked;
+    }
+    UiEvent::MenuCommand {
+        key,
+        command: entry.command,
+        checked: entry.checked,
+    }
+}
+
+fn resolve_shortcut(key: &'static str, entries: &mut [MenuEntry], keymap: &Keymap, pointer: &PointerState) -> Option<UiEvent> {
+    let command = keymap.resolve(pointer.shortcut.as_ref()?)?;
+    let entry = find_command_mut(entries, command)?;
+    entry.enabled.then(|| trigger(key, entry))
+}
+
+fn entries_at<'a>(root: &'a [MenuEntry], path: &[usize]) -> &'a [MenuEntry] {
+    let mut entries = root;
+    for index in path {
+        match entries.get(*index).map(|entry| &entry.kind) {
+            Some(MenuEntryKind::Submenu(children)) => entries = children,
+            _ => return &[],
+        }
+    }
+    entries
+}
+
+fn entry_at_mut<'a>(root: &'a mut [MenuEntry], path: &[usize]) -> Option<&'a mut MenuEntry> {
+    let (last, parents) = path.split_last()?;
+    let mut entries = root;
+    for index in parents {
+        match &mut entries.get_mut(*index)?.kind {
+            MenuEntryKind::Submenu(children) => entries = children,
+            _ => return None,
+        }
+    }
+    entries.get_mut(*last)
+}
+
+struct MenuLevel {
+    path: Vec<usize>,
+    rect: Rect,
+    highlighted: Option<usize>,
+}
+
+pub enum MenuResult {
+    Idle,
+    Activated(UiEvent),
+    Closed,
+    Step(isize),
+}
+
+#[derive(Default)]
+pub struct MenuPopup {
+    levels: Vec<MenuLevel>,
+    last_pointer: (f64, f64),
+}
+
+impl MenuPopup {
+    pub fn is_open(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    pub fn close(&mut self) {
+        self.levels.clear();
+    }
+
+    fn menu_size(entries: &[MenuEntry], style: &MenuStyle) -> (f64, f64) {
+        let height = entries.iter().map(|entry| entry.height(style)).sum::<f64>();
+        (style.width, height + MENU_PADDING * 2.0)
+    }
+
+    fn first_selectable(entries: &[MenuEntry]) -> Option<usize> {
+        entries.iter().position(MenuEntry::selectable)
+    }
+
+    pub fn open(&mut self, root: &[MenuEntry], anchor: Rect, style: &MenuStyle, highlight_first: bool) {
+        let (width, height) = Self::menu_size(root, style);
+        self.levels = vec![MenuLevel {
+            path: Vec::new(),
+            rect: place_menu(anchor, width, height),
+            highlighted: if highlight_first {
+                Self::first_selectable(root)
+            } else {
+                None
+            },
+        }];
+    }
+
+    fn item_rect(rect: Rect, entries: &[MenuEntry], index: usize, style: &MenuStyle) -> Rect {
+        let offset = entries[..index].iter().map(|entry| entry.height(style)).sum::<f64>();
+        Rect {
+            x: rect.x,
+            y: rect.y + MENU_PADDING + offset,
+            width: rect.width,
+            height: entries[index].height(style),
+        }
+    }
+
+    fn open_submenu(&mut self, root: &[MenuEntry], level: usize, style: &MenuStyle, highlight_first: bool) {
+        let parent = &self.levels[level];
+        let Some(index) = parent.highlighted else {
+            return;
+        };
+        let entries = entries_at(root, &parent.path);
+        if !entries[index].enabled || !matches!(entries[index].kind, MenuEntryKind::Submenu(_)) {
+            return;
+        }
+        let mut path = parent.path.clone();
+        path.push(index);
+        let anchor = Self::item_rect(parent.rect, entries, index, style);
+        let children = entries_at(root, &path);
+        let (width, height) = Self::menu_size(children, style);
+        let rect = place_beside(
+            Rect {
+                y: anchor.y - MENU_PADDING,
+                ..anchor
+            },
+            width,
+            height,
+        );
+        let highlighted = if highlight_first {
+            Self::first_selectable(children)
+        } else {
+            None
+        };
+        self.levels.truncate(level + 1);
+        self.levels.push(MenuLevel {
+            path,
+            rect,
+            highlighted,
+        });
+    }
+
+    fn item_at(&self, root: &[MenuEntry], x: f64, y: f64, style: &MenuStyle) -> Option<(usize, Option<usize>)> {
+        let (level, menu) = self
+            .levels
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, level)| level.rect.contains(x, y))?;
+        let entries = entries_at(root, &menu.path);
+        let index = (0..entries.len()).find(|index| Self::item_rect(menu.rect, entries, *index, style).contains(x, y));
+        Some((level, index))
+    }
+
+    fn step_highlight(&mut self, root: &[MenuEntry], delta: isize) {
+        let Some(level) = self.levels.last_mut() else {
+            return;
+        };
+        let entries = entries_at(root, &level.path);
+        let selectable = (0..entries.len())
+            .filter(|index| entries[*index].selectable())
+            .collect::<Vec<_>>();
+        if selectable.is_empty() {
+            return;
+        }
+        let count = selectable.len() as isize;
+        let next = match level
+            .highlighted
+            .and_then(|current| selectable.iter().position(|index| *index == current))
+        {
+            Some(position) => (position as isize + delta).rem_euclid(count),
+            None if delta > 0 => 0,
+            None => count - 1,
+        };
+        level.highlighted = Some(selectable[next as usize]);
+    }
+
+    fn activate(&mut self, key: &'static str, root: &mut [MenuEntry], level: usize, style: &MenuStyle) -> MenuResult {
+        let Some(index) = self.levels[level].highlighted else {
+            return MenuResult::Idle;
+        };
+        let mut path = self.levels[level].path.clone();
+        path.push(index);
+        let Some(entry) = entry_at_mut(root, &path).filter(|entry| entry.enabled) else {
+            return MenuResult::Idle;
+        };
+        match entry.kind {
+            MenuEntryKind::Command => {
+                let event = trigger(key, entry);
+                self.close();
+                MenuResult::Activated(event)
+            }
+            MenuEntryKind::Submenu(_) => {
+                self.open_submenu(root, level, style, true);
+                MenuResult::Idle
+            }
+            MenuEntryKind::Separator => MenuResult::Idle,
+        }
+    }
+
+    pub fn handle(&mut self, key: &'static str, root: &mut [MenuEntry], pointer: &PointerState, style: &MenuStyle) -> MenuResult {
+        if !self.is_open() {
+            return MenuResult::Idle;
+        }
+
+        let moved = self.last_pointer != (pointer.x, pointer.y);
+        self.last_pointer = (pointer.x, pointer.y);
+        let hit = self.item_at(root, pointer.x, pointer.y, style);
+        if let Some((level, index)) = hit
+            && (moved || pointer.just_released)
+        {
+            let entries = entries_at(root, &self.levels[level].path);
+            let index = index.filter(|index| entries[*index].selectable());
+            self.levels[level].highlighted = index;
+            if index.is_some_and(|index| matches!(entries[index].kind, MenuEntryKind::Submenu(_))) {
+                if self.levels.get(level + 1).map(|child| child.path.last()) != Some(index.as_ref()) {
+                    self.open_submenu(root, level, style, false);
+                }
+            } else {
+                self.levels.truncate(level + 1);
+            }
+        }
+
+        if pointer.just_released
+            && let Some((level, Some(_))) = hit
+        {
+            return self.activate(key, root, level, style);
+        }
+        if (pointer.just_pressed || pointer.secondary_click) && hit.is_none() {
+            self.close();
+            return MenuResult::Closed;
+        }
+
+        let deepest = self.levels.len() - 1;
+        if pointer.move_down {
+            self.step_highlight(root, 1);
+        } else if pointer.move_up {
+            self.step_highlight(root, -1);
+        } else if pointer.move_home {
+            self.levels[deepest].highlighted = None;
+            self.step_highlight(root, 1);
+        } else if pointer.move_end {
+            self.levels[deepest].highlighted = None;
+            self.step_highlight(root, -1);
+        } else if pointer.move_right {
+            let level = &self.levels[deepest];
+            let entries = entries_at(root, &level.path);
+            let submenu = level
+                .highlighted
+                .is_some_and(|index| entries[index].enabled && matches!(entries[index].kind, MenuEntryKind::Submenu(_)));
+            if submenu {
+                self.open_submenu(root, deepest, style, true);
+            } else {
+                return MenuResult::Step(1);
+            }
+        } else if pointer.move_left {
+            if deepest > 0 {
+                self.levels.pop();
+            } else {
+                return MenuResult::Step(-1);
+            }
+        } else if pointer.activate_primary {
+            return self.activate(key, root, deepest, style);
+        } else if pointer.cancel {
+            self.levels.pop();
+            if self.levels.is_empty() {
+                return MenuResult::Closed;
+            }
+        }
+        MenuResult::Idle
+    }
+
+    pub fn draw(&self, owner: &'static str, root: &[MenuEntry], keymap: &Keymap, style: MenuStyle) {
+        if !self.is_open() {
+            return;
+        }
+        capture_keyboard(owner);
+        for level in &self.levels {
+            let entries = entries_at(root, &level.path);
+            let rows = entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    let hint = match entry.kind {
+                        MenuEntryKind::Submenu(_) => "\u{25b8}".to_string(),
+                        MenuEntryKind::Command => keymap
+                            .shortcut_for(entry.command)
+                            .map(Shortcut::label)
+                            .unwrap_or_default(),
+                        MenuEntryKind::Separator => String::new(),
+                    };
+                    (
+                        Self::item_rect(level.rect, entries, index, &style),
+                        entry.label.clone(),
+                        hint,
+                        matches!(entry.kind, MenuEntryKind::Separator),
+                        entry.checked == Some(true),
+                        entry.enabled,
+                        level.highlighted == Some(index),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let rect = level.rect;
+            push_overlay(owner, rect, move |context| {
+                context.set_fill_style_str(style.fill);
+                context.fill_rect(rect.x, rect.y, rect.width, rect.height);
+                context.set_stroke_style_str(style.border);
+                context.set_line_width(1.0);
+                context.stroke_rect(rect.x, rect.y, rect.width, rect.height);
+
+                let font = style.font.css();
+                context.set_font(&font);
+                context.set_text_baseline("middle");
+                for (row, label, hint, separator, checked, enabled, highlighted) in rows {
+                    let middle = row.y + row.height * 0.5;
+                    if separator {
+                        context.set_fill_style_str(style.separator);
+                        context.fill_rect(row.x + 8.0, middle.floor(), row.width - 16.0, 1.0);
+                        continue;
+                    }
+                    if highlighted {
+                        context.set_fill_style_str(style.highlight);
+                        context.fill_rect(row.x + 2.0, row.y, row.width - 4.0, row.height);
+                    }
+                    context.set_fill_style_str(if enabled { style.text } else { style.disabled_text });
+                    context.set_text_align("left");
+                    if checked {
+                        let _ = context.fill_text("\u{2713}", row.x + 10.0, middle);
+                    }
+                    let hint_width = cached_width(context, &font, &hint);
+                    let label = ellipsize(context, &font, &label, row.width - hint_width - 48.0);
+                    let _ = context.fill_text(&label, row.x + 28.0, middle);
+                    context.set_fill_style_str(if enabled { style.shortcut_text } else { style.disabled_text });
+                    context.set_text_align("right");
+                    let _ = context.fill_text(&hint, row.x + row.width - 12.0, middle);
+                }
+            });
+        }
+    }
+}
+
+pub struct MenuBarMenu {
+    pub label: String,
+    pub entries: Vec<MenuEntry>,
+}
+
+impl MenuBarMenu {
+    pub fn new(label: impl Into<String>, entries: Vec<MenuEntry>) -> Self {
+        Self {
+            label: label.into(),
+            entries,
+        }
+    }
+}
+
+pub struct MenuBar {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub menus: Vec<MenuBarMenu>,
+    pub keymap: Keymap,
+    pub style: MenuStyle,
+    pub focused: bool,
+    active: usize,
+    open: Option<usize>,
+    popup: MenuPopup,
+    title_rects: Vec<Rect>,
+}
+
+impl MenuBar {
+    pub fn new(key: &'static str, menus: Vec<MenuBarMenu>) -> Self {
+        let mut keymap = Keymap::default();
+        for menu in &menus {
+            bind_menu_shortcuts(&mut keymap, &menu.entries);
+        }
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            menus,
+            keymap,
+            style: MenuStyle::default(),
+            focused: false,
+            active: 0,
+            open: None,
+            popup: MenuPopup::default(),
+            title_rects: Vec::new(),
+        }
+    }
+
+    pub fn set_checked(&mut self, command: &str, checked: bool) {
+        for menu in &mut self.menus {
+            set_checked_in(&mut menu.entries, command, checked);
+        }
+    }
+
+    fn open_menu(&mut self, index: usize, highlight_first: bool) {
+        let (Some(menu), Some(anchor)) = (self.menus.get(index), self.title_rects.get(index)) else {
+            return;
+        };
+        self.active = index;
+        self.open = Some(index);
+        self.popup.open(&menu.entries, *anchor, &self.style, highlight_first);
+    }
+
+    fn close(&mut self) {
+        self.open = None;
+        self.popup.close();
+    }
+
+    fn wrapped(&self, index: usize, delta: isize) -> usize {
+        (index as isize + delta).rem_euclid(self.menus.len().max(1) as isize) as usize
+    }
+}
+
+impl Widget for MenuBar {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let pointer = pointer_for(self.key, pointer);
+        let mut events = Vec::new();
+        let font = self.style.font.css();
+
+        let mut x = self.rect.x + 4.0;
+        self.title_rects = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = cached_width(context, &font, &menu.label) + 24.0;
+                let rect = Rect {
+                    x,
+                    y: self.rect.y,
+                    width,
+                    height: self.rect.height,
+                };
+                x += width;
+                rect
+            })
+            .collect();
+
+        for menu in &mut self.menus {
+            if let Some(event) = resolve_shortcut(self.key, &mut menu.entries, &self.keymap, &pointer) {
+                events.push(event);
+                break;
+            }
+        }
+
+        let title = self
+            .title_rects
+            .iter()
+            .position(|rect| rect.contains(pointer.x, pointer.y));
+        let mut menu_pointer = pointer.clone();
+        if let Some(index) = title {
+            menu_pointer.just_pressed = false;
+            if pointer.just_pressed {
+                if self.open == Some(index) {
+                    self.close();
+                } else {
+                    self.open_menu(index, false);
+                }
+            } else if self.open.is_some_and(|open| open != index) {
+                self.open_menu(index, false);
+            }
+        }
+
+        if self.focused && self.open.is_none() && !self.menus.is_empty() {
+            if pointer.move_left {
+                self.active = self.wrapped(self.active, -1);
+            } else if pointer.move_right {
+                self.active = self.wrapped(self.active, 1);
+            } else if pointer.move_down || pointer.activate_primary {
+                self.open_menu(self.active, true);
+                menu_pointer.move_down = false;
+                menu_pointer.activate_primary = false;
+            }
+        }
+
+        if let Some(index) = self.open {
+            match self.popup.handle(self.key, &mut self.menus[index].entries, &menu_pointer, &self.style) {
+                MenuResult::Activated(event) => {
+                    events.push(event);
+                    self.open = None;
+                }
+                MenuResult::Closed => self.open = None,
+                MenuResult::Step(delta) => {
+                    let next = self.wrapped(index, delta);
+                    self.open_menu(next, true);
+                }
+                MenuResult::Idle => {}
+            }
+        }
+
+        context.set_fill_style_str(self.style.bar_fill);
+        context.fill_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        context.set_font(&font);
+        context.set_text_align("center");
+        context.set_text_baseline("middle");
+        for (index, (menu, rect)) in self.menus.iter().zip(&self.title_rects).enumerate() {
+            if self.open == Some(index) || (self.focused && self.active == index) {
+                context.set_fill_style_str(self.style.highlight);
+                context.fill_rect(rect.x, rect.y + 3.0, rect.width, rect.height - 6.0);
+            }
+            context.set_fill_style_str(self.style.text);
+            let _ = context.fill_text(&menu.label, rect.x + rect.width * 0.5, rect.y + rect.height * 0.5);
+        }
+        context.set_stroke_style_str(if self.focused {
+            self.style.focus_border
+        } else {
+            self.style.border
+        });
+        context.set_line_width(if self.focused { 2.0 } else { 1.0 });
+        context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+
+        if let Some(index) = self.open {
+            self.popup.draw(self.key, &self.menus[index].entries, &self.keymap, self.style);
+        }
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if !focused {
+            self.close();
+        }
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.keymap.resolve(shortcut).is_some()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+pub struct ContextMenu {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub child: Box<dyn Widget>,
+    pub entries: Vec<MenuEntry>,
+    pub keymap: Keymap,
+    pub style: MenuStyle,
+    pub focused: bool,
+    popup: MenuPopup,
+    long_press: Option<(f64, f64, f64)>,
+    swallow_release: bool,
+}
+
+impl ContextMenu {
+    pub fn new(key: &'static str, entries: Vec<MenuEntry>, child: Box<dyn Widget>) -> Self {
+        let mut keymap = Keymap::default();
+        bind_menu_shortcuts(&mut keymap, &entries);
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            child,
+            entries,
+            keymap,
+            style: MenuStyle::default(),
+            focused: false,
+            popup: MenuPopup::default(),
+            long_press: None,
+            swallow_release: false,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn set_checked(&mut self, command: &str, checked: bool) {
+        set_checked_in(&mut self.entries, command, checked);
+    }
+
+    fn open_at(&mut self, x: f64, y: f64) {
+        let anchor = Rect {
+            x,
+            y,
+            width: 0.0,
+            height: 0.0,
+        };
+        self.popup.open(&self.entries, anchor, &self.style, false);
+    }
+
+    fn update_long_press(&mut self, pointer: &PointerState) {
+        if pointer.touch && pointer.just_pressed && self.rect.contains(pointer.x, pointer.y) {
+            self.long_press = Some((pointer.x, pointer.y, pointer.time_ms));
+        }
+        let Some((x, y, since)) = self.long_press else {
+            return;
+        };
+        if !pointer.is_down || (pointer.x - x).hypot(pointer.y - y) > LONG_PRESS_SLOP {
+            self.long_press = None;
+        } else if pointer.time_ms - since >= LONG_PRESS_MS {
+            self.long_press = None;
+            self.swallow_release = true;
+            self.open_at(x, y);
+        } else {
+            request_frame();
+        }
+    }
+}
+
+impl Widget for ContextMenu {
+    fn desired_size(&self) -> (f64, f64) {
+        self.child.desired_size()
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.child.set_rect(rect);
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let mut child_pointer = pointer.clone();
+        let mut menu_pointer = pointer_for(self.key, pointer);
+        if pointer.secondary_click && self.rect.contains(pointer.x, pointer.y) {
+            self.open_at(pointer.x, pointer.y);
+            menu_pointer.secondary_click = false;
+        }
+        self.update_long_press(pointer);
+        if self.swallow_release && pointer.just_released {
+            child_pointer.just_released = false;
+            menu_pointer.just_released = false;
+            self.swallow_release = false;
+        }
+        let mut events = self.child.draw(context, &child_pointer);
+
+        if self.focused
+            && let Some(event) = resolve_shortcut(self.key, &mut self.entries, &self.keymap, &menu_pointer)
+        {
+            events.push(event);
+        }
+        if let MenuResult::Activated(event) = self.popup.handle(self.key, &mut self.entries, &menu_pointer, &self.style) {
+            events.push(event);
+        }
+        self.popup.draw(self.key, &self.entries, &self.keymap, self.style);
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        self.child.focusable()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.child.set_focused(focused);
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        self.child.activate()
+    }
+
+    fn focus_next_in_children(&mut self) -> bool {
+        self.child.focus_next_in_children()
+    }
+
+    fn focus_prev_in_children(&mut self) -> bool {
+        self.child.focus_prev_in_children()
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        self.child.focus_rect()
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.child.scrollable_at(x, y)
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        (self.focused && self.keymap.resolve(shortcut).is_some()) || self.child.handles_shortcut(shortcut)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -1,14 +1,26 @@
 pub mod button;
 pub mod checkbox;
+pub mod checkbox_group;
+pub mod combo_box;
+pub mod date_picker;
 pub mod container;
+pub mod dialog;
+pub mod form;
 pub mod form_field;
 pub mod label;
 pub mod list_view;
-pub mod modal;
+pub mod menu;
+pub mod number_input;
 pub mod radio_group;
+pub mod rich_text;
+pub mod scroll_view;
 pub mod select;
 pub mod slider;
+pub mod table;
 pub mod tabs;
 pub mod text_input;
+pub mod toast_host;
 pub mod toggle;
+pub mod tooltip;
+pub mod tree_view;
 pub mod triangle_hero;
@@ -0,0 +1,346 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::locale::Locale;
+use crate::ui::tree::{UiEvent, Widget};
+use crate::widgets::slider::clamp_and_snap;
+use crate::widgets::text_input::{TextInput, TextInputBehavior};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+const REPEAT_DELAY_MS: f64 = 400.0;
+const REPEAT_INTERVAL_MS: f64 = 60.0;
+const MAX_PRECISION: usize = 6;
+
+fn step_precision(step: f64) -> usize {
+    let step = step.abs();
+    (0..MAX_PRECISION)
+        .find(|decimals| {
+            let scaled = step * 10f64.powi(*decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-9 * scaled.max(1.0)
+        })
+        .unwrap_or(MAX_PRECISION)
+}
+
+#[derive(Clone, Copy)]
+pub struct NumberInputStyle {
+    pub button_fill: &'static str,
+    pub button_active_fill: &'static str,
+    pub border: &'static str,
+    pub focus_border: &'static str,
+    pub glyph: &'static str,
+    pub disabled_glyph: &'static str,
+    pub button_width: f64,
+}
+
+impl Default for NumberInputStyle {
+    fn default() -> Self {
+        Self {
+            button_fill: "#111827",
+            button_active_fill: "#1f3b66",
+            border: "#2a3350",
+            focus_border: "#27ffd8",
+            glyph: "#d8e3ff",
+            disabled_glyph: "#3b4768",
+            button_width: 28.0,
+        }
+    }
+}
+
+pub struct NumberInput {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub input: TextInput,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub precision: usize,
+    pub locale: Locale,
+    pub style: NumberInputStyle,
+    pub focused: bool,
+    value: Option<f64>,
+    committed: String,
+    reported_error: Option<Option<String>>,
+    held: Option<(f64, f64)>,
+}
+
+impl NumberInput {
+    pub fn new(key: &'static str, min: f64, max: f64, step: f64, placeholder: &'static str) -> Self {
+        let rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 0.0,
+            height: 0.0,
+        };
+        Self {
+            key,
+            rect,
+            input: TextInput::new(key, placeholder).behavior(TextInputBehavior {
+                allowed_chars: Some(|ch| ch.is_ascii_digit() || matches!(ch, '.' | ',' | '-' | '+' | '\'' | ' ')),
+                ..TextInputBehavior::default()
+            }),
+            min,
+            max,
+            step,
+            precision: step_precision(step),
+            locale: Locale::default(),
+            style: NumberInputStyle::default(),
+            focused: false,
+            value: None,
+            committed: String::new(),
+            reported_error: None,
+            held: None,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    #[allow(dead_code)]
+    pub fn set_value(&mut self, value: Option<f64>) {
+        self.value = value.map(|value| self.snap(value));
+        self.committed = self.canonical();
+        self.input.set_value(self.display_text());
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        clamp_and_snap(value, self.min, self.max, self.step)
+    }
+
+    fn canonical(&self) -> String {
+        self.value
+            .map(|value| format!("{:.*}", self.precision, value))
+            .unwrap_or_default()
+    }
+
+    fn display_text(&self) -> String {
+        self.value
+            .map(|value| self.locale.format_number(value, self.precision))
+            .unwrap_or_default()
+    }
+
+    fn input_error(&self) -> Option<String> {
+        let text = self.input.value.trim();
+        if text.is_empty() {
+            return None;
+        }
+        match self.locale.parse_number(text) {
+            None => Some("Enter a number.".to_string()),
+            Some(value) if value < self.min.min(self.max) || value > self.max.max(self.min) => Some(format!(
+                "Enter a value between {} and {}.",
+                self.locale.format_number(self.min.min(self.max), self.precision),
+                self.locale.format_number(self.max.max(self.min), self.precision)
+            )),
+            Some(_) => None,
+        }
+    }
+
+    fn validation_event(&mut self) -> Option<UiEvent> {
+        let error = self.input_error();
+        if self.reported_error.as_ref() == Some(&error) {
+            return None;
+        }
+        self.reported_error = Some(error.clone());
+        Some(UiEvent::Validation { key: self.key, error })
+    }
+
+    fn emit_if_changed(&mut self) -> Option<UiEvent> {
+        let value = self.canonical();
+        if value == self.committed {
+            return None;
+        }
+        self.committed = value.clone();
+        Some(UiEvent::ValueChanged { key: self.key, value })
+    }
+
+    fn commit_input(&mut self) -> Option<UiEvent> {
+        let text = self.input.value.trim().to_string();
+        if text.is_empty() {
+            self.value = None;
+        } else if let Some(parsed) = self.locale.parse_number(&text) {
+            self.value = Some(self.snap(parsed));
+        } else {
+            return None;
+        }
+        self.input.set_value(self.display_text());
+        self.emit_if_changed()
+    }
+
+    fn apply_step(&mut self, steps: f64) -> Option<UiEvent> {
+        let typed = self.locale.parse_number(&self.input.value);
+        let base = typed.or(self.value).unwrap_or_else(|| self.min.max(0.0).min(self.max));
+        let next = if typed.is_none() && self.value.is_none() {
+            self.snap(base)
+        } else {
+            self.snap(base + self.step.abs() * steps)
+        };
+        self.value = Some(next);
+        self.input.set_value(self.display_text());
+        self.emit_if_changed()
+    }
+
+    fn button_rects(&self) -> (Rect, Rect) {
+        let x = self.rect.x + self.rect.width - self.style.button_width;
+        let half = self.rect.height * 0.5;
+        let up = Rect {
+            x,
+            y: self.rect.y,
+            width: self.style.button_width,
+            height: half,
+        };
+        let down = Rect { y: self.rect.y + half, ..up };
+        (up, down)
+    }
+
+    fn can_step(&self, direction: f64) -> bool {
+        match self.value {
+            Some(value) if direction > 0.0 => value < self.max.max(self.min),
+            Some(value) => value > self.min.min(self.max),
+            None => true,
+        }
+    }
+
+    fn handle_buttons(&mut self, pointer: &PointerState) -> Option<UiEvent> {
+        let (up, down) = self.button_rects();
+        let direction = if up.contains(pointer.x, pointer.y) {
+            1.0
+        } else if down.contains(pointer.x, pointer.y) {
+            -1.0
+        } else {
+            0.0
+        };
+        if pointer.just_pressed && direction != 0.0 {
+            self.held = Some((direction, pointer.time_ms + REPEAT_DELAY_MS));
+            request_frame();
+            return self.apply_step(direction);
+        }
+        let (held_direction, repeat_at) = self.held?;
+        if !pointer.is_down || direction != held_direction {
+            self.held = None;
+            return None;
+        }
+        request_frame();
+        if pointer.time_ms < repeat_at {
+            return None;
+        }
+        self.held = Some((held_direction, repeat_at + REPEAT_INTERVAL_MS));
+        self.apply_step(held_direction)
+    }
+
+    fn draw_button(&self, context: &CanvasRenderingContext2d, rect: Rect, direction: f64) {
+        let active = self.held.is_some_and(|(held, _)| held == direction);
+        context.set_fill_style_str(if active {
+            self.style.button_active_fill
+        } else {
+            self.style.button_fill
+        });
+        context.fill_rect(rect.x, rect.y, rect.width, rect.height);
+        context.set_stroke_style_str(self.style.border);
+        context.set_line_width(1.0);
+        context.stroke_rect(rect.x, rect.y, rect.width, rect.height);
+
+        let center_x = rect.x + rect.width * 0.5;
+        let center_y = rect.y + rect.height * 0.5;
+        context.set_fill_style_str(if self.can_step(direction) {
+            self.style.glyph
+        } else {
+            self.style.disabled_glyph
+        });
+        context.begin_path();
+        context.move_to(center_x - 4.0, center_y + 2.0 * direction);
+        context.line_to(center_x + 4.0, center_y + 2.0 * direction);
+        context.line_to(center_x, center_y - 2.5 * direction);
+        context.close_path();
+        context.fill();
+    }
+}
+
+impl Widget for NumberInput {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let mut events = Vec::new();
+        if !self.focused && self.input.value != self.display_text() {
+            events.extend(self.commit_input());
+        }
+
+        let mut input_pointer = pointer.clone();
+        if self.focused {
+            let steps = if pointer.move_up {
+                1.0
+            } else if pointer.move_down {
+                -1.0
+            } else if pointer.move_page_up {
+                10.0
+            } else if pointer.move_page_down {
+                -10.0
+            } else {
+                0.0
+            };
+            if steps != 0.0 {
+                events.extend(self.apply_step(steps));
+            } else if pointer.cancel {
+                self.input.set_value(self.display_text());
+            }
+            if self.rect.contains(pointer.x, pointer.y) && pointer.scroll_y != 0.0 {
+                events.extend(self.apply_step(-pointer.scroll_y.signum()));
+            }
+            input_pointer.move_up = false;
+            input_pointer.move_down = false;
+            input_pointer.cancel = false;
+        }
+        events.extend(self.handle_buttons(pointer));
+
+        self.input.focused = self.focused;
+        self.input.set_rect(Rect {
+            width: (self.rect.width - self.style.button_width).max(0.0),
+            ..self.rect
+        });
+        let _ = self.input.draw(context, &input_pointer);
+        events.extend(self.validation_event());
+
+        let (up, down) = self.button_rects();
+        self.draw_button(context, up, 1.0);
+        self.draw_button(context, down, -1.0);
+        context.set_stroke_style_str(if self.focused {
+            self.style.focus_border
+        } else {
+            self.style.border
+        });
+        context.set_line_width(2.0);
+        context.stroke_rect(up.x, up.y, up.width, up.height + down.height);
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.input.set_focused(focused);
+        if !focused {
+            self.held = None;
+        }
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        self.commit_input()
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.focused && self.rect.contains(x, y)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
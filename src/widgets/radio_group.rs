@@ -1,6 +1,7 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
-use crate::core::navigation::step_wrapped;
+use crate::core::navigation::{TypeAhead, find_next_prefix, step_wrapped};
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -12,6 +13,7 @@ pub struct RadioGroup {
     pub options: Vec<String>,
     pub selected: usize,
     pub focused: bool,
+    pub type_ahead: TypeAhead,
     pub style: RadioGroupStyle,
 }
 
@@ -21,7 +23,7 @@ pub struct RadioGroupStyle {
     pub focus_border: &'static str,
     pub selected_fill: &'static str,
     pub text: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
 }
 
 impl Default for RadioGroupStyle {
@@ -32,7 +34,7 @@ impl Default for RadioGroupStyle {
             focus_border: "#27ffd8",
             selected_fill: "#1f3b66",
             text: "#d8e3ff",
-            font: "600 14px Consolas",
+            font: FontDescriptor::new("Consolas", 14.0).weight(600),
         }
     }
 }
@@ -69,6 +71,17 @@ impl RadioGroup {
         }
         None
     }
+
+    fn jump_to(&mut self, text: &str, time_ms: f64) -> Option<UiEvent> {
+        let index = self
+            .type_ahead
+            .search(&self.options, text, time_ms, Some(self.selected), find_next_prefix)?;
+        if index == self.selected {
+            return None;
+        }
+        self.selected = index;
+        Some(self.emit_changed())
+    }
 }
 
 impl Widget for RadioGroup {
@@ -103,6 +116,11 @@ impl Widget for RadioGroup {
                     events.push(self.emit_changed());
                 }
             }
+            if let Some(input) = &pointer.text_input
+                && let Some(event) = self.jump_to(input, pointer.time_ms)
+            {
+                events.push(event);
+            }
         }
 
         context.set_fill_style_str(self.style.fill);
@@ -115,7 +133,7 @@ impl Widget for RadioGroup {
         context.set_line_width(2.0);
         context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_fill_style_str(self.style.text);
         context.set_text_align("left");
         context.set_text_baseline("middle");
@@ -167,6 +185,9 @@ impl Widget for RadioGroup {
     }
 
     fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.type_ahead.clear();
+        }
         self.focused = focused;
     }
 
@@ -0,0 +1,190 @@
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::{TextAlign, TextLayoutOptions, VerticalAlign};
+use crate::text::rich::{draw_rich, layout_rich, RichTextTheme, TextSpan};
+use crate::ui::tree::{UiEvent, Widget};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+pub struct RichText {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub spans: Vec<TextSpan>,
+    pub style: RichTextStyle,
+    pub focused: bool,
+    pub focused_link: Option<usize>,
+}
+
+pub struct RichTextStyle {
+    pub theme: RichTextTheme,
+    pub focus_border: &'static str,
+    pub align: TextAlign,
+    pub vertical_align: VerticalAlign,
+    pub wrap: bool,
+    pub max_lines: Option<usize>,
+    pub line_height: f64,
+}
+
+impl Default for RichTextStyle {
+    fn default() -> Self {
+        Self {
+            theme: RichTextTheme {
+                font: FontDescriptor::new("Consolas", 14.0),
+                code_font: FontDescriptor::new("Consolas", 13.0),
+                color: "#d8e3ff",
+                link_color: "#27ffd8",
+                code_background: "#1c2742",
+            },
+            focus_border: "#27ffd8",
+            align: TextAlign::Center,
+            vertical_align: VerticalAlign::Middle,
+            wrap: true,
+            max_lines: None,
+            line_height: 1.3,
+        }
+    }
+}
+
+impl RichText {
+    pub fn set_spans(&mut self, spans: Vec<TextSpan>) {
+        self.spans = spans;
+        if let Some(index) = self.focused_link
+            && self.spans.get(index).and_then(|span| span.style.link).is_none()
+        {
+            self.focused_link = self.link_indices().first().copied();
+        }
+    }
+
+    fn link_indices(&self) -> Vec<usize> {
+        self.spans
+            .iter()
+            .enumerate()
+            .filter(|(_, span)| span.style.link.is_some())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn step_link(&mut self, delta: isize) {
+        let links = self.link_indices();
+        if links.is_empty() {
+            self.focused_link = None;
+            return;
+        }
+        let current = self
+            .focused_link
+            .and_then(|index| links.iter().position(|link| *link == index));
+        let next = crate::core::navigation::step_clamped(current, delta, links.len()).unwrap_or(0);
+        self.focused_link = Some(links[next]);
+    }
+
+    fn emit_link(&self, span: usize) -> Option<UiEvent> {
+        self.spans
+            .get(span)
+            .and_then(|span| span.style.link)
+            .map(|target| UiEvent::LinkActivated {
+                key: self.key,
+                target,
+            })
+    }
+}
+
+impl Widget for RichText {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let mut events = Vec::new();
+        let layout = layout_rich(
+            context,
+            &self.spans,
+            &self.style.theme,
+            TextLayoutOptions {
+                max_width: Some(self.rect.width),
+                wrap: self.style.wrap,
+                max_lines: self.style.max_lines,
+                line_height: self.style.line_height,
+                ellipsis: true,
+            },
+        );
+
+        let hovered_span = layout
+            .hit_test(
+                self.rect,
+                self.style.align,
+                self.style.vertical_align,
+                pointer.x,
+                pointer.y,
+            )
+            .filter(|index| self.spans[*index].style.link.is_some());
+        if pointer.just_released
+            && let Some(span) = hovered_span
+        {
+            self.focused_link = Some(span);
+            events.extend(self.emit_link(span));
+        }
+
+        if self.focused {
+            if pointer.move_left || pointer.move_up {
+                self.step_link(-1);
+            } else if pointer.move_right || pointer.move_down {
+                self.step_link(1);
+            }
+        }
+
+        let highlighted = hovered_span.or(if self.focused { self.focused_link } else { None });
+        draw_rich(
+            context,
+            &self.spans,
+            &layout,
+            &self.style.theme,
+            self.rect,
+            self.style.align,
+            self.style.vertical_align,
+            highlighted,
+        );
+
+        if self.focused
+            && let Some(fragment) = layout
+                .fragments
+                .iter()
+                .find(|fragment| Some(fragment.span) == self.focused_link)
+        {
+            let area = layout.fragment_rect(
+                fragment,
+                self.rect,
+                self.style.align,
+                self.style.vertical_align,
+            );
+            context.set_stroke_style_str(self.style.focus_border);
+            context.set_line_width(1.0);
+            context.stroke_rect(area.x - 2.0, area.y, area.width + 4.0, area.height);
+        }
+
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        self.spans.iter().any(|span| span.style.link.is_some())
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        if focused && self.focused_link.is_none() {
+            self.focused_link = self.link_indices().first().copied();
+        }
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        self.focused_link.and_then(|span| self.emit_link(span))
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,606 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
+use crate::ui::tree::{UiEvent, UiTree, Widget};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+const DRAG_THRESHOLD: f64 = 6.0;
+const FRAME_MS: f64 = 16.0;
+const FRICTION_PER_FRAME: f64 = 0.95;
+const OVERSCROLL_DAMPING_PER_FRAME: f64 = 0.5;
+const SPRING_PER_FRAME: f64 = 0.78;
+const MIN_VELOCITY: f64 = 0.02;
+const MAX_VELOCITY: f64 = 4.0;
+const RUBBER_BAND: f64 = 0.55;
+const FOCUS_MARGIN: f64 = 8.0;
+
+pub struct ScrollViewStyle {
+    pub track: &'static str,
+    pub thumb: &'static str,
+    pub thumb_active: &'static str,
+    pub thickness: f64,
+    pub min_thumb: f64,
+    pub auto_hide: bool,
+    pub hide_delay_ms: f64,
+    pub fade_ms: f64,
+}
+
+impl Default for ScrollViewStyle {
+    fn default() -> Self {
+        Self {
+            track: "#141d34",
+            thumb: "#3a4a72",
+            thumb_active: "#27ffd8",
+            thickness: 8.0,
+            min_thumb: 24.0,
+            auto_hide: true,
+            hide_delay_ms: 900.0,
+            fade_ms: 300.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DragKind {
+    Content,
+    Track,
+    Thumb { axis: Axis, grab: f64 },
+}
+
+#[derive(Default)]
+struct ScrollMotion {
+    drag: Option<DragKind>,
+    dragging: bool,
+    press_x: f64,
+    press_y: f64,
+    start_x: f64,
+    start_y: f64,
+    last_x: f64,
+    last_y: f64,
+    last_sample: f64,
+    velocity_x: f64,
+    velocity_y: f64,
+    last_time: f64,
+    last_activity: f64,
+}
+
+pub struct ScrollView {
+    pub rect: Rect,
+    pub content: UiTree,
+    pub scroll_x: f64,
+    pub scroll_y: f64,
+    pub horizontal: bool,
+    pub vertical: bool,
+    pub style: ScrollViewStyle,
+    pub focused: bool,
+    motion: ScrollMotion,
+    last_focus: Option<&'static str>,
+}
+
+impl ScrollView {
+    pub fn new(content: UiTree) -> Self {
+        Self {
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            content,
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            horizontal: false,
+            vertical: true,
+            style: ScrollViewStyle::default(),
+            focused: false,
+            motion: ScrollMotion::default(),
+            last_focus: None,
+        }
+    }
+
+    fn content_extent(&self) -> (f64, f64) {
+        let (width, height) = self.content.content_size();
+        (
+            if self.horizontal {
+                width.max(self.rect.width)
+            } else {
+                self.rect.width
+            },
+            if self.vertical {
+                height.max(self.rect.height)
+            } else {
+                self.rect.height
+            },
+        )
+    }
+
+    fn max_scroll(&self) -> (f64, f64) {
+        let (width, height) = self.content_extent();
+        (
+            (width - self.rect.width).max(0.0),
+            (height - self.rect.height).max(0.0),
+        )
+    }
+
+    fn track(&self, axis: Axis) -> Rect {
+        let thickness = self.style.thickness;
+        let (max_x, max_y) = self.max_scroll();
+        let corner = if max_x > 0.0 && max_y > 0.0 {
+            thickness
+        } else {
+            0.0
+        };
+        match axis {
+            Axis::Vertical => Rect {
+                x: self.rect.x + self.rect.width - thickness,
+                y: self.rect.y,
+                width: thickness,
+                height: (self.rect.height - corner).max(0.0),
+            },
+            Axis::Horizontal => Rect {
+                x: self.rect.x,
+                y: self.rect.y + self.rect.height - thickness,
+                width: (self.rect.width - corner).max(0.0),
+                height: thickness,
+            },
+        }
+    }
+
+    fn thumb(&self, axis: Axis) -> Option<Rect> {
+        let (max_x, max_y) = self.max_scroll();
+        let (extent_w, extent_h) = self.content_extent();
+        let track = self.track(axis);
+        let (max, scroll, viewport, extent, track_length) = match axis {
+            Axis::Vertical => (max_y, self.scroll_y, self.rect.height, extent_h, track.height),
+            Axis::Horizontal => (max_x, self.scroll_x, self.rect.width, extent_w, track.width),
+        };
+        if max <= 0.0 {
+            return None;
+        }
+        let length = (track_length * viewport / extent)
+            .max(self.style.min_thumb)
+            .min(track_length);
+        let offset = (track_length - length) * (scroll / max).clamp(0.0, 1.0);
+        Some(match axis {
+            Axis::Vertical => Rect {
+                y: track.y + offset,
+                height: length,
+                ..track
+            },
+            Axis::Horizontal => Rect {
+                x: track.x + offset,
+                width: length,
+                ..track
+            },
+        })
+    }
+
+    fn scrollbar_at(&self, x: f64, y: f64) -> Option<Axis> {
+        [Axis::Vertical, Axis::Horizontal]
+            .into_iter()
+            .find(|axis| self.thumb(*axis).is_some() && self.track(*axis).contains(x, y))
+    }
+
+    fn drag_thumb(&mut self, axis: Axis, grab: f64, pointer: &PointerState) {
+        let (Some(thumb), track) = (self.thumb(axis), self.track(axis)) else {
+            return;
+        };
+        let (max_x, max_y) = self.max_scroll();
+        match axis {
+            Axis::Vertical => {
+                let travel = (track.height - thumb.height).max(1.0);
+                self.scroll_y = ((pointer.y - grab - track.y) / travel * max_y).clamp(0.0, max_y);
+            }
+            Axis::Horizontal => {
+                let travel = (track.width - thumb.width).max(1.0);
+                self.scroll_x = ((pointer.x - grab - track.x) / travel * max_x).clamp(0.0, max_x);
+            }
+        }
+    }
+
+    fn page_toward(&mut self, axis: Axis, pointer: &PointerState) {
+        let Some(thumb) = self.thumb(axis) else {
+            return;
+        };
+        let (max_x, max_y) = self.max_scroll();
+        match axis {
+            Axis::Vertical => {
+                let page = (self.rect.height - FOCUS_MARGIN * 2.0).max(FOCUS_MARGIN);
+                let direction = if pointer.y < thumb.y { -1.0 } else { 1.0 };
+                self.scroll_y = (self.scroll_y + page * direction).clamp(0.0, max_y);
+            }
+            Axis::Horizontal => {
+                let page = (self.rect.width - FOCUS_MARGIN * 2.0).max(FOCUS_MARGIN);
+                let direction = if pointer.x < thumb.x { -1.0 } else { 1.0 };
+                self.scroll_x = (self.scroll_x + page * direction).clamp(0.0, max_x);
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn scroll_to(&mut self, x: f64, y: f64) {
+        let (max_x, max_y) = self.max_scroll();
+        self.scroll_x = x.clamp(0.0, max_x);
+        self.scroll_y = y.clamp(0.0, max_y);
+        self.motion.velocity_x = 0.0;
+        self.motion.velocity_y = 0.0;
+    }
+
+    pub fn scroll_into_view(&mut self, target: Rect) -> bool {
+        let (max_x, max_y) = self.max_scroll();
+        let reveal = |scroll: f64, start: f64, length: f64, viewport: f64, max: f64| {
+            let mut scroll = scroll;
+            if start + length + FOCUS_MARGIN > scroll + viewport {
+                scroll = start + length + FOCUS_MARGIN - viewport;
+            }
+            if start - FOCUS_MARGIN < scroll {
+                scroll = start - FOCUS_MARGIN;
+            }
+            scroll.clamp(0.0, max)
+        };
+        let x = reveal(self.scroll_x, target.x, target.width, self.rect.width, max_x);
+        let y = reveal(self.scroll_y, target.y, target.height, self.rect.height, max_y);
+        let changed = (x - self.scroll_x).abs() > 0.5 || (y - self.scroll_y).abs() > 0.5;
+        self.scroll_x = x;
+        self.scroll_y = y;
+        changed
+    }
+
+    fn handle_wheel(&mut self, pointer: &PointerState, now: f64) -> bool {
+        let (max_x, max_y) = self.max_scroll();
+        if (pointer.scroll_x == 0.0 && pointer.scroll_y == 0.0) || (max_x <= 0.0 && max_y <= 0.0) {
+            return false;
+        }
+        let (delta_x, delta_y) = if max_y <= 0.0 {
+            (pointer.scroll_x + pointer.scroll_y, 0.0)
+        } else {
+            (pointer.scroll_x, pointer.scroll_y)
+        };
+        self.scroll_x = (self.scroll_x.clamp(0.0, max_x) + delta_x).clamp(0.0, max_x);
+        self.scroll_y = (self.scroll_y.clamp(0.0, max_y) + delta_y).clamp(0.0, max_y);
+        self.motion.velocity_x = 0.0;
+        self.motion.velocity_y = 0.0;
+        self.motion.last_activity = now;
+        true
+    }
+
+    fn begin_press(&mut self, pointer: &PointerState, over_scrollable_child: bool) {
+        self.motion.velocity_x = 0.0;
+        self.motion.velocity_y = 0.0;
+        if let Some(axis) = self.scrollbar_at(pointer.x, pointer.y) {
+            let thumb = self.thumb(axis).expect("scrollbar_at only reports visible thumbs");
+            if thumb.contains(pointer.x, pointer.y) {
+                let grab = match axis {
+                    Axis::Vertical => pointer.y - thumb.y,
+                    Axis::Horizontal => pointer.x - thumb.x,
+                };
+                self.motion.drag = Some(DragKind::Thumb { axis, grab });
+            } else {
+                self.page_toward(axis, pointer);
+                self.motion.drag = Some(DragKind::Track);
+            }
+        } else if pointer.touch && !over_scrollable_child {
+            self.motion.drag = Some(DragKind::Content);
+            self.motion.dragging = false;
+            self.motion.press_x = pointer.x;
+            self.motion.press_y = pointer.y;
+            self.motion.start_x = self.scroll_x;
+            self.motion.start_y = self.scroll_y;
+            self.motion.last_x = pointer.x;
+            self.motion.last_y = pointer.y;
+            self.motion.last_sample = pointer.time_ms;
+        }
+    }
+
+    fn drag_content(&mut self, pointer: &PointerState, now: f64) {
+        let delta_x = pointer.x - self.motion.press_x;
+        let delta_y = pointer.y - self.motion.press_y;
+        if !self.motion.dragging && delta_x.hypot(delta_y) > DRAG_THRESHOLD {
+            self.motion.dragging = true;
+        }
+        if !self.motion.dragging {
+            return;
+        }
+
+        let (max_x, max_y) = self.max_scroll();
+        if max_x > 0.0 {
+            self.scroll_x = rubber_band(self.motion.start_x - delta_x, max_x, self.rect.width);
+        }
+        if max_y > 0.0 {
+            self.scroll_y = rubber_band(self.motion.start_y - delta_y, max_y, self.rect.height);
+        }
+
+        let elapsed = now - self.motion.last_sample;
+        if elapsed > 0.0 {
+            let sample_x = (self.motion.last_x - pointer.x) / elapsed;
+            let sample_y = (self.motion.last_y - pointer.y) / elapsed;
+            self.motion.velocity_x = self.motion.velocity_x * 0.2 + sample_x * 0.8;
+            self.motion.velocity_y = self.motion.velocity_y * 0.2 + sample_y * 0.8;
+        }
+        self.motion.last_x = pointer.x;
+        self.motion.last_y = pointer.y;
+        self.motion.last_sample = now;
+        self.motion.last_activity = now;
+    }
+
+    fn end_drag(&mut self, now: f64) {
+        let flung = self.motion.drag == Some(DragKind::Content)
+            && self.motion.dragging
+            && now - self.motion.last_sample < 100.0;
+        if flung {
+            self.motion.velocity_x = self.motion.velocity_x.clamp(-MAX_VELOCITY, MAX_VELOCITY);
+            self.motion.velocity_y = self.motion.velocity_y.clamp(-MAX_VELOCITY, MAX_VELOCITY);
+        } else {
+            self.motion.velocity_x = 0.0;
+            self.motion.velocity_y = 0.0;
+        }
+        self.motion.drag = None;
+        self.motion.dragging = false;
+    }
+
+    fn animate(&mut self, elapsed: f64, now: f64) -> bool {
+        let (max_x, max_y) = self.max_scroll();
+        let moving_x = step_axis(&mut self.scroll_x, &mut self.motion.velocity_x, max_x, elapsed);
+        let moving_y = step_axis(&mut self.scroll_y, &mut self.motion.velocity_y, max_y, elapsed);
+        if moving_x || moving_y {
+            self.motion.last_activity = now;
+        }
+        moving_x || moving_y
+    }
+
+    fn scrollbar_alpha(&self, now: f64, hovering_bar: bool) -> f64 {
+        let holding_bar = matches!(self.motion.drag, Some(DragKind::Thumb { .. } | DragKind::Track));
+        if !self.style.auto_hide || holding_bar || hovering_bar {
+            return 1.0;
+        }
+        let idle = now - self.motion.last_activity - self.style.hide_delay_ms;
+        if idle <= 0.0 {
+            1.0
+        } else {
+            (1.0 - idle / self.style.fade_ms.max(1.0)).max(0.0)
+        }
+    }
+
+    fn draw_scrollbars(&self, context: &CanvasRenderingContext2d, alpha: f64, hovering_bar: Option<Axis>) {
+        if alpha <= 0.0 {
+            return;
+        }
+        context.save();
+        context.set_global_alpha(alpha);
+        for axis in [Axis::Vertical, Axis::Horizontal] {
+            let Some(thumb) = self.thumb(axis) else {
+                continue;
+            };
+            let track = self.track(axis);
+            context.set_fill_style_str(self.style.track);
+            context.fill_rect(track.x, track.y, track.width, track.height);
+            let active = hovering_bar == Some(axis)
+                || matches!(self.motion.drag, Some(DragKind::Thumb { axis: dragged, .. }) if dragged == axis);
+            context.set_fill_style_str(if active {
+                self.style.thumb_active
+            } else {
+                self.style.thumb
+            });
+            context.fill_rect(thumb.x, thumb.y, thumb.width, thumb.height);
+        }
+        context.restore();
+    }
+
+    fn reveal_focus(&mut self, origin_x: f64, origin_y: f64) -> bool {
+        let Some(focus) = self.content.focus_rect() else {
+            return false;
+        };
+        self.scroll_into_view(Rect {
+            x: focus.x - origin_x,
+            y: focus.y - origin_y,
+            ..focus
+        })
+    }
+}
+
+fn rubber_band(value: f64, max: f64, dimension: f64) -> f64 {
+    let excess = if value < 0.0 {
+        -value
+    } else if value > max {
+        value - max
+    } else {
+        return value;
+    };
+    let dimension = dimension.max(1.0);
+    let damped = (1.0 - 1.0 / (excess * RUBBER_BAND / dimension + 1.0)) * dimension;
+    if value < 0.0 {
+        -damped
+    } else {
+        max + damped
+    }
+}
+
+fn step_axis(scroll: &mut f64, velocity: &mut f64, max: f64, elapsed: f64) -> bool {
+    let frames = elapsed / FRAME_MS;
+    let mut moving = false;
+    if velocity.abs() > MIN_VELOCITY {
+        *scroll += *velocity * elapsed;
+        *velocity *= FRICTION_PER_FRAME.powf(frames);
+        if *scroll < 0.0 || *scroll > max {
+            *velocity *= OVERSCROLL_DAMPING_PER_FRAME.powf(frames);
+        }
+        moving = true;
+    } else {
+        *velocity = 0.0;
+    }
+
+    let target = scroll.clamp(0.0, max);
+    if (*scroll - target).abs() > 0.5 {
+        if *velocity == 0.0 {
+            *scroll = target + (*scroll - target) * SPRING_PER_FRAME.powf(frames);
+        }
+        moving = true;
+    } else if *velocity == 0.0 {
+        *scroll = target;
+    }
+    moving
+}
+
+impl Widget for ScrollView {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let now = pointer.time_ms;
+        let elapsed = if self.motion.last_time > 0.0 {
+            (now - self.motion.last_time).clamp(0.0, 100.0)
+        } else {
+            FRAME_MS
+        };
+        self.motion.last_time = now;
+
+        let hovered = self.rect.contains(pointer.x, pointer.y);
+        let over_scrollable_child = self.content.scrollable_at(pointer.x, pointer.y);
+
+        let mut child_pointer = pointer.clone();
+        child_pointer.focus_next = false;
+        child_pointer.focus_prev = false;
+        if !self.focused {
+            child_pointer.suppress_focus_and_text_input();
+        }
+
+        if hovered && !over_scrollable_child && self.handle_wheel(pointer, now) {
+            child_pointer.scroll_x = 0.0;
+            child_pointer.scroll_y = 0.0;
+        }
+        if pointer.just_pressed && hovered {
+            self.begin_press(pointer, over_scrollable_child);
+        }
+        match self.motion.drag {
+            Some(DragKind::Thumb { axis, grab }) if pointer.is_down => {
+                self.drag_thumb(axis, grab, pointer);
+                self.motion.last_activity = now;
+            }
+            Some(DragKind::Content) => self.drag_content(pointer, now),
+            _ => {}
+        }
+
+        let captured = match self.motion.drag {
+            Some(DragKind::Content) => self.motion.dragging,
+            Some(_) => true,
+            None => false,
+        };
+        if captured {
+            child_pointer.just_pressed = false;
+            child_pointer.just_released = false;
+            child_pointer.is_down = false;
+        }
+        if captured || !hovered {
+            child_pointer.x = f64::NEG_INFINITY;
+            child_pointer.y = f64::NEG_INFINITY;
+        }
+        if self.motion.drag.is_some() && (pointer.just_released || !pointer.is_down) {
+            self.end_drag(now);
+        }
+
+        let animating = self.motion.drag.is_none() && self.animate(elapsed, now);
+
+        let (extent_w, extent_h) = self.content_extent();
+        let origin_x = self.rect.x - self.scroll_x;
+        let origin_y = self.rect.y - self.scroll_y;
+        self.content.set_area(Rect {
+            x: origin_x,
+            y: origin_y,
+            width: extent_w,
+            height: extent_h,
+        });
+
+        context.save();
+        context.begin_path();
+        context.rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        context.clip();
+        let events = self.content.draw(context, &child_pointer);
+        context.restore();
+
+        let focus_key = self.content.focused_key();
+        let keyboard_focus = pointer.focus_next
+            || pointer.focus_prev
+            || (focus_key != self.last_focus && !pointer.just_pressed && !pointer.just_released);
+        self.last_focus = focus_key;
+        if self.focused && keyboard_focus && self.reveal_focus(origin_x, origin_y) {
+            self.motion.velocity_x = 0.0;
+            self.motion.velocity_y = 0.0;
+            self.motion.last_activity = now;
+            request_frame();
+        }
+
+        let hovering_bar = if hovered && self.motion.drag.is_none() {
+            self.scrollbar_at(pointer.x, pointer.y)
+        } else {
+            None
+        };
+        let alpha = self.scrollbar_alpha(now, hovering_bar.is_some());
+        self.draw_scrollbars(context, alpha, hovering_bar);
+
+        let has_bars = self.thumb(Axis::Vertical).is_some() || self.thumb(Axis::Horizontal).is_some();
+        if animating || (has_bars && alpha > 0.0 && hovering_bar.is_none() && self.style.auto_hide) {
+            request_frame();
+        }
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn focus_next_in_children(&mut self) -> bool {
+        if self.content.focus_next_in_focused_child() {
+            return true;
+        }
+        if !self.content.focus_next_bounded() {
+            return false;
+        }
+        self.content.focus_next_in_focused_child();
+        true
+    }
+
+    fn focus_prev_in_children(&mut self) -> bool {
+        if self.content.focus_prev_in_focused_child() {
+            return true;
+        }
+        if !self.content.focus_prev_bounded() {
+            return false;
+        }
+        self.content.focus_prev_in_focused_child();
+        true
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        self.content.focus_rect()
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        let (max_x, max_y) = self.max_scroll();
+        self.rect.contains(x, y) && (max_x > 0.0 || max_y > 0.0)
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.content.handles_shortcut(shortcut)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
-use crate::core::navigation::{find_next_prefix, step_wrapped};
+use crate::core::navigation::{TypeAhead, find_next_prefix, step_wrapped};
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -15,6 +16,7 @@ pub struct Select {
     pub open: bool,
     pub highlighted: usize,
     pub label: &'static str,
+    pub type_ahead: TypeAhead,
 }
 
 pub struct SelectStyle {
@@ -24,7 +26,7 @@ pub struct SelectStyle {
     pub border: &'static str,
     pub focus_border: &'static str,
     pub text: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
     pub option_height: f64,
 }
 
@@ -37,7 +39,7 @@ impl Default for SelectStyle {
             border: "#2a3350",
             focus_border: "#27ffd8",
             text: "#d8e3ff",
-            font: "600 15px Consolas",
+            font: FontDescriptor::new("Consolas", 15.0).weight(600),
             option_height: 34.0,
         }
     }
@@ -102,12 +104,15 @@ impl Select {
         }
     }
 
-    fn jump_to_option(&mut self, needle: &str) -> Option<UiEvent> {
+    fn jump_to_option(&mut self, needle: &str, time_ms: f64) -> Option<UiEvent> {
         if needle.is_empty() || self.options.is_empty() {
             return None;
         }
 
-        if let Some(index) = find_next_prefix(&self.options, needle, Some(self.selected)) {
+        if let Some(index) = self
+            .type_ahead
+            .search(&self.options, needle, time_ms, Some(self.selected), find_next_prefix)
+        {
             self.selected = index;
             self.highlighted = index;
             return Some(UiEvent::ValueChanged {
@@ -153,7 +158,7 @@ impl Widget for Select {
             }
 
             if let Some(input) = &pointer.text_input {
-                if let Some(event) = self.jump_to_option(input) {
+                if let Some(event) = self.jump_to_option(input, pointer.time_ms) {
                     events.push(event);
                 }
             }
@@ -212,7 +217,7 @@ impl Widget for Select {
         context.set_line_width(2.0);
         context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_fill_style_str(self.style.text);
         context.set_text_align("left");
         context.set_text_baseline("middle");
@@ -267,6 +272,9 @@ impl Widget for Select {
     }
 
     fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.type_ahead.clear();
+        }
         self.focused = focused;
         if !focused {
             self.open = false;
@@ -1,19 +1,44 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::text::font::FontDescriptor;
+use crate::text::measure::cached_width;
+use crate::ui::overlay::{pointer_for, push_overlay};
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
 
+const MAX_TICKS: usize = 200;
+
+pub fn clamp_and_snap(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    let range_min = min.min(max);
+    let range_max = max.max(min);
+    let step = step.abs().max(0.000_001);
+    let snapped = ((value - range_min) / step).round() * step + range_min;
+    snapped.clamp(range_min, range_max)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
 pub struct Slider {
     pub key: &'static str,
     pub rect: Rect,
     pub value: f64,
+    pub range_end: Option<f64>,
     pub min: f64,
     pub max: f64,
     pub step: f64,
+    pub large_step: Option<f64>,
+    pub orientation: Orientation,
+    pub tick_interval: Option<f64>,
+    pub tick_label_interval: Option<f64>,
     pub label: &'static str,
     pub focused: bool,
     pub dragging: bool,
+    pub active_thumb: usize,
     pub style: SliderStyle,
 }
 
@@ -25,7 +50,10 @@ pub struct SliderStyle {
     pub track_bg: &'static str,
     pub track_fill: &'static str,
     pub knob_fill: &'static str,
-    pub font: &'static str,
+    pub tick: &'static str,
+    pub tooltip_fill: &'static str,
+    pub font: FontDescriptor,
+    pub tick_font: FontDescriptor,
 }
 
 impl Default for SliderStyle {
@@ -38,7 +66,10 @@ impl Default for SliderStyle {
             track_bg: "#1c2742",
             track_fill: "#27ffd8",
             knob_fill: "#f8fafc",
-            font: "600 14px Consolas",
+            tick: "#3b4768",
+            tooltip_fill: "#1f3b66",
+            font: FontDescriptor::new("Consolas", 14.0).weight(600),
+            tick_font: FontDescriptor::new("Consolas", 11.0),
         }
     }
 }
@@ -46,61 +77,249 @@ impl Default for SliderStyle {
 impl Slider {
     pub fn set_value(&mut self, value: f64) {
         self.value = self.clamp_and_snap(value);
+        if let Some(end) = self.range_end {
+            self.range_end = Some(end.max(self.value));
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn set_range(&mut self, start: f64, end: f64) {
+        let start = self.clamp_and_snap(start);
+        let end = self.clamp_and_snap(end);
+        self.value = start.min(end);
+        self.range_end = Some(start.max(end));
     }
 
     fn clamp_and_snap(&self, value: f64) -> f64 {
-        let range_min = self.min.min(self.max);
-        let range_max = self.max.max(self.min);
-        let step = self.step.abs().max(0.000_001);
-        let snapped = ((value - range_min) / step).round() * step + range_min;
-        snapped.clamp(range_min, range_max)
+        clamp_and_snap(value, self.min, self.max, self.step)
     }
 
-    fn ratio(&self) -> f64 {
+    fn ratio_of(&self, value: f64) -> f64 {
         let span = (self.max - self.min).abs();
         if span <= 0.000_001 {
             0.0
         } else {
-            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        }
+    }
+
+    fn thumb_values(&self) -> Vec<f64> {
+        match self.range_end {
+            Some(end) => vec![self.value, end],
+            None => vec![self.value],
+        }
+    }
+
+    fn thumb_value(&self, thumb: usize) -> f64 {
+        match (thumb, self.range_end) {
+            (1, Some(end)) => end,
+            _ => self.value,
         }
     }
 
     fn track_bounds(&self) -> (f64, f64) {
-        let start = self.rect.x + 10.0;
-        let end = self.rect.x + self.rect.width - 10.0;
-        (start, end.max(start))
+        let (start, end) = match self.orientation {
+            Orientation::Horizontal => (self.rect.x + 10.0, self.rect.x + self.rect.width - 10.0),
+            Orientation::Vertical => (self.rect.y + self.rect.height - 10.0, self.rect.y + 28.0),
+        };
+        match self.orientation {
+            Orientation::Horizontal => (start, end.max(start)),
+            Orientation::Vertical => (start, end.min(start)),
+        }
+    }
+
+    fn track_cross(&self) -> f64 {
+        match self.orientation {
+            Orientation::Horizontal if self.tick_label_interval.is_some() => self.rect.y + 30.0,
+            Orientation::Horizontal => self.rect.y + self.rect.height - 11.0,
+            Orientation::Vertical => self.rect.x + 18.0,
+        }
+    }
+
+    fn point_for(&self, value: f64) -> (f64, f64) {
+        let (start, end) = self.track_bounds();
+        let along = start + (end - start) * self.ratio_of(value);
+        match self.orientation {
+            Orientation::Horizontal => (along, self.track_cross()),
+            Orientation::Vertical => (self.track_cross(), along),
+        }
     }
 
-    fn value_from_pointer_x(&self, x: f64) -> f64 {
+    fn value_from_pointer(&self, x: f64, y: f64) -> f64 {
         let (start, end) = self.track_bounds();
-        let track_width = (end - start).max(0.000_001);
-        let ratio = ((x - start) / track_width).clamp(0.0, 1.0);
+        let position = match self.orientation {
+            Orientation::Horizontal => x,
+            Orientation::Vertical => y,
+        };
+        let length = end - start;
+        let ratio = if length.abs() <= 0.000_001 {
+            0.0
+        } else {
+            ((position - start) / length).clamp(0.0, 1.0)
+        };
         self.min + (self.max - self.min) * ratio
     }
 
+    fn nearest_thumb(&self, x: f64, y: f64) -> usize {
+        let Some(end) = self.range_end else {
+            return 0;
+        };
+        let target = self.value_from_pointer(x, y);
+        if (target - end).abs() < (target - self.value).abs() || (target >= end && end <= self.value) {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        let step = self.step.abs();
+        if step >= 1.0 || step == 0.0 {
+            format!("{:.0}", value)
+        } else {
+            let decimals = (-step.log10()).ceil().clamp(1.0, 4.0) as usize;
+            format!("{:.*}", decimals, value)
+        }
+    }
+
     fn emit_changed(&self) -> UiEvent {
-        UiEvent::ValueChanged {
-            key: self.key,
-            value: format!("{:.2}", self.value),
+        let value = match self.range_end {
+            Some(end) => format!("{:.2}..{:.2}", self.value, end),
+            None => format!("{:.2}", self.value),
+        };
+        UiEvent::ValueChanged { key: self.key, value }
+    }
+
+    fn set_thumb(&mut self, thumb: usize, value: f64) -> Option<UiEvent> {
+        let next = self.clamp_and_snap(value);
+        let (lower, upper) = match self.range_end {
+            Some(_) if thumb == 1 => (self.value, next.max(self.value)),
+            Some(end) => (next.min(end), end),
+            None => (next, next),
+        };
+        let changed = (lower - self.value).abs() > 0.000_001
+            || self.range_end.is_some_and(|end| (upper - end).abs() > 0.000_001);
+        if !changed {
+            return None;
         }
+        self.value = lower;
+        if self.range_end.is_some() {
+            self.range_end = Some(upper);
+        }
+        Some(self.emit_changed())
     }
 
     fn apply_delta(&mut self, delta: f64) -> Option<UiEvent> {
-        let next = self.clamp_and_snap(self.value + delta);
-        if (next - self.value).abs() > 0.000_001 {
-            self.value = next;
-            return Some(self.emit_changed());
+        let thumb = self.active_thumb;
+        self.set_thumb(thumb, self.thumb_value(thumb) + delta)
+    }
+
+    fn apply_pointer_value(&mut self, x: f64, y: f64) -> Option<UiEvent> {
+        let thumb = self.active_thumb;
+        self.set_thumb(thumb, self.value_from_pointer(x, y))
+    }
+
+    fn handle_keys(&mut self, pointer: &PointerState) -> Option<UiEvent> {
+        let step = self.step.abs();
+        let large = self.large_step.unwrap_or(step * 10.0).abs();
+        let thumb = self.active_thumb;
+        if pointer.move_left || pointer.move_down {
+            self.apply_delta(-step)
+        } else if pointer.move_right || pointer.move_up {
+            self.apply_delta(step)
+        } else if pointer.move_page_down || pointer.move_left_select || pointer.move_down_select {
+            self.apply_delta(-large)
+        } else if pointer.move_page_up || pointer.move_right_select || pointer.move_up_select {
+            self.apply_delta(large)
+        } else if pointer.move_home {
+            let floor = if thumb == 1 { self.value } else { self.min };
+            self.set_thumb(thumb, floor)
+        } else if pointer.move_end {
+            let ceiling = match self.range_end {
+                Some(end) if thumb == 0 => end,
+                _ => self.max,
+            };
+            self.set_thumb(thumb, ceiling)
+        } else {
+            None
         }
-        None
     }
 
-    fn apply_pointer_value(&mut self, x: f64) -> Option<UiEvent> {
-        let next = self.clamp_and_snap(self.value_from_pointer_x(x));
-        if (next - self.value).abs() > 0.000_001 {
-            self.value = next;
-            return Some(self.emit_changed());
+    fn tick_values(&self, interval: Option<f64>) -> Vec<f64> {
+        let Some(interval) = interval.map(f64::abs).filter(|interval| *interval > 0.000_001) else {
+            return Vec::new();
+        };
+        let low = self.min.min(self.max);
+        let high = self.max.max(self.min);
+        (0..=MAX_TICKS)
+            .map(|index| low + interval * index as f64)
+            .take_while(|value| *value <= high + 0.000_001)
+            .collect()
+    }
+
+    fn draw_ticks(&self, context: &CanvasRenderingContext2d) {
+        let ticks = self.tick_values(self.tick_interval);
+        let labels = self.tick_values(self.tick_label_interval);
+        if ticks.is_empty() && labels.is_empty() {
+            return;
         }
-        None
+        context.set_fill_style_str(self.style.tick);
+        for value in &ticks {
+            let (x, y) = self.point_for(*value);
+            match self.orientation {
+                Orientation::Horizontal => context.fill_rect(x - 0.5, y + 6.0, 1.0, 5.0),
+                Orientation::Vertical => context.fill_rect(x + 6.0, y - 0.5, 5.0, 1.0),
+            }
+        }
+        context.set_font(&self.style.tick_font.css());
+        context.set_fill_style_str(self.style.text);
+        context.set_text_baseline("middle");
+        context.set_text_align(match self.orientation {
+            Orientation::Horizontal => "center",
+            Orientation::Vertical => "left",
+        });
+        for value in &labels {
+            let (x, y) = self.point_for(*value);
+            let text = self.format_value(*value);
+            let _ = match self.orientation {
+                Orientation::Horizontal => context.fill_text(&text, x, y + 18.0),
+                Orientation::Vertical => context.fill_text(&text, x + 14.0, y),
+            };
+        }
+    }
+
+    fn push_tooltip(&self, context: &CanvasRenderingContext2d) {
+        let value = self.thumb_value(self.active_thumb);
+        let text = self.format_value(value);
+        let font = self.style.tick_font.css();
+        let width = cached_width(context, &font, &text) + 12.0;
+        let height = 20.0;
+        let (x, y) = self.point_for(value);
+        let rect = match self.orientation {
+            Orientation::Horizontal => Rect {
+                x: x - width * 0.5,
+                y: y - 14.0 - height,
+                width,
+                height,
+            },
+            Orientation::Vertical => Rect {
+                x: x - 14.0 - width,
+                y: y - height * 0.5,
+                width,
+                height,
+            },
+        };
+        let fill = self.style.tooltip_fill;
+        let color = self.style.text;
+        push_overlay(self.key, rect, move |context| {
+            context.set_fill_style_str(fill);
+            context.fill_rect(rect.x, rect.y, rect.width, rect.height);
+            context.set_font(&font);
+            context.set_fill_style_str(color);
+            context.set_text_align("center");
+            context.set_text_baseline("middle");
+            let _ = context.fill_text(&text, rect.x + rect.width * 0.5, rect.y + rect.height * 0.5);
+        });
     }
 }
 
@@ -114,50 +333,20 @@ impl Widget for Slider {
     }
 
     fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let pointer = pointer_for(self.key, pointer);
         let mut events = Vec::new();
 
         if self.focused {
-            if pointer.move_left || pointer.move_down {
-                if let Some(event) = self.apply_delta(-self.step.abs()) {
-                    events.push(event);
-                }
-            } else if pointer.move_right || pointer.move_up {
-                if let Some(event) = self.apply_delta(self.step.abs()) {
-                    events.push(event);
-                }
-            } else if pointer.move_page_down {
-                if let Some(event) = self.apply_delta(-(self.step.abs() * 10.0)) {
-                    events.push(event);
-                }
-            } else if pointer.move_page_up {
-                if let Some(event) = self.apply_delta(self.step.abs() * 10.0) {
-                    events.push(event);
-                }
-            } else if pointer.move_home {
-                let next = self.clamp_and_snap(self.min);
-                if (next - self.value).abs() > 0.000_001 {
-                    self.value = next;
-                    events.push(self.emit_changed());
-                }
-            } else if pointer.move_end {
-                let next = self.clamp_and_snap(self.max);
-                if (next - self.value).abs() > 0.000_001 {
-                    self.value = next;
-                    events.push(self.emit_changed());
-                }
-            }
+            events.extend(self.handle_keys(&pointer));
         }
 
         if pointer.just_pressed && self.rect.contains(pointer.x, pointer.y) {
             self.dragging = true;
-            if let Some(event) = self.apply_pointer_value(pointer.x) {
-                events.push(event);
-            }
+            self.active_thumb = self.nearest_thumb(pointer.x, pointer.y);
+            events.extend(self.apply_pointer_value(pointer.x, pointer.y));
         }
         if self.dragging && pointer.is_down {
-            if let Some(event) = self.apply_pointer_value(pointer.x) {
-                events.push(event);
-            }
+            events.extend(self.apply_pointer_value(pointer.x, pointer.y));
         }
         if pointer.just_released || pointer.cancel {
             self.dragging = false;
@@ -173,33 +362,57 @@ impl Widget for Slider {
         context.set_line_width(2.0);
         context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_fill_style_str(self.style.text);
         context.set_text_align("left");
         context.set_text_baseline("middle");
-        let _ = context.fill_text(
-            &format!("{}: {:.0}", self.label, self.value),
-            self.rect.x + 10.0,
-            self.rect.y + 12.0,
-        );
+        let summary = match self.range_end {
+            Some(end) => format!("{}: {}\u{2013}{}", self.label, self.format_value(self.value), self.format_value(end)),
+            None => format!("{}: {:.0}", self.label, self.value),
+        };
+        let _ = context.fill_text(&summary, self.rect.x + 10.0, self.rect.y + 12.0);
 
+        let track_thickness = 6.0;
         let (track_start, track_end) = self.track_bounds();
-        let track_y = self.rect.y + self.rect.height - 14.0;
-        let track_height = 6.0;
-        let width = (track_end - track_start).max(0.0);
-        let active_width = width * self.ratio();
+        let cross = self.track_cross() - track_thickness * 0.5;
+        let active_from = if self.range_end.is_some() {
+            self.point_for(self.value)
+        } else {
+            self.point_for(self.min)
+        };
+        let active_to = self.point_for(self.thumb_value(1));
 
         context.set_fill_style_str(self.style.track_bg);
-        context.fill_rect(track_start, track_y, width, track_height);
-        context.set_fill_style_str(self.style.track_fill);
-        context.fill_rect(track_start, track_y, active_width, track_height);
+        match self.orientation {
+            Orientation::Horizontal => {
+                context.fill_rect(track_start, cross, (track_end - track_start).max(0.0), track_thickness);
+                context.set_fill_style_str(self.style.track_fill);
+                context.fill_rect(active_from.0, cross, (active_to.0 - active_from.0).max(0.0), track_thickness);
+            }
+            Orientation::Vertical => {
+                context.fill_rect(cross, track_end, track_thickness, (track_start - track_end).max(0.0));
+                context.set_fill_style_str(self.style.track_fill);
+                context.fill_rect(cross, active_to.1, track_thickness, (active_from.1 - active_to.1).max(0.0));
+            }
+        }
+        self.draw_ticks(context);
 
-        let knob_x = track_start + active_width;
-        context.begin_path();
-        let _ = context.arc(knob_x, track_y + track_height * 0.5, 6.0, 0.0, std::f64::consts::PI * 2.0);
-        context.set_fill_style_str(self.style.knob_fill);
-        context.fill();
+        for (thumb, value) in self.thumb_values().into_iter().enumerate() {
+            let (x, y) = self.point_for(value);
+            context.begin_path();
+            let _ = context.arc(x, y, 6.0, 0.0, std::f64::consts::PI * 2.0);
+            context.set_fill_style_str(self.style.knob_fill);
+            context.fill();
+            if self.focused && self.range_end.is_some() && thumb == self.active_thumb {
+                context.set_stroke_style_str(self.style.focus_border);
+                context.set_line_width(2.0);
+                context.stroke();
+            }
+        }
 
+        if self.dragging {
+            self.push_tooltip(context);
+        }
         events
     }
 
@@ -211,7 +424,24 @@ impl Widget for Slider {
         self.focused = focused;
         if !focused {
             self.dragging = false;
+            self.active_thumb = 0;
+        }
+    }
+
+    fn focus_next_in_children(&mut self) -> bool {
+        if self.range_end.is_some() && self.active_thumb == 0 {
+            self.active_thumb = 1;
+            return true;
+        }
+        false
+    }
+
+    fn focus_prev_in_children(&mut self) -> bool {
+        if self.range_end.is_some() && self.active_thumb == 1 {
+            self.active_thumb = 0;
+            return true;
         }
+        false
     }
 
     fn as_any_mut(&mut self) -> &mut dyn Any {
@@ -0,0 +1,790 @@
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::navigation::step_clamped;
+use crate::core::selection::SelectionModel;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::{TextAlign, ellipsize};
+use crate::text::measure::cached_width;
+use crate::ui::tree::{SortDirection, UiEvent, Widget};
+use std::any::Any;
+use std::cmp::Ordering;
+use web_sys::CanvasRenderingContext2d;
+
+const RESIZE_GRIP: f64 = 5.0;
+const REORDER_THRESHOLD: f64 = 6.0;
+
+#[derive(Clone, PartialEq)]
+#[allow(dead_code)]
+pub enum CellValue {
+    Text(String),
+    Number { value: f64, precision: usize },
+    Badge { text: String, color: &'static str },
+    Progress(f64),
+}
+
+impl CellValue {
+    pub fn display(&self) -> String {
+        match self {
+            CellValue::Text(text) | CellValue::Badge { text, .. } => text.clone(),
+            CellValue::Number { value, precision } => format!("{:.*}", *precision, value),
+            CellValue::Progress(value) => format!("{:.0}%", value.clamp(0.0, 1.0) * 100.0),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            CellValue::Number { .. } | CellValue::Progress(_) => 0,
+            CellValue::Text(_) | CellValue::Badge { .. } => 1,
+        }
+    }
+
+    fn numeric(&self) -> Option<f64> {
+        match self {
+            CellValue::Number { value, .. } | CellValue::Progress(value) => Some(*value),
+            CellValue::Text(_) | CellValue::Badge { .. } => None,
+        }
+    }
+
+    pub fn compare(&self, other: &CellValue) -> Ordering {
+        match (self.numeric(), other.numeric()) {
+            (Some(left), Some(right)) => left.total_cmp(&right),
+            (None, None) => self.display().to_lowercase().cmp(&other.display().to_lowercase()),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+pub trait TableDataSource {
+    fn row_count(&self) -> usize;
+    fn cell(&self, row: usize, column: &'static str) -> CellValue;
+}
+
+pub type CellRenderer = fn(&CanvasRenderingContext2d, Rect, &CellValue, &TableColumn, &TableStyle);
+
+pub struct TableColumn {
+    pub key: &'static str,
+    pub title: &'static str,
+    pub width: f64,
+    pub min_width: f64,
+    pub align: TextAlign,
+    pub sortable: bool,
+    pub renderer: CellRenderer,
+}
+
+impl TableColumn {
+    pub fn new(key: &'static str, title: &'static str, width: f64) -> Self {
+        Self {
+            key,
+            title,
+            width,
+            min_width: 40.0,
+            align: TextAlign::Start,
+            sortable: true,
+            renderer: render_cell,
+        }
+    }
+
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn unsortable(mut self) -> Self {
+        self.sortable = false;
+        self
+    }
+
+    pub fn renderer(mut self, renderer: CellRenderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct TableStyle {
+    pub fill: &'static str,
+    pub border: &'static str,
+    pub focus_border: &'static str,
+    pub header_fill: &'static str,
+    pub header_text: &'static str,
+    pub divider: &'static str,
+    pub row_even: &'static str,
+    pub row_odd: &'static str,
+    pub row_selected: &'static str,
+    pub cell_focus: &'static str,
+    pub text: &'static str,
+    pub muted_text: &'static str,
+    pub drop_marker: &'static str,
+    pub font: FontDescriptor,
+    pub header_font: FontDescriptor,
+    pub cell_padding: f64,
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        Self {
+            fill: "#0f162a",
+            border: "#2a3350",
+            focus_border: "#27ffd8",
+            header_fill: "#1a2440",
+            header_text: "#9eb4ff",
+            divider: "#2a3350",
+            row_even: "#141d34",
+            row_odd: "#10182d",
+            row_selected: "#24406f",
+            cell_focus: "#27ffd8",
+            text: "#cfe0ff",
+            muted_text: "#6f7fa8",
+            drop_marker: "#ff2d2d",
+            font: FontDescriptor::new("Consolas", 13.0),
+            header_font: FontDescriptor::new("Consolas", 13.0).weight(600),
+            cell_padding: 8.0,
+        }
+    }
+}
+
+pub fn render_text_cell(
+    context: &CanvasRenderingContext2d,
+    rect: Rect,
+    value: &CellValue,
+    column: &TableColumn,
+    style: &TableStyle,
+) {
+    let font = style.font.css();
+    let inner_width = (rect.width - style.cell_padding * 2.0).max(0.0);
+    let text = ellipsize(context, &font, &value.display(), inner_width);
+    let (x, align) = match column.align {
+        TextAlign::Start => (rect.x + style.cell_padding, "left"),
+        TextAlign::Center => (rect.x + rect.width * 0.5, "center"),
+        TextAlign::End => (rect.x + rect.width - style.cell_padding, "right"),
+    };
+    context.set_font(&font);
+    context.set_fill_style_str(style.text);
+    context.set_text_align(align);
+    context.set_text_baseline("middle");
+    let _ = context.fill_text(&text, x, rect.y + rect.height * 0.5);
+}
+
+pub fn render_cell(
+    context: &CanvasRenderingContext2d,
+    rect: Rect,
+    value: &CellValue,
+    column: &TableColumn,
+    style: &TableStyle,
+) {
+    match value {
+        CellValue::Text(_) | CellValue::Number { .. } => {
+            render_text_cell(context, rect, value, column, style);
+        }
+        CellValue::Badge { text, color } => {
+            let font = style.font.css();
+            let inner_width = (rect.width - style.cell_padding * 2.0).max(0.0);
+            let text = ellipsize(context, &font, text, (inner_width - 12.0).max(0.0));
+            let badge_width = (cached_width(context, &font, &text) + 12.0).min(inner_width);
+            let badge_height = (rect.height - 10.0).max(0.0);
+            let badge_x = match column.align {
+                TextAlign::Start => rect.x + style.cell_padding,
+                TextAlign::Center => rect.x + (rect.width - badge_width) * 0.5,
+                TextAlign::End => rect.x + rect.width - style.cell_padding - badge_width,
+            };
+            let badge_y = rect.y + (rect.height - badge_height) * 0.5;
+            context.set_fill_style_str(color);
+            context.fill_rect(badge_x, badge_y, badge_width, badge_height);
+            context.set_font(&font);
+            context.set_fill_style_str("#080b13");
+            context.set_text_align("center");
+            context.set_text_baseline("middle");
+            let _ = context.fill_text(&text, badge_x + badge_width * 0.5, rect.y + rect.height * 0.5);
+        }
+        CellValue::Progress(progress) => {
+            let bar = Rect {
+                x: rect.x + style.cell_padding,
+                y: rect.y + rect.height * 0.5 - 4.0,
+                width: (rect.width - style.cell_padding * 2.0).max(0.0),
+                height: 8.0,
+            };
+            context.set_fill_style_str(style.divider);
+            context.fill_rect(bar.x, bar.y, bar.width, bar.height);
+            context.set_fill_style_str(style.focus_border);
+            context.fill_rect(bar.x, bar.y, bar.width * progress.clamp(0.0, 1.0), bar.height);
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum HeaderDrag {
+    Resize {
+        column: usize,
+        press_x: f64,
+        start_width: f64,
+    },
+    Move {
+        column: usize,
+        press_x: f64,
+        moving: bool,
+    },
+}
+
+pub struct Table {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub columns: Vec<TableColumn>,
+    pub source: Box<dyn TableDataSource>,
+    pub row_height: f64,
+    pub header_height: f64,
+    pub scroll_x: f64,
+    pub scroll_y: f64,
+    pub selection: SelectionModel,
+    pub style: TableStyle,
+    pub focused: bool,
+    sort: Option<(&'static str, SortDirection)>,
+    order: Vec<usize>,
+    order_stale: bool,
+    focused_column: usize,
+    header_drag: Option<HeaderDrag>,
+}
+
+impl Table {
+    pub fn new(
+        key: &'static str,
+        columns: Vec<TableColumn>,
+        source: Box<dyn TableDataSource>,
+        selection: SelectionModel,
+    ) -> Self {
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            columns,
+            source,
+            row_height: 28.0,
+            header_height: 32.0,
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            selection,
+            style: TableStyle::default(),
+            focused: false,
+            sort: None,
+            order: Vec::new(),
+            order_stale: true,
+            focused_column: 0,
+            header_drag: None,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn set_source(&mut self, source: Box<dyn TableDataSource>) {
+        self.source = source;
+        self.selection.clear();
+        self.invalidate();
+    }
+
+    pub fn invalidate(&mut self) {
+        self.order_stale = true;
+    }
+
+    #[allow(dead_code)]
+    pub fn sort(&self) -> Option<(&'static str, SortDirection)> {
+        self.sort
+    }
+
+    pub fn set_sort(&mut self, sort: Option<(&'static str, SortDirection)>) {
+        if self.sort != sort {
+            self.sort = sort;
+            self.invalidate();
+        }
+    }
+
+    pub fn source_row(&self, view_row: usize) -> Option<usize> {
+        self.order.get(view_row).copied()
+    }
+
+    fn sync_order(&mut self) {
+        let count = self.source.row_count();
+        if !self.order_stale && self.order.len() == count {
+            return;
+        }
+        self.order_stale = false;
+
+        let previous = std::mem::take(&mut self.order);
+        let mut order = (0..count).collect::<Vec<_>>();
+        if let Some((column, direction)) = self.sort {
+            let keys = (0..count)
+                .map(|row| self.source.cell(row, column))
+                .collect::<Vec<_>>();
+            order.sort_by(|left, right| {
+                let ordering = keys[*left].compare(&keys[*right]);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        let mut view_of = vec![None; count];
+        for (view_row, source_row) in order.iter().enumerate() {
+            view_of[*source_row] = Some(view_row);
+        }
+        self.selection.remap(|view_row| {
+            previous
+                .get(view_row)
+                .and_then(|source_row| view_of.get(*source_row).copied().flatten())
+        });
+        self.order = order;
+    }
+
+    fn row_count(&self) -> usize {
+        self.order.len()
+    }
+
+    fn total_width(&self) -> f64 {
+        self.columns.iter().map(|column| column.width).sum()
+    }
+
+    fn body_rect(&self) -> Rect {
+        Rect {
+            x: self.rect.x,
+            y: self.rect.y + self.header_height,
+            width: self.rect.width,
+            height: (self.rect.height - self.header_height).max(0.0),
+        }
+    }
+
+    fn max_scroll(&self) -> (f64, f64) {
+        (
+            (self.total_width() - self.rect.width).max(0.0),
+            (self.row_count() as f64 * self.row_height - self.body_rect().height).max(0.0),
+        )
+    }
+
+    fn column_x(&self, index: usize) -> f64 {
+        self.rect.x - self.scroll_x
+            + self.columns[..index]
+                .iter()
+                .map(|column| column.width)
+                .sum::<f64>()
+    }
+
+    fn column_at(&self, x: f64) -> Option<usize> {
+        (0..self.columns.len()).find(|index| {
+            let left = self.column_x(*index);
+            x >= left && x < left + self.columns[*index].width
+        })
+    }
+
+    fn resize_grip_at(&self, x: f64) -> Option<usize> {
+        (0..self.columns.len()).find(|index| {
+            let right = self.column_x(*index) + self.columns[*index].width;
+            (x - right).abs() <= RESIZE_GRIP
+        })
+    }
+
+    fn drop_index(&self, x: f64) -> usize {
+        (0..self.columns.len())
+            .find(|index| x < self.column_x(*index) + self.columns[*index].width * 0.5)
+            .unwrap_or(self.columns.len())
+    }
+
+    fn cycle_sort(&mut self, column: usize) -> Option<UiEvent> {
+        if !self.columns[column].sortable {
+            return None;
+        }
+        let key = self.columns[column].key;
+        let direction = match self.sort {
+            Some((current, SortDirection::Ascending)) if current == key => Some(SortDirection::Descending),
+            Some((current, SortDirection::Descending)) if current == key => None,
+            _ => Some(SortDirection::Ascending),
+        };
+        self.set_sort(direction.map(|direction| (key, direction)));
+        Some(UiEvent::SortChanged {
+            key: self.key,
+            column: key,
+            direction,
+        })
+    }
+
+    fn move_column(&mut self, from: usize, to: usize) {
+        let target = if to > from { to - 1 } else { to };
+        if target == from {
+            return;
+        }
+        let focused_key = self.columns.get(self.focused_column).map(|column| column.key);
+        let column = self.columns.remove(from);
+        self.columns.insert(target, column);
+        if let Some(position) = focused_key.and_then(|key| self.columns.iter().position(|column| column.key == key)) {
+            self.focused_column = position;
+        }
+    }
+
+    fn ensure_visible(&mut self) {
+        let body = self.body_rect();
+        if let Some(row) = self.selection.lead() {
+            let top = row as f64 * self.row_height;
+            if top < self.scroll_y {
+                self.scroll_y = top;
+            } else if top + self.row_height > self.scroll_y + body.height {
+                self.scroll_y = top + self.row_height - body.height;
+            }
+        }
+        if self.focused_column < self.columns.len() {
+            let left = self.column_x(self.focused_column) - self.rect.x + self.scroll_x;
+            let right = left + self.columns[self.focused_column].width;
+            if left < self.scroll_x {
+                self.scroll_x = left;
+            } else if right > self.scroll_x + self.rect.width {
+                self.scroll_x = right - self.rect.width;
+            }
+        }
+        let (max_x, max_y) = self.max_scroll();
+        self.scroll_x = self.scroll_x.clamp(0.0, max_x);
+        self.scroll_y = self.scroll_y.clamp(0.0, max_y);
+    }
+
+    fn update_selection(&mut self, update: impl FnOnce(&mut SelectionModel) -> bool) -> Vec<UiEvent> {
+        let changed = update(&mut self.selection);
+        self.ensure_visible();
+        if !changed {
+            return Vec::new();
+        }
+        vec![UiEvent::SelectionChanged {
+            key: self.key,
+            indices: self
+                .selection
+                .indices()
+                .into_iter()
+                .filter_map(|view_row| self.source_row(view_row))
+                .collect(),
+        }]
+    }
+
+    fn page_rows(&self) -> isize {
+        (self.body_rect().height / self.row_height.max(1.0)).floor().max(1.0) as isize
+    }
+
+    fn handle_keys(&mut self, pointer: &PointerState) -> Vec<UiEvent> {
+        let count = self.row_count();
+        let target = if pointer.move_up {
+            step_clamped(self.selection.lead(), -1, count)
+        } else if pointer.move_down {
+            step_clamped(self.selection.lead(), 1, count)
+        } else if pointer.move_page_up {
+            step_clamped(self.selection.lead(), -self.page_rows(), count)
+        } else if pointer.move_page_down {
+            step_clamped(self.selection.lead(), self.page_rows(), count)
+        } else if pointer.move_home {
+            (count > 0).then_some(0)
+        } else if pointer.move_end {
+            count.checked_sub(1)
+        } else {
+            None
+        };
+        if let Some(row) = target {
+            return self.update_selection(|selection| selection.select_only(row));
+        }
+
+        let extend = if pointer.move_up_select {
+            step_clamped(self.selection.lead(), -1, count)
+        } else if pointer.move_down_select {
+            step_clamped(self.selection.lead(), 1, count)
+        } else {
+            None
+        };
+        if let Some(row) = extend {
+            return self.update_selection(|selection| selection.extend_to(row, false));
+        }
+
+        if pointer.move_left || pointer.move_right {
+            let delta = if pointer.move_left { -1 } else { 1 };
+            if let Some(column) = step_clamped(Some(self.focused_column), delta, self.columns.len()) {
+                self.focused_column = column;
+                self.ensure_visible();
+            }
+        } else if pointer.select_all {
+            return self.update_selection(|selection| selection.select_all(count));
+        }
+        Vec::new()
+    }
+
+    fn handle_header(&mut self, pointer: &PointerState) -> Vec<UiEvent> {
+        let header = Rect {
+            height: self.header_height,
+            ..self.rect
+        };
+        if pointer.just_pressed && header.contains(pointer.x, pointer.y) {
+            self.header_drag = if let Some(column) = self.resize_grip_at(pointer.x) {
+                Some(HeaderDrag::Resize {
+                    column,
+                    press_x: pointer.x,
+                    start_width: self.columns[column].width,
+                })
+            } else {
+                self.column_at(pointer.x).map(|column| HeaderDrag::Move {
+                    column,
+                    press_x: pointer.x,
+                    moving: false,
+                })
+            };
+        }
+
+        let mut events = Vec::new();
+        match self.header_drag {
+            Some(HeaderDrag::Resize {
+                column,
+                press_x,
+                start_width,
+            }) => {
+                let min_width = self.columns[column].min_width;
+                self.columns[column].width = (start_width + pointer.x - press_x).max(min_width);
+            }
+            Some(HeaderDrag::Move {
+                column,
+                press_x,
+                moving,
+            }) => {
+                let moving = moving || (pointer.x - press_x).abs() > REORDER_THRESHOLD;
+                self.header_drag = Some(HeaderDrag::Move {
+                    column,
+                    press_x,
+                    moving,
+                });
+                if pointer.just_released {
+                    if moving {
+                        let to = self.drop_index(pointer.x);
+                        self.move_column(column, to);
+                    } else {
+                        self.focused_column = column;
+                        events.extend(self.cycle_sort(column));
+                    }
+                }
+            }
+            None => {}
+        }
+        if pointer.just_released || !pointer.is_down {
+            self.header_drag = None;
+        }
+        events
+    }
+
+    fn draw_rows(&self, context: &CanvasRenderingContext2d) {
+        let body = self.body_rect();
+        context.save();
+        context.begin_path();
+        context.rect(body.x, body.y, body.width, body.height);
+        context.clip();
+
+        let count = self.row_count();
+        let first = (self.scroll_y / self.row_height.max(1.0)).floor() as usize;
+        let last = (((self.scroll_y + body.height) / self.row_height.max(1.0)).ceil() as usize).min(count);
+        for view_row in first..last {
+            let y = body.y + view_row as f64 * self.row_height - self.scroll_y;
+            context.set_fill_style_str(if self.selection.is_selected(view_row) {
+                self.style.row_selected
+            } else if view_row % 2 == 0 {
+                self.style.row_even
+            } else {
+                self.style.row_odd
+            });
+            context.fill_rect(body.x, y, body.width, self.row_height);
+
+            let Some(source_row) = self.source_row(view_row) else {
+                continue;
+            };
+            for (index, column) in self.columns.iter().enumerate() {
+                let cell = Rect {
+                    x: self.column_x(index),
+                    y,
+                    width: column.width,
+                    height: self.row_height,
+                };
+                if cell.x + cell.width < body.x || cell.x > body.x + body.width {
+                    continue;
+                }
+                context.save();
+                context.begin_path();
+                context.rect(cell.x, cell.y, cell.width, cell.height);
+                context.clip();
+                (column.renderer)(context, cell, &self.source.cell(source_row, column.key), column, &self.style);
+                context.restore();
+
+                if self.focused && self.selection.lead() == Some(view_row) && index == self.focused_column {
+                    context.set_stroke_style_str(self.style.cell_focus);
+                    context.set_line_width(1.5);
+                    context.stroke_rect(cell.x + 1.0, cell.y + 1.0, cell.width - 2.0, cell.height - 2.0);
+                }
+            }
+        }
+
+        if count == 0 {
+            context.set_font(&self.style.font.css());
+            context.set_fill_style_str(self.style.muted_text);
+            context.set_text_align("center");
+            context.set_text_baseline("middle");
+            let _ = context.fill_text("No rows", body.x + body.width * 0.5, body.y + self.row_height * 0.5);
+        }
+        context.restore();
+    }
+
+    fn draw_header(&self, context: &CanvasRenderingContext2d, pointer: &PointerState) {
+        context.save();
+        context.begin_path();
+        context.rect(self.rect.x, self.rect.y, self.rect.width, self.header_height);
+        context.clip();
+        context.set_fill_style_str(self.style.header_fill);
+        context.fill_rect(self.rect.x, self.rect.y, self.rect.width, self.header_height);
+
+        let font = self.style.header_font.css();
+        for (index, column) in self.columns.iter().enumerate() {
+            let x = self.column_x(index);
+            let arrow = match self.sort {
+                Some((key, SortDirection::Ascending)) if key == column.key => " ▲",
+                Some((key, SortDirection::Descending)) if key == column.key => " ▼",
+                _ => "",
+            };
+            let title = ellipsize(
+                context,
+                &font,
+                &format!("{}{}", column.title, arrow),
+                (column.width - self.style.cell_padding * 2.0).max(0.0),
+            );
+            context.set_font(&font);
+            context.set_fill_style_str(self.style.header_text);
+            context.set_text_baseline("middle");
+            let center_y = self.rect.y + self.header_height * 0.5;
+            let _ = match column.align {
+                TextAlign::Start => {
+                    context.set_text_align("left");
+                    context.fill_text(&title, x + self.style.cell_padding, center_y)
+                }
+                TextAlign::Center => {
+                    context.set_text_align("center");
+                    context.fill_text(&title, x + column.width * 0.5, center_y)
+                }
+                TextAlign::End => {
+                    context.set_text_align("right");
+                    context.fill_text(&title, x + column.width - self.style.cell_padding, center_y)
+                }
+            };
+            context.set_fill_style_str(self.style.divider);
+            context.fill_rect(x + column.width - 1.0, self.rect.y + 6.0, 1.0, self.header_height - 12.0);
+        }
+
+        if let Some(HeaderDrag::Move {
+            column,
+            moving: true,
+            ..
+        }) = self.header_drag
+        {
+            let marker_index = self.drop_index(pointer.x);
+            let marker_x = if marker_index < self.columns.len() {
+                self.column_x(marker_index)
+            } else {
+                self.column_x(self.columns.len() - 1) + self.columns[self.columns.len() - 1].width
+            };
+            context.set_fill_style_str(self.style.drop_marker);
+            context.fill_rect(marker_x - 1.0, self.rect.y, 2.0, self.rect.height.min(self.header_height + 4.0));
+
+            context.set_global_alpha(0.6);
+            context.set_fill_style_str(self.style.row_selected);
+            context.fill_rect(
+                pointer.x - self.columns[column].width * 0.5,
+                self.rect.y,
+                self.columns[column].width,
+                self.header_height,
+            );
+            context.set_global_alpha(1.0);
+        }
+
+        context.set_fill_style_str(self.style.divider);
+        context.fill_rect(self.rect.x, self.rect.y + self.header_height - 1.0, self.rect.width, 1.0);
+        context.restore();
+    }
+}
+
+impl Widget for Table {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        self.sync_order();
+
+        let mut events = Vec::new();
+        let hovered = self.rect.contains(pointer.x, pointer.y);
+        if hovered && (pointer.scroll_x != 0.0 || pointer.scroll_y != 0.0) {
+            self.scroll_x += pointer.scroll_x;
+            self.scroll_y += pointer.scroll_y * 0.75;
+        }
+
+        events.extend(self.handle_header(pointer));
+
+        let body = self.body_rect();
+        if pointer.just_released && self.header_drag.is_none() && body.contains(pointer.x, pointer.y) {
+            let view_row = ((pointer.y - body.y + self.scroll_y) / self.row_height.max(1.0)).floor() as usize;
+            if view_row < self.row_count() {
+                if let Some(column) = self.column_at(pointer.x) {
+                    self.focused_column = column;
+                }
+                let modifiers = pointer.modifiers;
+                events.extend(self.update_selection(|selection| selection.click(view_row, modifiers)));
+            }
+        }
+
+        if self.focused {
+            events.extend(self.handle_keys(pointer));
+        }
+
+        let (max_x, max_y) = self.max_scroll();
+        self.scroll_x = self.scroll_x.clamp(0.0, max_x);
+        self.scroll_y = self.scroll_y.clamp(0.0, max_y);
+        self.focused_column = self.focused_column.min(self.columns.len().saturating_sub(1));
+
+        context.set_fill_style_str(self.style.fill);
+        context.fill_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        self.draw_rows(context);
+        self.draw_header(context, pointer);
+        context.set_stroke_style_str(if self.focused {
+            self.style.focus_border
+        } else {
+            self.style.border
+        });
+        context.set_line_width(2.0);
+        context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        let row = self.selection.lead().and_then(|row| self.source_row(row))?;
+        let column = self.columns.get(self.focused_column)?;
+        Some(UiEvent::CellActivated {
+            key: self.key,
+            row,
+            column: column.key,
+        })
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        let (max_x, max_y) = self.max_scroll();
+        self.rect.contains(x, y) && (max_x > 0.0 || max_y > 0.0)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
-use crate::core::navigation::{find_next_prefix, step_wrapped};
+use crate::core::navigation::{TypeAhead, find_next_prefix, step_wrapped};
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -11,6 +12,7 @@ pub struct Tabs {
     pub options: Vec<String>,
     pub selected: usize,
     pub focused: bool,
+    pub type_ahead: TypeAhead,
     pub style: TabsStyle,
 }
 
@@ -21,7 +23,7 @@ pub struct TabsStyle {
     pub active_fill: &'static str,
     pub text: &'static str,
     pub active_text: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
 }
 
 impl Default for TabsStyle {
@@ -33,7 +35,7 @@ impl Default for TabsStyle {
             active_fill: "#22375e",
             text: "#9fb4e4",
             active_text: "#e5efff",
-            font: "600 14px Consolas",
+            font: FontDescriptor::new("Consolas", 14.0).weight(600),
         }
     }
 }
@@ -71,8 +73,11 @@ impl Tabs {
         None
     }
 
-    fn jump_to(&mut self, text: &str) -> Option<UiEvent> {
-        if let Some(index) = find_next_prefix(&self.options, text, Some(self.selected)) {
+    fn jump_to(&mut self, text: &str, time_ms: f64) -> Option<UiEvent> {
+        if let Some(index) = self
+            .type_ahead
+            .search(&self.options, text, time_ms, Some(self.selected), find_next_prefix)
+        {
             if index != self.selected {
                 self.selected = index;
                 return Some(self.emit_changed());
@@ -115,7 +120,7 @@ impl Widget for Tabs {
                 }
             }
             if let Some(input) = &pointer.text_input {
-                if let Some(event) = self.jump_to(input) {
+                if let Some(event) = self.jump_to(input, pointer.time_ms) {
                     events.push(event);
                 }
             }
@@ -133,7 +138,7 @@ impl Widget for Tabs {
 
         let count = self.options.len().max(1) as f64;
         let tab_width = self.rect.width / count;
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_text_baseline("middle");
         context.set_text_align("center");
 
@@ -178,6 +183,9 @@ impl Widget for Tabs {
     }
 
     fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.type_ahead.clear();
+        }
         self.focused = focused;
     }
 
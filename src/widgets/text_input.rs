@@ -1,8 +1,13 @@
 use crate::core::geometry::Rect;
+use crate::core::history::{EditHistory, EditKind};
 use crate::core::input::PointerState;
+use crate::core::validation::{first_error, InputMask, Validator};
+use crate::text::font::FontDescriptor;
+use crate::text::measure::{shape_text, ShapedText};
 use crate::ui::tree::{UiEvent, Widget};
 use std::any::Any;
 use std::cell::RefCell;
+use std::rc::Rc;
 use web_sys::CanvasRenderingContext2d;
 
 thread_local! {
@@ -19,6 +24,36 @@ pub struct TextInput {
     pub cursor: usize,
     pub selection_anchor: Option<usize>,
     pub dragging_selection: bool,
+    pub scroll_x: f64,
+    pub history: EditHistory<TextInputSnapshot>,
+    pub behavior: TextInputBehavior,
+    pub reported_validation: Option<Option<String>>,
+    shaped: Option<ShapedValue>,
+}
+
+struct ShapedValue {
+    value: String,
+    font: Rc<str>,
+    obscured: bool,
+    text: Rc<ShapedText>,
+}
+
+#[derive(Default)]
+pub struct TextInputBehavior {
+    pub obscured: bool,
+    pub max_length: Option<usize>,
+    pub allowed_chars: Option<fn(char) -> bool>,
+    pub mask: Option<InputMask>,
+    pub formatter: Option<fn(&str) -> String>,
+    pub validators: Vec<Validator>,
+}
+
+
+#[derive(Clone)]
+pub struct TextInputSnapshot {
+    pub value: String,
+    pub cursor: usize,
+    pub selection_anchor: Option<usize>,
 }
 
 pub struct TextInputStyle {
@@ -27,7 +62,7 @@ pub struct TextInputStyle {
     pub focus_border: &'static str,
     pub text: &'static str,
     pub placeholder: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
     pub padding_x: f64,
 }
 
@@ -39,27 +74,106 @@ impl Default for TextInputStyle {
             focus_border: "#27ffd8",
             text: "#d8e3ff",
             placeholder: "#6f7fa8",
-            font: "600 16px Consolas",
+            font: FontDescriptor::new("Consolas", 16.0).weight(600),
             padding_x: 10.0,
         }
     }
 }
 
 impl TextInput {
+    pub fn new(key: &'static str, placeholder: &'static str) -> Self {
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 44.0,
+            },
+            value: String::new(),
+            placeholder,
+            style: TextInputStyle::default(),
+            focused: false,
+            cursor: 0,
+            selection_anchor: None,
+            dragging_selection: false,
+            scroll_x: 0.0,
+            history: EditHistory::default(),
+            behavior: TextInputBehavior::default(),
+            reported_validation: None,
+            shaped: None,
+        }
+    }
+
+    pub fn behavior(mut self, behavior: TextInputBehavior) -> Self {
+        self.behavior = behavior;
+        self
+    }
+
     pub fn set_value(&mut self, value: String) {
         if self.value != value {
             self.value = value;
             self.cursor = self.value.len();
+            self.history.clear();
         } else if self.cursor > self.value.len() {
             self.cursor = self.value.len();
         }
     }
 
+    fn snapshot(&self) -> TextInputSnapshot {
+        TextInputSnapshot {
+            value: self.value.clone(),
+            cursor: self.cursor,
+            selection_anchor: self.selection_anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: TextInputSnapshot) {
+        self.value = snapshot.value;
+        self.cursor = snapshot.cursor.min(self.value.len());
+        self.selection_anchor = snapshot
+            .selection_anchor
+            .map(|anchor| anchor.min(self.value.len()));
+    }
+
+    fn record_edit(&mut self, kind: EditKind) {
+        let kind = if self.selection_range().is_some() {
+            EditKind::Replace
+        } else {
+            kind
+        };
+        self.history.record(self.snapshot(), kind);
+    }
+
+    fn undo(&mut self) -> bool {
+        match self.history.undo(self.snapshot()) {
+            Some(previous) => {
+                self.restore(previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn redo(&mut self) -> bool {
+        match self.history.redo(self.snapshot()) {
+            Some(next) => {
+                self.restore(next);
+                true
+            }
+            None => false,
+        }
+    }
+
     fn prev_char_boundary(&self) -> usize {
-        if self.cursor == 0 {
+        self.prev_char_boundary_from(self.cursor)
+    }
+
+    fn prev_char_boundary_from(&self, index: usize) -> usize {
+        if index == 0 {
             0
         } else {
-            self.value[..self.cursor]
+            self.value[..index]
                 .char_indices()
                 .last()
                 .map(|(index, _)| index)
@@ -67,6 +181,88 @@ impl TextInput {
         }
     }
 
+    fn accepts_char(&self, ch: char) -> bool {
+        !ch.is_control()
+            && self.behavior.allowed_chars.map(|allowed| allowed(ch)).unwrap_or(true)
+            && self
+                .behavior
+                .mask
+                .map(|mask| mask.accepts_any_slot(ch))
+                .unwrap_or(true)
+    }
+
+    fn filter_insert(&self, text: &str) -> String {
+        let accepted = text.chars().filter(|ch| self.accepts_char(*ch));
+        match self.behavior.max_length {
+            Some(max) => {
+                let selected = self
+                    .selection_range()
+                    .map(|(start, end)| self.value[start..end].chars().count())
+                    .unwrap_or(0);
+                let remaining = max.saturating_sub(self.value.chars().count() - selected);
+                accepted.take(remaining).collect()
+            }
+            None => accepted.collect(),
+        }
+    }
+
+    fn insert_text(&mut self, text: &str) {
+        let _ = self.delete_selection_if_any();
+        self.value.insert_str(self.cursor, text);
+        self.cursor += text.len();
+        self.clear_selection();
+        self.apply_format();
+    }
+
+    fn apply_format(&mut self) {
+        if let Some(mask) = self.behavior.mask {
+            let (value, cursor) = mask.apply(&self.value, self.cursor);
+            self.value = value;
+            self.cursor = cursor;
+        }
+        if let Some(formatter) = self.behavior.formatter {
+            let formatted = formatter(&self.value);
+            if formatted != self.value {
+                self.value = formatted;
+                self.cursor = self.cursor.min(self.value.len());
+                while !self.value.is_char_boundary(self.cursor) {
+                    self.cursor -= 1;
+                }
+            }
+        }
+    }
+
+    fn backspace_start(&self) -> usize {
+        let mut start = self.prev_char_boundary();
+        if let Some(mask) = self.behavior.mask {
+            while start > 0 && mask.is_literal_at(self.value[..start].chars().count()) {
+                start = self.prev_char_boundary_from(start);
+            }
+        }
+        start
+    }
+
+    fn display_prefix(&self, end: usize) -> String {
+        let prefix = &self.value[..end.min(self.value.len())];
+        if self.behavior.obscured {
+            "\u{2022}".repeat(prefix.chars().count())
+        } else {
+            prefix.to_string()
+        }
+    }
+
+    fn validation_event(&mut self) -> Option<UiEvent> {
+        let error = first_error(&self.behavior.validators, &self.value);
+        if self.reported_validation.as_ref() == Some(&error) {
+            return None;
+        }
+        self.reported_validation = Some(error.clone());
+        Some(UiEvent::Validation {
+            key: self.key,
+            error,
+        })
+    }
+
     fn next_char_boundary(&self) -> usize {
         if self.cursor >= self.value.len() {
             self.value.len()
@@ -171,28 +367,91 @@ impl TextInput {
             self.value.replace_range(start..end, "");
             self.cursor = start;
             self.clear_selection();
+            self.apply_format();
             true
         } else {
             false
         }
     }
 
-    fn set_cursor_from_x(&mut self, context: &CanvasRenderingContext2d, x: f64) {
-        let mut best = 0usize;
-        let mut best_distance = f64::MAX;
-        for index in self.value.char_indices().map(|(i, _)| i).chain(std::iter::once(self.value.len())) {
-            let width = context
-                .measure_text(&self.value[..index])
-                .ok()
-                .map(|metrics| metrics.width())
-                .unwrap_or(0.0);
-            let distance = (width - x).abs();
-            if distance < best_distance {
-                best_distance = distance;
-                best = index;
-            }
+    fn refresh_shaped(&mut self, context: &CanvasRenderingContext2d) {
+        let font = self.style.font.css();
+        if let Some(shaped) = &self.shaped
+            && shaped.value == self.value
+            && shaped.font == font
+            && shaped.obscured == self.behavior.obscured
+        {
+            return;
         }
-        self.cursor = best;
+        let text = shape_text(context, &font, &self.display_prefix(self.value.len()));
+        self.shaped = Some(ShapedValue {
+            value: self.value.clone(),
+            font,
+            obscured: self.behavior.obscured,
+            text,
+        });
+    }
+
+    fn shaped(&self) -> Option<&ShapedText> {
+        self.shaped.as_ref().map(|shaped| shaped.text.as_ref())
+    }
+
+    fn prefix_width(&self, end: usize) -> f64 {
+        let Some(shaped) = self.shaped() else {
+            return 0.0;
+        };
+        let end = end.min(self.value.len());
+        if self.behavior.obscured {
+            shaped.char_advance(self.value[..end].chars().count())
+        } else {
+            shaped.prefix_width(end)
+        }
+    }
+
+    fn visible_text_width(&self) -> f64 {
+        (self.rect.width - self.style.padding_x * 2.0).max(0.0)
+    }
+
+    fn text_origin_x(&self) -> f64 {
+        self.rect.x + self.style.padding_x - self.scroll_x
+    }
+
+    fn autoscroll_while_dragging(&mut self, x: f64) {
+        let left = self.rect.x + self.style.padding_x;
+        let right = left + self.visible_text_width();
+        if x < left {
+            self.scroll_x -= (left - x).min(24.0);
+        } else if x > right {
+            self.scroll_x += (x - right).min(24.0);
+        }
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let visible = self.visible_text_width();
+        let total = self.shaped().map_or(0.0, ShapedText::width);
+        let caret = self.prefix_width(self.cursor);
+        if caret - self.scroll_x > visible {
+            self.scroll_x = caret - visible;
+        } else if caret < self.scroll_x {
+            self.scroll_x = caret;
+        }
+        self.scroll_x = self.scroll_x.clamp(0.0, (total - visible).max(0.0));
+    }
+
+    fn set_cursor_from_x(&mut self, x: f64) {
+        let Some(shaped) = self.shaped.as_ref().map(|shaped| Rc::clone(&shaped.text)) else {
+            return;
+        };
+        let char_index = shaped.nearest_char(x);
+        self.cursor = if self.behavior.obscured {
+            self.value
+                .char_indices()
+                .nth(char_index)
+                .map(|(index, _)| index)
+                .unwrap_or(self.value.len())
+        } else {
+            shaped.char_offset(char_index)
+        };
     }
 }
 
@@ -207,6 +466,7 @@ impl Widget for TextInput {
 
     fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
         let mut events = Vec::new();
+        self.refresh_shaped(context);
 
         if self.focused {
             if pointer.select_all {
@@ -215,16 +475,16 @@ impl Widget for TextInput {
             }
 
             if pointer.just_pressed && self.rect.contains(pointer.x, pointer.y) {
-                context.set_font(self.style.font);
-                let text_x = self.rect.x + self.style.padding_x;
-                self.set_cursor_from_x(context, (pointer.x - text_x).max(0.0));
+                let text_x = self.text_origin_x();
+                self.set_cursor_from_x((pointer.x - text_x).max(0.0));
                 self.selection_anchor = Some(self.cursor);
                 self.dragging_selection = true;
+                self.history.seal();
             }
             if pointer.is_down && self.dragging_selection {
-                context.set_font(self.style.font);
-                let text_x = self.rect.x + self.style.padding_x;
-                self.set_cursor_from_x(context, (pointer.x - text_x).max(0.0));
+                self.autoscroll_while_dragging(pointer.x);
+                let text_x = self.text_origin_x();
+                self.set_cursor_from_x((pointer.x - text_x).max(0.0));
             }
             if pointer.just_released && self.dragging_selection {
                 self.dragging_selection = false;
@@ -233,6 +493,19 @@ impl Widget for TextInput {
                 }
             }
 
+            if pointer.move_home
+                || pointer.move_end
+                || pointer.move_left
+                || pointer.move_right
+                || pointer.move_left_select
+                || pointer.move_right_select
+                || pointer.move_word_left
+                || pointer.move_word_right
+                || pointer.move_word_left_select
+                || pointer.move_word_right_select
+            {
+                self.history.seal();
+            }
             if pointer.move_home {
                 self.cursor = 0;
                 self.clear_selection();
@@ -282,15 +555,20 @@ impl Widget for TextInput {
                 self.clear_selection();
             }
             if pointer.backspace {
+                if self.selection_range().is_some() {
+                    self.record_edit(EditKind::Delete);
+                }
                 if self.delete_selection_if_any() {
                     events.push(UiEvent::ValueChanged {
                         key: self.key,
                         value: self.value.clone(),
                     });
                 } else if self.cursor > 0 {
-                    let start = self.prev_char_boundary();
+                    self.record_edit(EditKind::Delete);
+                    let start = self.backspace_start();
                     self.value.replace_range(start..self.cursor, "");
                     self.cursor = start;
+                    self.apply_format();
                     events.push(UiEvent::ValueChanged {
                         key: self.key,
                         value: self.value.clone(),
@@ -298,14 +576,19 @@ impl Widget for TextInput {
                 }
             }
             if pointer.delete_forward {
+                if self.selection_range().is_some() {
+                    self.record_edit(EditKind::Delete);
+                }
                 if self.delete_selection_if_any() {
                     events.push(UiEvent::ValueChanged {
                         key: self.key,
                         value: self.value.clone(),
                     });
                 } else if self.cursor < self.value.len() {
+                    self.record_edit(EditKind::Delete);
                     let end = self.next_char_boundary();
                     self.value.replace_range(self.cursor..end, "");
+                    self.apply_format();
                     events.push(UiEvent::ValueChanged {
                         key: self.key,
                         value: self.value.clone(),
@@ -313,16 +596,20 @@ impl Widget for TextInput {
                 }
             }
             if let Some(input) = &pointer.text_input {
-                let _ = self.delete_selection_if_any();
-                self.value.insert_str(self.cursor, input);
-                self.cursor += input.len();
-                self.clear_selection();
-                events.push(UiEvent::ValueChanged {
-                    key: self.key,
-                    value: self.value.clone(),
-                });
+                let accepted = self.filter_insert(input);
+                if !accepted.is_empty() {
+                    self.record_edit(EditKind::Insert);
+                    self.insert_text(&accepted);
+                    if accepted.chars().all(char::is_whitespace) {
+                        self.history.seal();
+                    }
+                    events.push(UiEvent::ValueChanged {
+                        key: self.key,
+                        value: self.value.clone(),
+                    });
+                }
             }
-            if pointer.copy {
+            if pointer.copy && !self.behavior.obscured {
                 if let Some((start, end)) = self.selection_range() {
                     let copied = self.value[start..end].to_string();
                     INTERNAL_CLIPBOARD.with(|buffer| {
@@ -330,15 +617,18 @@ impl Widget for TextInput {
                     });
                 }
             }
-            if pointer.cut {
+            if pointer.cut && !self.behavior.obscured {
                 if let Some((start, end)) = self.selection_range() {
                     let cut = self.value[start..end].to_string();
                     INTERNAL_CLIPBOARD.with(|buffer| {
                         *buffer.borrow_mut() = cut;
                     });
+                    self.record_edit(EditKind::Delete);
                     self.value.replace_range(start..end, "");
                     self.cursor = start;
                     self.clear_selection();
+                    self.apply_format();
+                    self.history.seal();
                     events.push(UiEvent::ValueChanged {
                         key: self.key,
                         value: self.value.clone(),
@@ -347,18 +637,29 @@ impl Widget for TextInput {
             }
             if pointer.paste {
                 let pasted = INTERNAL_CLIPBOARD.with(|buffer| buffer.borrow().clone());
+                let pasted = self.filter_insert(&pasted);
                 if !pasted.is_empty() {
-                    let _ = self.delete_selection_if_any();
-                    self.value.insert_str(self.cursor, &pasted);
-                    self.cursor += pasted.len();
-                    self.clear_selection();
+                    self.history.seal();
+                    self.record_edit(EditKind::Insert);
+                    self.insert_text(&pasted);
+                    self.history.seal();
                     events.push(UiEvent::ValueChanged {
                         key: self.key,
                         value: self.value.clone(),
                     });
                 }
             }
+            if (pointer.undo && self.undo()) || (pointer.redo && self.redo()) {
+                events.push(UiEvent::ValueChanged {
+                    key: self.key,
+                    value: self.value.clone(),
+                });
+            }
+        }
+        if let Some(event) = self.validation_event() {
+            events.push(event);
         }
+        self.refresh_shaped(context);
 
         context.set_fill_style_str(self.style.fill);
         context.fill_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
@@ -372,32 +673,40 @@ impl Widget for TextInput {
         context.set_line_width(2.0);
         context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_text_align("left");
         context.set_text_baseline("middle");
 
-        let text_x = self.rect.x + self.style.padding_x;
+        if self.focused {
+            self.scroll_to_cursor();
+        } else {
+            self.scroll_x = 0.0;
+        }
+
+        context.save();
+        context.begin_path();
+        context.rect(
+            self.rect.x + 2.0,
+            self.rect.y + 2.0,
+            (self.rect.width - 4.0).max(0.0),
+            (self.rect.height - 4.0).max(0.0),
+        );
+        context.clip();
+
+        let text_x = self.text_origin_x();
         let text_y = self.rect.y + self.rect.height * 0.5;
         if self.value.is_empty() {
             context.set_fill_style_str(self.style.placeholder);
             let _ = context.fill_text(self.placeholder, text_x, text_y);
         } else {
             context.set_fill_style_str(self.style.text);
-            let _ = context.fill_text(&self.value, text_x, text_y);
+            let _ = context.fill_text(&self.display_prefix(self.value.len()), text_x, text_y);
         }
 
         if self.focused {
             if let Some((start, end)) = self.selection_range() {
-                let start_width = context
-                    .measure_text(&self.value[..start])
-                    .ok()
-                    .map(|metrics| metrics.width())
-                    .unwrap_or(0.0);
-                let end_width = context
-                    .measure_text(&self.value[..end])
-                    .ok()
-                    .map(|metrics| metrics.width())
-                    .unwrap_or(start_width);
+                let start_width = self.prefix_width(start);
+                let end_width = self.prefix_width(end);
                 context.set_fill_style_str("rgba(39,255,216,0.28)");
                 context.fill_rect(
                     text_x + start_width,
@@ -406,16 +715,10 @@ impl Widget for TextInput {
                     (self.rect.height - 14.0).max(0.0),
                 );
                 context.set_fill_style_str(self.style.text);
-                let _ = context.fill_text(&self.value, text_x, text_y);
+                let _ = context.fill_text(&self.display_prefix(self.value.len()), text_x, text_y);
             }
 
-            let before_cursor = &self.value[..self.cursor.min(self.value.len())];
-            let width = context
-                .measure_text(before_cursor)
-                .ok()
-                .map(|metrics| metrics.width())
-                .unwrap_or(0.0);
-            let cursor_x = text_x + width;
+            let cursor_x = text_x + self.prefix_width(self.cursor);
             let cursor_top = self.rect.y + 8.0;
             let cursor_height = (self.rect.height - 16.0).max(0.0);
             context.set_stroke_style_str(self.style.text);
@@ -426,6 +729,7 @@ impl Widget for TextInput {
             context.stroke();
         }
 
+        context.restore();
         events
     }
 
@@ -438,6 +742,7 @@ impl Widget for TextInput {
         if !focused {
             self.clear_selection();
             self.dragging_selection = false;
+            self.history.seal();
         }
     }
 
@@ -0,0 +1,307 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::ellipsize;
+use crate::ui::overlay::{pointer_for, push_overlay};
+use crate::ui::toast::{Toast, ToastLevel, take_pending};
+use crate::ui::tree::{UiEvent, Widget};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[allow(dead_code)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[derive(Clone, Copy)]
+pub struct ToastStyle {
+    pub fill: &'static str,
+    pub hover_fill: &'static str,
+    pub border: &'static str,
+    pub text: &'static str,
+    pub action_text: &'static str,
+    pub close_glyph: &'static str,
+    pub info: &'static str,
+    pub success: &'static str,
+    pub warning: &'static str,
+    pub error: &'static str,
+    pub font: FontDescriptor,
+    pub action_font: FontDescriptor,
+}
+
+impl Default for ToastStyle {
+    fn default() -> Self {
+        Self {
+            fill: "#111827",
+            hover_fill: "#162036",
+            border: "#2a3350",
+            text: "#d8e3ff",
+            action_text: "#27ffd8",
+            close_glyph: "#7d8bb0",
+            info: "#5b8cff",
+            success: "#43c06b",
+            warning: "#ffd166",
+            error: "#ff5c7a",
+            font: FontDescriptor::new("Consolas", 14.0),
+            action_font: FontDescriptor::new("Consolas", 13.0).weight(700),
+        }
+    }
+}
+
+impl ToastStyle {
+    fn level_color(&self, level: ToastLevel) -> &'static str {
+        match level {
+            ToastLevel::Info => self.info,
+            ToastLevel::Success => self.success,
+            ToastLevel::Warning => self.warning,
+            ToastLevel::Error => self.error,
+        }
+    }
+}
+
+struct ActiveToast {
+    toast: Toast,
+    remaining_ms: Option<f64>,
+}
+
+pub struct ToastHost {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub corner: ToastCorner,
+    pub max_visible: usize,
+    pub toast_width: f64,
+    pub toast_height: f64,
+    pub margin: f64,
+    pub gap: f64,
+    pub style: ToastStyle,
+    pub dismiss_shortcut: Option<Shortcut>,
+    visible: Vec<ActiveToast>,
+    queued: Vec<Toast>,
+    last_time_ms: Option<f64>,
+    hovered: bool,
+}
+
+impl ToastHost {
+    pub fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            corner: ToastCorner::default(),
+            max_visible: 4,
+            toast_width: 340.0,
+            toast_height: 52.0,
+            margin: 16.0,
+            gap: 8.0,
+            style: ToastStyle::default(),
+            dismiss_shortcut: Shortcut::parse("Alt+Shift+D"),
+            visible: Vec::new(),
+            queued: Vec::new(),
+            last_time_ms: None,
+            hovered: false,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.visible.is_empty() && self.queued.is_empty()
+    }
+
+    fn sync_queue(&mut self) {
+        let (pending, dismissed) = take_pending();
+        self.queued.extend(pending);
+        self.queued.retain(|toast| !dismissed.contains(&toast.id));
+        self.visible.retain(|active| !dismissed.contains(&active.toast.id));
+        while self.visible.len() < self.max_visible.max(1) && !self.queued.is_empty() {
+            let toast = self.queued.remove(0);
+            self.visible.push(ActiveToast {
+                remaining_ms: toast.duration_ms,
+                toast,
+            });
+        }
+    }
+
+    fn toast_rect(&self, slot: usize) -> Rect {
+        let width = self.toast_width.min(self.rect.width - self.margin * 2.0).max(0.0);
+        let offset = self.margin + slot as f64 * (self.toast_height + self.gap);
+        let x = match self.corner {
+            ToastCorner::TopLeft | ToastCorner::BottomLeft => self.rect.x + self.margin,
+            ToastCorner::TopRight | ToastCorner::BottomRight => self.rect.x + self.rect.width - self.margin - width,
+        };
+        let y = match self.corner {
+            ToastCorner::TopLeft | ToastCorner::TopRight => self.rect.y + offset,
+            ToastCorner::BottomLeft | ToastCorner::BottomRight => {
+                self.rect.y + self.rect.height - offset - self.toast_height
+            }
+        };
+        Rect {
+            x,
+            y,
+            width,
+            height: self.toast_height,
+        }
+    }
+
+    fn close_rect(rect: Rect) -> Rect {
+        Rect {
+            x: rect.x + rect.width - 30.0,
+            y: rect.y,
+            width: 30.0,
+            height: rect.height,
+        }
+    }
+
+    fn action_rect(rect: Rect) -> Rect {
+        Rect {
+            x: rect.x + rect.width - 100.0,
+            y: rect.y + 10.0,
+            width: 66.0,
+            height: rect.height - 20.0,
+        }
+    }
+
+    fn slots(&self) -> Vec<(usize, Rect)> {
+        let count = self.visible.len();
+        (0..count)
+            .map(|index| (index, self.toast_rect(count - 1 - index)))
+            .collect()
+    }
+
+    fn tick(&mut self, time_ms: f64) {
+        let elapsed = self.last_time_ms.map_or(0.0, |last| (time_ms - last).max(0.0));
+        self.last_time_ms = Some(time_ms);
+        if self.hovered {
+            return;
+        }
+        for active in &mut self.visible {
+            if let Some(remaining) = &mut active.remaining_ms {
+                *remaining -= elapsed;
+            }
+        }
+        self.visible
+            .retain(|active| active.remaining_ms.is_none_or(|remaining| remaining > 0.0));
+    }
+
+    fn draw_toast(&self, index: usize, rect: Rect, hovered: bool) {
+        let active = &self.visible[index];
+        let style = self.style;
+        let message = active.toast.message.clone();
+        let accent = style.level_color(active.toast.level);
+        let action = active.toast.action;
+        let progress = match (active.remaining_ms, active.toast.duration_ms) {
+            (Some(remaining), Some(duration)) if duration > 0.0 => Some((remaining / duration).clamp(0.0, 1.0)),
+            _ => None,
+        };
+        push_overlay(self.key, rect, move |context| {
+            context.set_fill_style_str(if hovered { style.hover_fill } else { style.fill });
+            context.fill_rect(rect.x, rect.y, rect.width, rect.height);
+            context.set_stroke_style_str(style.border);
+            context.set_line_width(1.0);
+            context.stroke_rect(rect.x, rect.y, rect.width, rect.height);
+            context.set_fill_style_str(accent);
+            context.fill_rect(rect.x, rect.y, 4.0, rect.height);
+            if let Some(progress) = progress {
+                context.fill_rect(rect.x, rect.y + rect.height - 2.0, rect.width * progress, 2.0);
+            }
+
+            let close = ToastHost::close_rect(rect);
+            let mut text_right = close.x;
+            context.set_text_baseline("middle");
+            if let Some(action) = action {
+                let action_rect = ToastHost::action_rect(rect);
+                text_right = action_rect.x;
+                context.set_font(&style.action_font.css());
+                context.set_text_align("center");
+                context.set_fill_style_str(style.action_text);
+                let _ = context.fill_text(
+                    &action.label.to_uppercase(),
+                    action_rect.x + action_rect.width * 0.5,
+                    action_rect.y + action_rect.height * 0.5,
+                );
+            }
+
+            context.set_font(&style.action_font.css());
+            context.set_text_align("center");
+            context.set_fill_style_str(style.close_glyph);
+            let _ = context.fill_text("×", close.x + close.width * 0.5, close.y + close.height * 0.5);
+
+            let font = style.font.css();
+            let text_x = rect.x + 16.0;
+            let fitted = ellipsize(context, &font, &message, (text_right - text_x - 8.0).max(0.0));
+            context.set_font(&font);
+            context.set_text_align("left");
+            context.set_fill_style_str(style.text);
+            let _ = context.fill_text(&fitted, text_x, rect.y + rect.height * 0.5);
+        });
+    }
+}
+
+impl Widget for ToastHost {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, _context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let pointer = pointer_for(self.key, pointer);
+        let mut events = Vec::new();
+        self.sync_queue();
+        self.hovered = self.slots().into_iter().any(|(_, rect)| rect.contains(pointer.x, pointer.y));
+        self.tick(pointer.time_ms);
+
+        if pointer.just_released
+            && let Some((index, rect)) = self.slots().into_iter().find(|(_, rect)| rect.contains(pointer.x, pointer.y))
+        {
+            let toast = &self.visible[index].toast;
+            if let Some(action) = toast.action
+                && Self::action_rect(rect).contains(pointer.x, pointer.y)
+            {
+                events.push(UiEvent::ToastAction {
+                    key: action.key,
+                    id: toast.id,
+                });
+                self.visible.remove(index);
+            } else if Self::close_rect(rect).contains(pointer.x, pointer.y) {
+                self.visible.remove(index);
+            }
+        }
+        if pointer.shortcut.is_some() && pointer.shortcut == self.dismiss_shortcut {
+            self.visible.pop();
+        }
+        self.sync_queue();
+
+        if self.visible.is_empty() {
+            self.last_time_ms = None;
+            return events;
+        }
+        if self.visible.iter().any(|active| active.remaining_ms.is_some()) {
+            request_frame();
+        }
+        for (index, rect) in self.slots() {
+            self.draw_toast(index, rect, rect.contains(pointer.x, pointer.y));
+        }
+        events
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        !self.visible.is_empty() && self.dismiss_shortcut.as_ref() == Some(shortcut)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
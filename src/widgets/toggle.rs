@@ -1,5 +1,6 @@
 use crate::core::geometry::Rect;
 use crate::core::input::PointerState;
+use crate::text::font::FontDescriptor;
 use crate::ui::tree::{UiAction, UiEvent, Widget};
 use std::any::Any;
 use web_sys::CanvasRenderingContext2d;
@@ -18,7 +19,7 @@ pub struct ToggleStyle {
     pub knob_fill: &'static str,
     pub text_fill: &'static str,
     pub focus_border: &'static str,
-    pub font: &'static str,
+    pub font: FontDescriptor,
 }
 
 impl Default for ToggleStyle {
@@ -29,7 +30,7 @@ impl Default for ToggleStyle {
             knob_fill: "#d8e3ff",
             text_fill: "#d8e3ff",
             focus_border: "#27ffd8",
-            font: "600 15px Consolas",
+            font: FontDescriptor::new("Consolas", 15.0).weight(600),
         }
     }
 }
@@ -56,7 +57,7 @@ impl Widget for Toggle {
             self.value = !self.value;
         }
 
-        context.set_font(self.style.font);
+        context.set_font(&self.style.font.css());
         context.set_fill_style_str(self.style.text_fill);
         context.set_text_align("left");
         context.set_text_baseline("middle");
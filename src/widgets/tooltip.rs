@@ -0,0 +1,211 @@
+use crate::core::frame::request_frame;
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::keymap::Shortcut;
+use crate::text::font::FontDescriptor;
+use crate::text::layout::{TextAlign, TextLayoutOptions, VerticalAlign, draw_layout, layout_text};
+use crate::ui::overlay::{place_popup, push_decoration};
+use crate::ui::tree::{UiEvent, Widget};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+pub const TOOLTIP_DELAY_MS: f64 = 500.0;
+
+#[derive(Clone, Copy)]
+pub struct TooltipStyle {
+    pub fill: &'static str,
+    pub border: &'static str,
+    pub text: &'static str,
+    pub font: FontDescriptor,
+    pub max_width: f64,
+    pub padding: f64,
+    pub gap: f64,
+}
+
+impl Default for TooltipStyle {
+    fn default() -> Self {
+        Self {
+            fill: "#1f2a44",
+            border: "#3d5387",
+            text: "#e7eeff",
+            font: FontDescriptor::new("Consolas", 13.0),
+            max_width: 280.0,
+            padding: 6.0,
+            gap: 6.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct TooltipTrigger {
+    hover: Option<(usize, f64)>,
+    focus: Option<usize>,
+    dismissed: bool,
+}
+
+impl TooltipTrigger {
+    pub fn update(
+        &mut self,
+        pointer: &PointerState,
+        hovered: Option<usize>,
+        focused: Option<usize>,
+        delay_ms: f64,
+    ) -> Option<usize> {
+        if hovered != self.hover.map(|(target, _)| target) {
+            self.hover = hovered.map(|target| (target, pointer.time_ms));
+            self.dismissed = self.dismissed && hovered.is_none();
+        }
+        if focused != self.focus {
+            self.focus = focused;
+            self.dismissed = pointer.just_pressed || pointer.just_released;
+        }
+        if pointer.cancel || pointer.just_pressed || pointer.scroll_x != 0.0 || pointer.scroll_y != 0.0 {
+            self.dismissed = true;
+        }
+        if self.dismissed {
+            return None;
+        }
+        if let Some((target, since)) = self.hover {
+            if pointer.time_ms - since >= delay_ms {
+                return Some(target);
+            }
+            request_frame();
+        }
+        self.focus
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+pub fn show_tooltip(context: &CanvasRenderingContext2d, anchor: Rect, text: &str, style: TooltipStyle) {
+    let font = style.font.css();
+    let layout = layout_text(
+        context,
+        &font,
+        text,
+        TextLayoutOptions {
+            max_width: Some(style.max_width - style.padding * 2.0),
+            wrap: true,
+            max_lines: None,
+            line_height: 1.3,
+            ellipsis: false,
+        },
+    );
+    let rect = place_popup(
+        anchor,
+        layout.width + style.padding * 2.0,
+        layout.height + style.padding * 2.0,
+        style.gap,
+        true,
+    );
+    push_decoration(move |context| {
+        context.set_fill_style_str(style.fill);
+        context.fill_rect(rect.x, rect.y, rect.width, rect.height);
+        context.set_stroke_style_str(style.border);
+        context.set_line_width(1.0);
+        context.stroke_rect(rect.x, rect.y, rect.width, rect.height);
+        context.set_font(&font);
+        context.set_fill_style_str(style.text);
+        let inner = Rect {
+            x: rect.x + style.padding,
+            y: rect.y + style.padding,
+            width: rect.width - style.padding * 2.0,
+            height: rect.height - style.padding * 2.0,
+        };
+        draw_layout(context, &layout, inner, TextAlign::Start, VerticalAlign::Top);
+    });
+}
+
+pub struct Tooltip {
+    pub child: Box<dyn Widget>,
+    pub text: String,
+    pub delay_ms: f64,
+    pub style: TooltipStyle,
+    pub rect: Rect,
+    pub trigger: TooltipTrigger,
+    pub focused: bool,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>, child: Box<dyn Widget>) -> Self {
+        Self {
+            child,
+            text: text.into(),
+            delay_ms: TOOLTIP_DELAY_MS,
+            style: TooltipStyle::default(),
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            trigger: TooltipTrigger::default(),
+            focused: false,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn child_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.child.as_any_mut().downcast_mut::<T>()
+    }
+}
+
+impl Widget for Tooltip {
+    fn desired_size(&self) -> (f64, f64) {
+        self.child.desired_size()
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.child.set_rect(rect);
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        let events = self.child.draw(context, pointer);
+        let hovered = self.rect.contains(pointer.x, pointer.y).then_some(0);
+        let focused = self.focused.then_some(0);
+        if !self.text.is_empty() && self.trigger.update(pointer, hovered, focused, self.delay_ms).is_some() {
+            show_tooltip(context, self.rect, &self.text, self.style);
+        }
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        self.child.focusable()
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.child.set_focused(focused);
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        self.child.activate()
+    }
+
+    fn focus_next_in_children(&mut self) -> bool {
+        self.child.focus_next_in_children()
+    }
+
+    fn focus_prev_in_children(&mut self) -> bool {
+        self.child.focus_prev_in_children()
+    }
+
+    fn focus_rect(&self) -> Option<Rect> {
+        self.child.focus_rect()
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.child.scrollable_at(x, y)
+    }
+
+    fn handles_shortcut(&self, shortcut: &Shortcut) -> bool {
+        self.child.handles_shortcut(shortcut)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,495 @@
+use crate::core::geometry::Rect;
+use crate::core::input::PointerState;
+use crate::core::navigation::{TypeAhead, find_next_prefix, step_clamped};
+use crate::text::font::FontDescriptor;
+use crate::text::layout::ellipsize;
+use crate::ui::tree::{UiEvent, Widget};
+use std::any::Any;
+use web_sys::CanvasRenderingContext2d;
+
+pub enum TreeChildren {
+    None,
+    Lazy,
+    Loading,
+    Loaded(Vec<TreeNode>),
+}
+
+pub struct TreeNode {
+    pub id: String,
+    pub label: String,
+    pub children: TreeChildren,
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    pub fn leaf(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: TreeChildren::None,
+            expanded: false,
+        }
+    }
+
+    pub fn branch(id: impl Into<String>, label: impl Into<String>, children: Vec<TreeNode>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: TreeChildren::Loaded(children),
+            expanded: false,
+        }
+    }
+
+    pub fn lazy(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: TreeChildren::Lazy,
+            expanded: false,
+        }
+    }
+
+    pub fn expanded(mut self) -> Self {
+        self.expanded = true;
+        self
+    }
+
+    fn is_branch(&self) -> bool {
+        !matches!(self.children, TreeChildren::None)
+    }
+}
+
+struct VisibleRow {
+    path: Vec<usize>,
+    depth: usize,
+    placeholder: bool,
+}
+
+pub struct TreeViewStyle {
+    pub fill: &'static str,
+    pub border: &'static str,
+    pub focus_border: &'static str,
+    pub row_selected: &'static str,
+    pub text: &'static str,
+    pub placeholder_text: &'static str,
+    pub disclosure: &'static str,
+    pub guide: &'static str,
+    pub font: FontDescriptor,
+    pub indent: f64,
+}
+
+impl Default for TreeViewStyle {
+    fn default() -> Self {
+        Self {
+            fill: "#0f162a",
+            border: "#2a3350",
+            focus_border: "#27ffd8",
+            row_selected: "#274060",
+            text: "#cfe0ff",
+            placeholder_text: "#6f7fa8",
+            disclosure: "#9eb4ff",
+            guide: "#1f2a48",
+            font: FontDescriptor::new("Consolas", 14.0),
+            indent: 18.0,
+        }
+    }
+}
+
+pub struct TreeView {
+    pub key: &'static str,
+    pub rect: Rect,
+    pub roots: Vec<TreeNode>,
+    pub row_height: f64,
+    pub scroll_offset: f64,
+    pub style: TreeViewStyle,
+    pub focused: bool,
+    selected: Option<String>,
+    rows: Vec<VisibleRow>,
+    rows_stale: bool,
+    type_ahead: TypeAhead,
+}
+
+impl TreeView {
+    pub fn new(key: &'static str, roots: Vec<TreeNode>) -> Self {
+        Self {
+            key,
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            roots,
+            row_height: 26.0,
+            scroll_offset: 0.0,
+            style: TreeViewStyle::default(),
+            focused: false,
+            selected: None,
+            rows: Vec::new(),
+            rows_stale: true,
+            type_ahead: TypeAhead::default(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn invalidate(&mut self) {
+        self.rows_stale = true;
+    }
+
+    #[allow(dead_code)]
+    pub fn selected_id(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    pub fn set_children(&mut self, id: &str, children: Vec<TreeNode>) -> bool {
+        let Some(node) = find_node_mut(&mut self.roots, id) else {
+            return false;
+        };
+        node.children = TreeChildren::Loaded(children);
+        self.rows_stale = true;
+        true
+    }
+
+    #[allow(dead_code)]
+    pub fn reset_children(&mut self, id: &str) -> bool {
+        let Some(node) = find_node_mut(&mut self.roots, id) else {
+            return false;
+        };
+        node.children = TreeChildren::Lazy;
+        node.expanded = false;
+        self.rows_stale = true;
+        true
+    }
+
+    fn node(&self, path: &[usize]) -> Option<&TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get(*first)?;
+        for index in rest {
+            let TreeChildren::Loaded(children) = &node.children else {
+                return None;
+            };
+            node = children.get(*index)?;
+        }
+        Some(node)
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.get_mut(*first)?;
+        for index in rest {
+            let TreeChildren::Loaded(children) = &mut node.children else {
+                return None;
+            };
+            node = children.get_mut(*index)?;
+        }
+        Some(node)
+    }
+
+    fn sync_rows(&mut self) {
+        if !self.rows_stale {
+            return;
+        }
+        self.rows_stale = false;
+        let mut rows = Vec::new();
+        flatten(&self.roots, &mut Vec::new(), &mut rows);
+        self.rows = rows;
+    }
+
+    fn max_scroll(&self) -> f64 {
+        (self.rows.len() as f64 * self.row_height - self.rect.height).max(0.0)
+    }
+
+    fn row_node(&self, index: usize) -> Option<&TreeNode> {
+        let row = self.rows.get(index)?;
+        if row.placeholder {
+            return None;
+        }
+        self.node(&row.path)
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_deref()?;
+        (0..self.rows.len()).find(|index| self.row_node(*index).is_some_and(|node| node.id == selected))
+    }
+
+    fn select_index(&mut self, index: usize) -> Option<UiEvent> {
+        let id = self.row_node(index)?.id.clone();
+        let top = index as f64 * self.row_height;
+        if top < self.scroll_offset {
+            self.scroll_offset = top;
+        } else if top + self.row_height > self.scroll_offset + self.rect.height {
+            self.scroll_offset = top + self.row_height - self.rect.height;
+        }
+        if self.selected.as_deref() == Some(id.as_str()) {
+            return None;
+        }
+        self.selected = Some(id.clone());
+        Some(UiEvent::ValueChanged {
+            key: self.key,
+            value: id,
+        })
+    }
+
+    fn step_selection(&mut self, delta: isize) -> Option<UiEvent> {
+        let mut index = step_clamped(self.selected_index(), delta, self.rows.len())?;
+        while self.rows[index].placeholder && index > 0 {
+            index -= 1;
+        }
+        self.select_index(index)
+    }
+
+    fn set_expanded(&mut self, index: usize, expanded: bool) -> Option<UiEvent> {
+        let key = self.key;
+        let path = self.rows.get(index)?.path.clone();
+        if self.node(&path).is_none_or(|node| !node.is_branch() || node.expanded == expanded) {
+            return None;
+        }
+        let depth = self.rows[index].depth;
+        let hides_selection = !expanded
+            && self.selected_index().is_some_and(|selected| {
+                selected > index && self.rows[index + 1..=selected].iter().all(|row| row.depth > depth)
+            });
+        let selection_event = if hides_selection { self.select_index(index) } else { None };
+        self.rows_stale = true;
+        let node = self.node_mut(&path)?;
+        node.expanded = expanded;
+        if expanded && matches!(node.children, TreeChildren::Lazy) {
+            node.children = TreeChildren::Loading;
+            return Some(UiEvent::ChildrenRequested {
+                key,
+                id: node.id.clone(),
+            });
+        }
+        selection_event
+    }
+
+    fn toggle(&mut self, index: usize) -> Option<UiEvent> {
+        let expanded = self.row_node(index)?.expanded;
+        self.set_expanded(index, !expanded)
+    }
+
+    fn parent_index(&self, index: usize) -> Option<usize> {
+        let depth = self.rows.get(index)?.depth;
+        (0..index).rev().find(|candidate| self.rows[*candidate].depth < depth)
+    }
+
+    fn handle_keys(&mut self, pointer: &PointerState) -> Vec<UiEvent> {
+        let page = (self.rect.height / self.row_height.max(1.0)).floor().max(1.0) as isize;
+        let event = if pointer.move_up {
+            self.step_selection(-1)
+        } else if pointer.move_down {
+            self.step_selection(1)
+        } else if pointer.move_page_up {
+            self.step_selection(-page)
+        } else if pointer.move_page_down {
+            self.step_selection(page)
+        } else if pointer.move_home {
+            self.select_index(0)
+        } else if pointer.move_end {
+            let last = (0..self.rows.len()).rev().find(|index| !self.rows[*index].placeholder);
+            last.and_then(|index| self.select_index(index))
+        } else if pointer.move_right {
+            let index = self.selected_index();
+            match index.and_then(|index| self.row_node(index).map(|node| (index, node.expanded, node.is_branch()))) {
+                Some((index, false, true)) => self.set_expanded(index, true),
+                Some((index, true, true)) => match self.rows.get(index + 1) {
+                    Some(child) if child.depth > self.rows[index].depth && !child.placeholder => {
+                        self.select_index(index + 1)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        } else if pointer.move_left {
+            let index = self.selected_index();
+            match index.and_then(|index| self.row_node(index).map(|node| (index, node.expanded))) {
+                Some((index, true)) => self.set_expanded(index, false),
+                Some((index, false)) => self.parent_index(index).and_then(|parent| self.select_index(parent)),
+                None => None,
+            }
+        } else if let Some(input) = &pointer.text_input {
+            self.jump_to_prefix(input, pointer.time_ms)
+        } else {
+            None
+        };
+        event.into_iter().collect()
+    }
+
+    fn jump_to_prefix(&mut self, needle: &str, time_ms: f64) -> Option<UiEvent> {
+        let labels = (0..self.rows.len())
+            .map(|index| self.row_node(index).map(|node| node.label.clone()).unwrap_or_default())
+            .collect::<Vec<_>>();
+        let current = self.selected_index();
+        let index = self
+            .type_ahead
+            .search(&labels, needle, time_ms, current, find_next_prefix)?;
+        self.select_index(index)
+    }
+
+    fn draw_disclosure(&self, context: &CanvasRenderingContext2d, x: f64, center_y: f64, expanded: bool) {
+        context.set_fill_style_str(self.style.disclosure);
+        context.begin_path();
+        if expanded {
+            context.move_to(x - 5.0, center_y - 3.0);
+            context.line_to(x + 5.0, center_y - 3.0);
+            context.line_to(x, center_y + 4.0);
+        } else {
+            context.move_to(x - 3.0, center_y - 5.0);
+            context.line_to(x + 4.0, center_y);
+            context.line_to(x - 3.0, center_y + 5.0);
+        }
+        context.close_path();
+        context.fill();
+    }
+}
+
+fn flatten(nodes: &[TreeNode], path: &mut Vec<usize>, rows: &mut Vec<VisibleRow>) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+        rows.push(VisibleRow {
+            path: path.clone(),
+            depth: path.len() - 1,
+            placeholder: false,
+        });
+        if node.expanded {
+            match &node.children {
+                TreeChildren::Loaded(children) => flatten(children, path, rows),
+                TreeChildren::Loading => rows.push(VisibleRow {
+                    path: path.clone(),
+                    depth: path.len(),
+                    placeholder: true,
+                }),
+                TreeChildren::None | TreeChildren::Lazy => {}
+            }
+        }
+        path.pop();
+    }
+}
+
+fn find_node_mut<'a>(nodes: &'a mut [TreeNode], id: &str) -> Option<&'a mut TreeNode> {
+    for node in nodes {
+        if node.id == id {
+            return Some(node);
+        }
+        if let TreeChildren::Loaded(children) = &mut node.children
+            && let Some(found) = find_node_mut(children, id)
+        {
+            return Some(found);
+        }
+    }
+    None
+}
+
+impl Widget for TreeView {
+    fn desired_size(&self) -> (f64, f64) {
+        (self.rect.width, self.rect.height)
+    }
+
+    fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+    }
+
+    fn draw(&mut self, context: &CanvasRenderingContext2d, pointer: &PointerState) -> Vec<UiEvent> {
+        self.sync_rows();
+        let mut events = Vec::new();
+        let hovered = self.rect.contains(pointer.x, pointer.y);
+        if hovered && pointer.scroll_y.abs() > 0.0 {
+            self.scroll_offset += pointer.scroll_y * 0.75;
+        }
+
+        if pointer.just_released && hovered {
+            let index = ((pointer.y - self.rect.y + self.scroll_offset) / self.row_height.max(1.0)).floor() as usize;
+            if let Some(row) = self.rows.get(index).filter(|row| !row.placeholder) {
+                let disclosure_right = self.rect.x + 8.0 + (row.depth as f64 + 1.0) * self.style.indent;
+                if pointer.x <= disclosure_right {
+                    events.extend(self.toggle(index));
+                }
+                events.extend(self.select_index(index));
+            }
+        }
+        if self.focused {
+            events.extend(self.handle_keys(pointer));
+        }
+        self.sync_rows();
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll());
+
+        context.set_fill_style_str(self.style.fill);
+        context.fill_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        context.save();
+        context.begin_path();
+        context.rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        context.clip();
+
+        let font = self.style.font.css();
+        let selected_index = self.selected_index();
+        let first = (self.scroll_offset / self.row_height.max(1.0)).floor() as usize;
+        let last = (((self.scroll_offset + self.rect.height) / self.row_height.max(1.0)).ceil() as usize)
+            .min(self.rows.len());
+        for index in first..last {
+            let row = &self.rows[index];
+            let y = self.rect.y + index as f64 * self.row_height - self.scroll_offset;
+            let center_y = y + self.row_height * 0.5;
+            if selected_index == Some(index) {
+                context.set_fill_style_str(self.style.row_selected);
+                context.fill_rect(self.rect.x, y, self.rect.width, self.row_height);
+            }
+            context.set_fill_style_str(self.style.guide);
+            for level in 0..row.depth {
+                let guide_x = self.rect.x + 8.0 + level as f64 * self.style.indent + self.style.indent * 0.5;
+                context.fill_rect(guide_x, y, 1.0, self.row_height);
+            }
+
+            let indent_x = self.rect.x + 8.0 + row.depth as f64 * self.style.indent;
+            let label_x = indent_x + self.style.indent;
+            let (label, color) = match self.row_node(index) {
+                Some(node) => {
+                    if node.is_branch() {
+                        self.draw_disclosure(context, indent_x + self.style.indent * 0.5, center_y, node.expanded);
+                    }
+                    (node.label.clone(), self.style.text)
+                }
+                None => ("Loading\u{2026}".to_string(), self.style.placeholder_text),
+            };
+            let label = ellipsize(context, &font, &label, (self.rect.x + self.rect.width - label_x - 8.0).max(0.0));
+            context.set_font(&font);
+            context.set_fill_style_str(color);
+            context.set_text_align("left");
+            context.set_text_baseline("middle");
+            let _ = context.fill_text(&label, label_x, center_y);
+        }
+        context.restore();
+
+        context.set_stroke_style_str(if self.focused {
+            self.style.focus_border
+        } else {
+            self.style.border
+        });
+        context.set_line_width(2.0);
+        context.stroke_rect(self.rect.x, self.rect.y, self.rect.width, self.rect.height);
+        events
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        if self.focused != focused {
+            self.type_ahead.clear();
+        }
+        self.focused = focused;
+    }
+
+    fn activate(&mut self) -> Option<UiEvent> {
+        let index = self.selected_index()?;
+        self.toggle(index)
+    }
+
+    fn scrollable_at(&self, x: f64, y: f64) -> bool {
+        self.rect.contains(x, y) && self.max_scroll() > 0.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}